@@ -0,0 +1,54 @@
+//! Builder API signing from `rs-builder-signing-sdk`, with the `builder` feature
+//!
+//! Without it (e.g. on wasm32, where the SDK's tokio runtime doesn't build) `BuilderConfig`
+//! has no values, so no client is ever configured for builder auth.
+
+#[cfg(feature = "builder")]
+pub use rs_builder_signing_sdk::{BuilderConfig, BuilderHeaderPayload};
+
+/// Key of the local builder credentials, or `None` for a remote signer
+///
+/// The SDK keeps the credentials private, so the key is read back from headers signed
+/// in-process; a remote signer is never contacted.
+#[cfg(feature = "builder")]
+pub(crate) async fn local_api_key(config: &BuilderConfig) -> Option<String> {
+    if config.get_builder_type() != rs_builder_signing_sdk::BuilderType::Local {
+        return None;
+    }
+    config
+        .generate_builder_headers("GET", "/", None, Some(0))
+        .await
+        .ok()?
+        .get("POLY_BUILDER_API_KEY")
+        .cloned()
+}
+
+#[cfg(not(feature = "builder"))]
+pub type BuilderHeaderPayload = std::collections::HashMap<String, String>;
+
+/// Builder configuration; uninhabited without the `builder` feature
+#[cfg(not(feature = "builder"))]
+#[derive(Debug, Clone)]
+pub enum BuilderConfig {}
+
+#[cfg(not(feature = "builder"))]
+impl BuilderConfig {
+    pub fn is_valid(&self) -> bool {
+        match *self {}
+    }
+
+    pub async fn generate_builder_headers(
+        &self,
+        _method: &str,
+        _path: &str,
+        _body: Option<&str>,
+        _timestamp: Option<u64>,
+    ) -> Result<BuilderHeaderPayload, std::convert::Infallible> {
+        match *self {}
+    }
+}
+
+#[cfg(not(feature = "builder"))]
+pub(crate) async fn local_api_key(config: &BuilderConfig) -> Option<String> {
+    match *config {}
+}
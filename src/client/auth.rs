@@ -1,36 +1,51 @@
+use crate::builder_sdk::BuilderHeaderPayload;
 use crate::client::ClobClient;
+use crate::constants::NOTIFICATION_POLL_MAX_BACKOFF;
 use crate::endpoints::endpoints;
 use crate::errors::{ClobError, ClobResult};
 use crate::headers::{create_l1_headers, create_l2_headers, inject_builder_headers};
 use crate::types::*;
-use rs_builder_signing_sdk::BuilderHeaderPayload;
-use std::collections::HashMap;
+use alloy_primitives::Address;
+use futures::stream::{self, Stream};
+use serde::de::DeserializeOwned;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::time::Duration;
 
 impl ClobClient {
     // API Key (L1 Authentication)
     pub async fn create_api_key(&self, nonce: Option<u64>) -> ClobResult<ApiKeyCreds> {
         self.can_l1_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let wallet = self.signer.as_deref().ok_or(ClobError::L1AuthUnavailable)?;
 
         // Get timestamp if server time is enabled
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let timestamp = self.signing_timestamp().await?;
 
         // Create L1 headers
-        let headers = create_l1_headers(wallet, self.chain_id.chain_id(), nonce, timestamp)
-            .await?
-            .to_headers();
-
-        println!("Headers: {:?}", headers);
+        let headers = create_l1_headers(
+            wallet,
+            self.chain_id.chain_id(),
+            nonce,
+            timestamp,
+            self.time_provider.as_ref(),
+        )
+        .await?
+        .to_headers();
+
+        let redacted: BTreeMap<&str, &str> = headers
+            .iter()
+            .map(|(name, value)| match name.as_str() {
+                "POLY_SIGNATURE" => ("POLY_SIGNATURE", "***"),
+                name => (name, value.as_str()),
+            })
+            .collect();
+        tracing::debug!(headers = ?redacted, "creating API key");
 
         // Make request
         let response: ApiKeyRaw = self
             .http_client
-            .post(endpoints::CREATE_API_KEY, Some(headers), None::<()>, None)
+            .post_json(endpoints::CREATE_API_KEY, Some(headers), None::<()>, None)
             .await?;
 
         Ok(response.into())
@@ -39,24 +54,26 @@ impl ClobClient {
     pub async fn derive_api_key(&self, nonce: Option<u64>) -> ClobResult<ApiKeyCreds> {
         self.can_l1_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let wallet = self.signer.as_deref().ok_or(ClobError::L1AuthUnavailable)?;
 
         // Get timestamp if server time is enabled
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let timestamp = self.signing_timestamp().await?;
 
         // Create L1 headers
-        let headers = create_l1_headers(wallet, self.chain_id.chain_id(), nonce, timestamp)
-            .await?
-            .to_headers();
+        let headers = create_l1_headers(
+            wallet,
+            self.chain_id.chain_id(),
+            nonce,
+            timestamp,
+            self.time_provider.as_ref(),
+        )
+        .await?
+        .to_headers();
 
         // Make request
         let response: ApiKeyRaw = self
             .http_client
-            .get(endpoints::DERIVE_API_KEY, Some(headers), None)
+            .get_json(endpoints::DERIVE_API_KEY, Some(headers), None)
             .await?;
 
         Ok(response.into())
@@ -77,66 +94,21 @@ impl ClobClient {
     pub async fn get_api_keys(&self) -> ClobResult<ApiKeysResponse> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
-        let endpoint_path = endpoints::GET_API_KEYS;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-
-        let headers = create_l2_headers(wallet, creds, "GET", endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
-
-        self.http_client
-            .get(endpoint_path, Some(headers), None)
+        self.send_l2("GET", endpoints::GET_API_KEYS, None, None)
             .await
     }
 
     pub async fn get_closed_only_mode(&self) -> ClobResult<BanStatus> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
-        let endpoint_path = endpoints::CLOSED_ONLY;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-
-        let headers = create_l2_headers(wallet, creds, "GET", endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
-
-        self.http_client
-            .get(endpoint_path, Some(headers), None)
+        self.send_l2("GET", endpoints::CLOSED_ONLY, None, None)
             .await
     }
 
     pub async fn delete_api_key(&self) -> ClobResult<serde_json::Value> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
-        let endpoint_path = endpoints::DELETE_API_KEY;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-
-        let headers = create_l2_headers(wallet, creds, "DELETE", endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
-
-        self.http_client
-            .delete(endpoint_path, Some(headers), None::<()>, None)
+        self.send_l2("DELETE", endpoints::DELETE_API_KEY, None, None)
             .await
     }
 
@@ -144,44 +116,14 @@ impl ClobClient {
     pub async fn create_builder_api_key(&self) -> ClobResult<BuilderApiKey> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
-        let endpoint_path = endpoints::CREATE_BUILDER_API_KEY;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-
-        let headers = create_l2_headers(wallet, creds, "POST", endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
-
-        self.http_client
-            .post(endpoint_path, Some(headers), None::<()>, None)
+        self.send_l2("POST", endpoints::CREATE_BUILDER_API_KEY, None, None)
             .await
     }
 
     pub async fn get_builder_api_keys(&self) -> ClobResult<Vec<BuilderApiKeyResponse>> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
-        let endpoint_path = endpoints::GET_BUILDER_API_KEYS;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-
-        let headers = create_l2_headers(wallet, creds, "GET", endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
-
-        self.http_client
-            .get(endpoint_path, Some(headers), None)
+        self.send_l2("GET", endpoints::GET_BUILDER_API_KEYS, None, None)
             .await
     }
 
@@ -196,7 +138,35 @@ impl ClobClient {
             .await?;
 
         self.http_client
-            .delete(endpoint_path, Some(headers), None::<()>, None)
+            .delete_json(endpoint_path, Some(headers), None::<()>, None)
+            .await
+    }
+
+    /// Revokes a specific builder API key owned by the user
+    ///
+    /// Unlike `revoke_builder_api_key`, which revokes the configured builder key, this targets
+    /// `key` using L2 authentication. Revoking the active builder key is refused unless `force`;
+    /// with a remote signer the active key isn't known locally, so nothing is refused.
+    pub async fn revoke_builder_api_key_by(
+        &self,
+        key: &str,
+        force: bool,
+    ) -> ClobResult<serde_json::Value> {
+        self.can_l2_auth()?;
+
+        if !force && self.active_builder_api_key().await.as_deref() == Some(key) {
+            return Err(ClobError::Other(format!(
+                "Refusing to revoke the active builder API key {}; pass force = true to override",
+                key
+            )));
+        }
+
+        let endpoint_path = endpoints::REVOKE_BUILDER_API_KEY;
+
+        let mut query_params = HashMap::new();
+        query_params.insert("key".to_string(), key.to_string());
+
+        self.send_l2("DELETE", endpoint_path, None, Some(query_params))
             .await
     }
 
@@ -212,36 +182,30 @@ impl ClobClient {
     pub async fn get_balance_allowance(
         &self,
         params: BalanceAllowanceParams,
-    ) -> ClobResult<serde_json::Value> {
+    ) -> ClobResult<BalanceAllowanceResponse> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
         let endpoint_path = endpoints::GET_BALANCE_ALLOWANCE;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
 
-        let headers = create_l2_headers(wallet, creds, "GET", endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
+        let query_params = balance_allowance_query(params);
 
-        let mut query_params = HashMap::new();
-        let asset_type_str = match params.asset_type {
-            AssetType::Collateral => "COLLATERAL",
-            AssetType::Conditional => "CONDITIONAL",
-        };
-        query_params.insert("asset_type".to_string(), asset_type_str.to_string());
+        self.send_l2("GET", endpoint_path, None, Some(query_params))
+            .await
+    }
 
-        if let Some(token_id) = params.token_id {
-            query_params.insert("token_id".to_string(), token_id);
-        }
+    /// Asks the server to refresh its cached on-chain balance and allowance, e.g. after
+    /// approving the exchange contracts
+    pub async fn update_balance_allowance(
+        &self,
+        params: BalanceAllowanceParams,
+    ) -> ClobResult<serde_json::Value> {
+        self.can_l2_auth()?;
 
-        self.http_client
-            .get(endpoint_path, Some(headers), Some(query_params))
+        let endpoint_path = endpoints::UPDATE_BALANCE_ALLOWANCE;
+
+        let query_params = balance_allowance_query(params);
+
+        self.send_l2("GET", endpoint_path, None, Some(query_params))
             .await
     }
 
@@ -249,41 +213,14 @@ impl ClobClient {
     pub async fn get_notifications(&self) -> ClobResult<Vec<Notification>> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
-        let endpoint_path = endpoints::GET_NOTIFICATIONS;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-
-        let headers = create_l2_headers(wallet, creds, "GET", endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
-
-        self.http_client
-            .get(endpoint_path, Some(headers), None)
+        self.send_l2("GET", endpoints::GET_NOTIFICATIONS, None, None)
             .await
     }
 
     pub async fn drop_notifications(&self, params: DropNotificationParams) -> ClobResult<()> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
         let endpoint_path = endpoints::DROP_NOTIFICATIONS;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-
-        let headers = create_l2_headers(wallet, creds, "DELETE", endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
 
         let mut query_params = HashMap::new();
 
@@ -292,32 +229,142 @@ impl ClobClient {
         }
 
         let _: serde_json::Value = self
-            .http_client
-            .delete(endpoint_path, Some(headers), None::<()>, Some(query_params))
+            .send_l2("DELETE", endpoint_path, None, Some(query_params))
             .await?;
 
         Ok(())
     }
 
+    /// Polls `get_notifications` every `interval` and yields each notification once
+    ///
+    /// Notifications already seen are skipped (by id, or by content when the server sends
+    /// no id). With `auto_drop`, newly seen notifications that carry an id are dropped on the
+    /// server right after they are fetched. Failed polls are logged and retried with a
+    /// doubling delay capped at `NOTIFICATION_POLL_MAX_BACKOFF`; the stream never ends.
+    pub fn notifications_stream(
+        &self,
+        interval: Duration,
+        auto_drop: bool,
+    ) -> impl Stream<Item = Notification> + '_ {
+        struct PollState {
+            seen: HashSet<String>,
+            pending: VecDeque<Notification>,
+            // None until the first poll, which runs immediately
+            delay: Option<Duration>,
+        }
+
+        let state = PollState {
+            seen: HashSet::new(),
+            pending: VecDeque::new(),
+            delay: None,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(notification) = state.pending.pop_front() {
+                    return Some((notification, state));
+                }
+
+                if let Some(delay) = state.delay {
+                    crate::time::sleep(delay).await;
+                }
+
+                let notifications = match self.get_notifications().await {
+                    Ok(notifications) => notifications,
+                    Err(e) => {
+                        let backoff = (state.delay.unwrap_or(interval).max(interval) * 2)
+                            .min(NOTIFICATION_POLL_MAX_BACKOFF.max(interval));
+                        tracing::warn!(?backoff, "notification poll failed: {}", e);
+                        state.delay = Some(backoff);
+                        continue;
+                    }
+                };
+                state.delay = Some(interval);
+
+                // Only remember what the server still reports, so `seen` stays bounded
+                let mut current = HashSet::new();
+                for notification in notifications {
+                    let key = notification.dedup_key();
+                    if current.insert(key.clone()) && !state.seen.contains(&key) {
+                        state.pending.push_back(notification);
+                    }
+                }
+                state.seen = current;
+
+                if auto_drop {
+                    let ids: Vec<String> = state
+                        .pending
+                        .iter()
+                        .filter_map(|notification| notification.id)
+                        .map(|id| id.to_string())
+                        .collect();
+                    if !ids.is_empty() {
+                        if let Err(e) = self
+                            .drop_notifications(DropNotificationParams { ids })
+                            .await
+                        {
+                            tracing::warn!("failed to drop delivered notifications: {}", e);
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    /// User channel authenticated with this client's API credentials
+    ///
+    /// `markets` are condition ids to receive order and trade updates for; leave it empty
+    /// for every market.
+    #[cfg(feature = "ws")]
+    pub fn user_channel(&self, markets: Vec<String>) -> ClobResult<crate::ws::UserChannel> {
+        let creds = self.api_creds().ok_or(ClobError::L2AuthNotAvailable)?;
+        Ok(crate::ws::UserChannel::new(creds, markets))
+    }
+
+    /// Address of the signing wallet, if the client has one
+    ///
+    /// For proxy and Safe signature types this is the owner key, not the funder address.
+    pub fn address(&self) -> Option<Address> {
+        self.signer_address
+    }
+
+    /// Highest authentication level available, derived from the configured wallet,
+    /// credentials and builder config without making any request
+    pub fn auth_level(&self) -> AuthLevel {
+        if self.can_l1_auth().is_err() {
+            AuthLevel::Public
+        } else if self.can_l2_auth().is_err() {
+            AuthLevel::L1
+        } else if self.can_builder_auth() {
+            AuthLevel::L2Builder
+        } else {
+            AuthLevel::L2
+        }
+    }
+
     // Helper Methods
-    pub(crate) fn can_l1_auth(&self) -> ClobResult<()> {
-        if self.wallet.is_none() {
+
+    /// `Ok` when a wallet is set, else `L1AuthUnavailable`
+    pub fn can_l1_auth(&self) -> ClobResult<()> {
+        if self.signer.is_none() {
             return Err(ClobError::L1AuthUnavailable);
         }
         Ok(())
     }
 
-    pub(crate) fn can_l2_auth(&self) -> ClobResult<()> {
+    /// `Ok` when a wallet and API credentials are set, else the missing level's error
+    pub fn can_l2_auth(&self) -> ClobResult<()> {
         self.can_l1_auth()?;
 
-        if self.creds.is_none() {
+        if self.creds.read().unwrap().is_none() {
             return Err(ClobError::L2AuthNotAvailable);
         }
 
         Ok(())
     }
 
-    pub(crate) fn can_builder_auth(&self) -> bool {
+    /// Whether a builder config with local credentials or a remote signer is set
+    pub fn can_builder_auth(&self) -> bool {
         self.builder_config
             .as_ref()
             .map_or(false, |config| config.is_valid())
@@ -330,6 +377,119 @@ impl ClobClient {
         Ok(())
     }
 
+    /// L2 headers for a request, signed with the current API credentials
+    pub(crate) async fn l2_headers(
+        &self,
+        method: &str,
+        endpoint_path: &str,
+        body: Option<&str>,
+    ) -> ClobResult<L2PolyHeader> {
+        let address = self.signer_address.ok_or(ClobError::L1AuthUnavailable)?;
+        let creds = self.api_creds().ok_or(ClobError::L2AuthNotAvailable)?;
+
+        // Get timestamp if server time is enabled
+        let timestamp = self.signing_timestamp().await?;
+
+        create_l2_headers(
+            address,
+            &creds,
+            method,
+            endpoint_path,
+            body,
+            timestamp,
+            self.time_provider.as_ref(),
+        )
+        .await
+    }
+
+    /// Sends an L2 request (`GET`, `POST` or `DELETE`) through `with_reauth` and decodes
+    /// the response
+    pub(crate) async fn send_l2<T: DeserializeOwned>(
+        &self,
+        method: &'static str,
+        endpoint_path: &str,
+        body: Option<serde_json::Value>,
+        params: Option<HashMap<String, String>>,
+    ) -> ClobResult<T> {
+        let body_str = body.as_ref().map(serde_json::to_string).transpose()?;
+        let (body, body_str, params) = (&body, body_str.as_deref(), &params);
+
+        self.with_reauth(|| async move {
+            let headers = self
+                .l2_headers(method, endpoint_path, body_str)
+                .await?
+                .to_headers();
+
+            match method {
+                "GET" => {
+                    self.http_client
+                        .get_json(endpoint_path, Some(headers), params.clone())
+                        .await
+                }
+                "POST" => {
+                    self.http_client
+                        .post_json(endpoint_path, Some(headers), body.clone(), params.clone())
+                        .await
+                }
+                "DELETE" => {
+                    self.http_client
+                        .delete_json(endpoint_path, Some(headers), body.clone(), params.clone())
+                        .await
+                }
+                _ => Err(ClobError::Other(format!(
+                    "Unsupported L2 method {}",
+                    method
+                ))),
+            }
+        })
+        .await
+    }
+
+    /// Runs an L2 request, re-deriving the API key and retrying once on a 401
+    ///
+    /// A 401 signed with a drifted local clock is returned as `ClockDrift` instead, since a
+    /// new key wouldn't help. Re-derivation needs `auto_reauth` and a wallet; otherwise the
+    /// 401 is returned as is. `request` must sign with the credentials current when it is
+    /// called, so the retry uses the new key. A failed re-derivation is returned instead of
+    /// the 401. Requests rejected together share one re-derivation: those that find the key
+    /// already replaced retry with the new one.
+    pub(crate) async fn with_reauth<T, F, Fut>(&self, request: F) -> ClobResult<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = ClobResult<T>>,
+    {
+        let rejected_key = self.api_key();
+        match request().await {
+            Err(error @ ClobError::ApiError { status: 401, .. }) => {
+                if let Some(drift) = self.detect_clock_drift().await {
+                    tracing::warn!("L2 request unauthorized: {}", drift);
+                    return Err(drift);
+                }
+                if !self.auto_reauth || self.signer.is_none() {
+                    return Err(error);
+                }
+
+                let _reauth = self.reauth_lock.lock().await;
+                if self.api_key() == rejected_key {
+                    tracing::warn!("L2 request unauthorized, re-deriving the API key");
+                    let creds = self.create_or_derive_api_key(None).await?;
+                    *self.creds.write().unwrap() = Some(creds);
+                }
+                request().await
+            }
+            result => result,
+        }
+    }
+
+    /// Key of the current API credentials, if any
+    pub(crate) fn api_key(&self) -> Option<String> {
+        self.creds
+            .read()
+            .unwrap()
+            .as_ref()
+            .map(|creds| creds.key.clone())
+    }
+
     pub(crate) async fn _get_builder_headers(
         &self,
         method: &str,
@@ -342,21 +502,22 @@ impl ClobClient {
             .ok_or(ClobError::BuilderAuthNotAvailable)?;
 
         // Get timestamp if server time is enabled
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let timestamp = self.signing_timestamp().await?;
 
         config
             .generate_builder_headers(method, path, body, timestamp)
             .await
             .map_err(|e| {
-                eprintln!("Builder header generation error: {:?}", e);
+                tracing::error!("builder header generation failed: {:?}", e);
                 ClobError::BuilderAuthFailed
             })
     }
 
+    /// Key of the configured local builder credentials (`None` with a remote signer)
+    pub(crate) async fn active_builder_api_key(&self) -> Option<String> {
+        crate::builder_sdk::local_api_key(self.builder_config.as_ref()?).await
+    }
+
     pub(crate) async fn _generate_builder_headers(
         &self,
         l2_headers: L2PolyHeader,
@@ -374,3 +535,19 @@ impl ClobClient {
         }
     }
 }
+
+/// Query parameters shared by the balance-allowance endpoints
+fn balance_allowance_query(params: BalanceAllowanceParams) -> HashMap<String, String> {
+    let mut query_params = HashMap::new();
+    let asset_type_str = match params.asset_type {
+        AssetType::Collateral => "COLLATERAL",
+        AssetType::Conditional => "CONDITIONAL",
+    };
+    query_params.insert("asset_type".to_string(), asset_type_str.to_string());
+
+    if let Some(token_id) = params.token_id {
+        query_params.insert("token_id".to_string(), token_id);
+    }
+
+    query_params
+}
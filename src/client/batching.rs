@@ -0,0 +1,266 @@
+use crate::client::ClobClient;
+use crate::errors::{ClobError, ClobResult};
+use crate::types::*;
+use futures::channel::oneshot;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Coalesces single-token reads for a configured token set into batch requests
+pub(crate) struct ReadBatcher {
+    /// How long the first caller waits for others to join its batch
+    window: Duration,
+
+    /// Tokens whose reads are batched; other tokens use the single-token endpoints
+    token_ids: HashSet<String>,
+
+    books: Batch<String, OrderBookSummary>,
+    prices: Batch<(String, Side), Price>,
+    midpoints: Batch<String, Midpoint>,
+}
+
+impl ReadBatcher {
+    fn new(token_ids: Vec<String>, window: Duration) -> Self {
+        Self {
+            window,
+            token_ids: token_ids.into_iter().collect(),
+            books: Batch::new(),
+            prices: Batch::new(),
+            midpoints: Batch::new(),
+        }
+    }
+}
+
+type Waiter<K, V> = (K, oneshot::Sender<ClobResult<V>>);
+
+/// Pending requests for one batch endpoint
+///
+/// The first caller to join an empty batch becomes its leader: it waits for the window,
+/// sends one batch request for every queued key, then hands each waiter its result.
+struct Batch<K, V> {
+    pending: Mutex<Vec<Waiter<K, V>>>,
+}
+
+impl<K, V> Batch<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    fn new() -> Self {
+        Self {
+            pending: Mutex::new(Vec::new()),
+        }
+    }
+
+    async fn load<F, Fut>(
+        &self,
+        window: Duration,
+        key: K,
+        missing: fn(&K) -> ClobError,
+        fetch: F,
+    ) -> ClobResult<V>
+    where
+        F: FnOnce(Vec<K>) -> Fut,
+        Fut: Future<Output = ClobResult<HashMap<K, V>>>,
+    {
+        let (tx, rx) = oneshot::channel();
+        let is_leader = {
+            let mut pending = self.pending.lock().unwrap();
+            pending.push((key, tx));
+            pending.len() == 1
+        };
+
+        if is_leader {
+            // If the leader is cancelled, drop the queued senders so waiters fail fast
+            let mut guard = LeaderGuard {
+                pending: &self.pending,
+                armed: true,
+            };
+
+            crate::time::sleep(window).await;
+            let waiters = std::mem::take(&mut *self.pending.lock().unwrap());
+            guard.armed = false;
+
+            let mut keys: Vec<K> = Vec::new();
+            for (key, _) in &waiters {
+                if !keys.contains(key) {
+                    keys.push(key.clone());
+                }
+            }
+
+            let result = fetch(keys).await;
+            for (key, tx) in waiters {
+                let value = match &result {
+                    Ok(values) => values.get(&key).cloned().ok_or_else(|| missing(&key)),
+                    Err(e) => Err(share_error(e)),
+                };
+                let _ = tx.send(value);
+            }
+        }
+
+        rx.await
+            .map_err(|_| ClobError::Other("Batched read was cancelled".to_string()))?
+    }
+}
+
+struct LeaderGuard<'a, K, V> {
+    pending: &'a Mutex<Vec<Waiter<K, V>>>,
+    armed: bool,
+}
+
+impl<K, V> Drop for LeaderGuard<'_, K, V> {
+    fn drop(&mut self) {
+        if self.armed {
+            if let Ok(mut pending) = self.pending.lock() {
+                pending.clear();
+            }
+        }
+    }
+}
+
+/// Copies a batch error for every waiter (`ClobError` is not `Clone`)
+fn share_error(e: &ClobError) -> ClobError {
+    match e {
+        ClobError::ApiError {
+            message,
+            status,
+            error_code,
+        } => ClobError::ApiError {
+            message: message.clone(),
+            status: *status,
+            error_code: error_code.clone(),
+        },
+        ClobError::NoOrderbook => ClobError::NoOrderbook,
+        other => ClobError::Other(other.to_string()),
+    }
+}
+
+impl ClobClient {
+    /// Coalesces `get_order_book`, `get_price` and `get_midpoint` calls for `token_ids`
+    /// into batch requests
+    ///
+    /// Concurrent reads issued within `window` of each other share a single request to
+    /// `/books`, `/prices` or `/midpoints`. Reads for other tokens are unaffected.
+    pub fn enable_read_batching(&mut self, token_ids: Vec<String>, window: Duration) {
+        self.read_batcher = Some(Arc::new(ReadBatcher::new(token_ids, window)));
+    }
+
+    /// Sends every read individually again
+    pub fn disable_read_batching(&mut self) {
+        self.read_batcher = None;
+    }
+
+    fn read_batcher_for(&self, token_id: &str) -> Option<&ReadBatcher> {
+        self.read_batcher
+            .as_deref()
+            .filter(|batcher| batcher.token_ids.contains(token_id))
+    }
+
+    /// Batched `get_order_book`, or `None` if the token is not batched
+    pub(crate) async fn batched_order_book(
+        &self,
+        token_id: &str,
+    ) -> Option<ClobResult<OrderBookSummary>> {
+        let batcher = self.read_batcher_for(token_id)?;
+
+        let result = batcher
+            .books
+            .load(
+                batcher.window,
+                token_id.to_string(),
+                |_| ClobError::NoOrderbook,
+                |token_ids| async move {
+                    let params = token_ids
+                        .into_iter()
+                        .map(|token_id| OrderBookParams {
+                            token_id,
+                            side: None,
+                        })
+                        .collect();
+                    let books = self.get_order_books(params).await?;
+                    Ok(books
+                        .into_iter()
+                        .map(|book| (book.asset_id.clone(), book))
+                        .collect())
+                },
+            )
+            .await;
+
+        Some(result)
+    }
+
+    /// Batched `get_price`, or `None` if the token is not batched
+    pub(crate) async fn batched_price(&self, params: &PriceParams) -> Option<ClobResult<Price>> {
+        let batcher = self.read_batcher_for(&params.token_id)?;
+
+        let result = batcher
+            .prices
+            .load(
+                batcher.window,
+                (params.token_id.clone(), params.side),
+                |(token_id, _)| ClobError::Other(format!("No price returned for {}", token_id)),
+                |keys| async move {
+                    let params = keys
+                        .iter()
+                        .map(|(token_id, side)| PriceParams {
+                            token_id: token_id.clone(),
+                            side: *side,
+                        })
+                        .collect();
+                    let response = self.get_prices(params).await?;
+
+                    let mut prices = HashMap::new();
+                    for key in keys {
+                        let (token_id, side) = &key;
+                        let price = response
+                            .get(token_id)
+                            .and_then(|sides| sides.get(side))
+                            .cloned();
+                        if let Some(price) = price {
+                            prices.insert(key, Price { price });
+                        }
+                    }
+                    Ok(prices)
+                },
+            )
+            .await;
+
+        Some(result)
+    }
+
+    /// Batched `get_midpoint`, or `None` if the token is not batched
+    pub(crate) async fn batched_midpoint(&self, token_id: &str) -> Option<ClobResult<Midpoint>> {
+        let batcher = self.read_batcher_for(token_id)?;
+
+        let result = batcher
+            .midpoints
+            .load(
+                batcher.window,
+                token_id.to_string(),
+                |token_id| ClobError::Other(format!("No midpoint returned for {}", token_id)),
+                |token_ids| async move {
+                    let params = token_ids
+                        .iter()
+                        .map(|token_id| OrderBookParams {
+                            token_id: token_id.clone(),
+                            side: None,
+                        })
+                        .collect();
+                    let response = self.get_midpoints(params).await?;
+
+                    let mut midpoints = HashMap::new();
+                    for token_id in token_ids {
+                        if let Some(mid) = response.get(&token_id).cloned() {
+                            midpoints.insert(token_id, Midpoint { mid });
+                        }
+                    }
+                    Ok(midpoints)
+                },
+            )
+            .await;
+
+        Some(result)
+    }
+}
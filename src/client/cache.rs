@@ -0,0 +1,52 @@
+use crate::time::Instant;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::Duration;
+
+/// Thread-safe cache whose entries go stale `ttl` after they were stored
+///
+/// `None` keeps entries until they are removed. Stale entries are not evicted, just
+/// ignored until the next `insert` for the key replaces them.
+pub(crate) struct TtlCache<V> {
+    entries: RwLock<HashMap<String, (Instant, V)>>,
+    ttl: RwLock<Option<Duration>>,
+}
+
+impl<V: Clone> TtlCache<V> {
+    pub(crate) fn new(ttl: Option<Duration>) -> Self {
+        Self {
+            entries: RwLock::new(HashMap::new()),
+            ttl: RwLock::new(ttl),
+        }
+    }
+
+    /// The cached value for `key`, unless missing or stale
+    pub(crate) fn get(&self, key: &str) -> Option<V> {
+        let entries = self.entries.read().unwrap();
+        let (stored_at, value) = entries.get(key)?;
+        match *self.ttl.read().unwrap() {
+            Some(ttl) if stored_at.elapsed() >= ttl => None,
+            _ => Some(value.clone()),
+        }
+    }
+
+    pub(crate) fn insert(&self, key: String, value: V) {
+        self.entries
+            .write()
+            .unwrap()
+            .insert(key, (Instant::now(), value));
+    }
+
+    pub(crate) fn remove(&self, key: &str) {
+        self.entries.write().unwrap().remove(key);
+    }
+
+    pub(crate) fn clear(&self) {
+        self.entries.write().unwrap().clear();
+    }
+
+    /// Changes the TTL, applied to entries already stored as well
+    pub(crate) fn set_ttl(&self, ttl: Option<Duration>) {
+        *self.ttl.write().unwrap() = ttl;
+    }
+}
@@ -1,9 +1,16 @@
 use crate::client::ClobClient;
+use crate::constants::{
+    END_CURSOR, GAMMA_PAGE_LIMIT, INITIAL_CURSOR, MARKET_CONSISTENCY_TOLERANCE,
+    MAX_CONCURRENT_REQUESTS,
+};
 use crate::endpoints::endpoints;
 use crate::errors::{ClobError, ClobResult};
 use crate::types::*;
+use crate::utilities::{dedup_by_key, value_to_string};
+use futures::stream::{self, StreamExt, TryStreamExt};
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::future::Future;
 
 impl ClobClient {
     // ===================================
@@ -12,11 +19,11 @@ impl ClobClient {
 
     // Server
     pub async fn get_ok(&self) -> ClobResult<serde_json::Value> {
-        self.http_client.get("/", None, None).await
+        self.http_client.get_json("/", None, None).await
     }
 
     pub async fn get_server_time(&self) -> ClobResult<u64> {
-        self.http_client.get(endpoints::TIME, None, None).await
+        self.http_client.get_json(endpoints::TIME, None, None).await
     }
 
     // Tags
@@ -38,7 +45,7 @@ impl ClobClient {
         }
 
         self.gamma_api_client
-            .get(endpoint, None, Some(query_params))
+            .get_json(endpoint, None, Some(query_params))
             .await
     }
 
@@ -48,7 +55,7 @@ impl ClobClient {
         }
 
         let endpoint = format!("{}{}", endpoints::GET_TAG_BY_SLUG, slug);
-        self.gamma_api_client.get(&endpoint, None, None).await
+        self.gamma_api_client.get_json(&endpoint, None, None).await
     }
 
     pub async fn get_popular_tags(&self) -> ClobResult<Vec<Tag>> {
@@ -80,19 +87,19 @@ impl ClobClient {
         }
 
         self.gamma_api_client
-            .get(endpoint, None, Some(query_params))
+            .get_json(endpoint, None, Some(query_params))
             .await
     }
 
     pub async fn get_events_by_id(&self, id: &str) -> ClobResult<Event> {
         let endpoint = format!("{}{}", endpoints::GET_EVENT, id);
-        self.gamma_api_client.get(&endpoint, None, None).await
+        self.gamma_api_client.get_json(&endpoint, None, None).await
     }
 
     pub async fn get_event_by_slug(&self, slug: &str) -> ClobResult<Event> {
         let endpoint = format!("{}{}", endpoints::GET_EVENT_BY_SLUG, slug);
 
-        self.gamma_api_client.get(&endpoint, None, None).await
+        self.gamma_api_client.get_json(&endpoint, None, None).await
     }
 
     // Markets
@@ -118,88 +125,281 @@ impl ClobClient {
         if let Some(closed) = params.closed {
             query_params.insert("closed".to_string(), closed.to_string());
         }
+        if let Some(tag_id) = params.tag_id {
+            query_params.insert("tag_id".to_string(), tag_id.to_string());
+        }
 
         self.gamma_api_client
-            .get(endpoint, None, Some(query_params))
+            .get_json(endpoint, None, Some(query_params))
             .await
     }
 
+    /// Gets all open markets under a tag that are currently accepting orders
+    ///
+    /// Resolves the tag by slug, pages through its open markets and keeps those with
+    /// `accepting_orders` and `enable_order_book` set.
+    pub async fn get_tradeable_markets_for_tag(&self, slug: &str) -> ClobResult<Vec<Market>> {
+        let tag = self.get_tag_by_slug(slug).await?;
+        let tag_id: u64 = tag
+            .id
+            .parse()
+            .map_err(|_| ClobError::Other(format!("Invalid tag id: {}", tag.id)))?;
+
+        let mut results = Vec::new();
+        let mut offset = 0;
+
+        loop {
+            let page = self
+                .get_markets(MarketParams {
+                    limit: Some(GAMMA_PAGE_LIMIT),
+                    offset: Some(offset),
+                    order: None,
+                    ascending: None,
+                    condition_id: None,
+                    closed: Some(false),
+                    tag_id: Some(tag_id),
+                })
+                .await?;
+
+            let page_len = page.len() as u64;
+            results.extend(page.into_iter().filter(Market::is_tradeable));
+
+            if page_len < GAMMA_PAGE_LIMIT {
+                break;
+            }
+            offset += page_len;
+        }
+
+        Ok(results)
+    }
+
+    /// Checks that a binary market's YES + NO midpoints sum to roughly 1.0
+    ///
+    /// Resolves the market's outcome tokens, fetches both midpoints in one request and
+    /// flags deviations above `MARKET_CONSISTENCY_TOLERANCE`.
+    pub async fn check_market_consistency(
+        &self,
+        condition_id: &str,
+    ) -> ClobResult<ConsistencyReport> {
+        let markets = self
+            .get_markets(MarketParams {
+                limit: Some(1),
+                offset: None,
+                order: None,
+                ascending: None,
+                condition_id: Some(condition_id.to_string()),
+                closed: None,
+                tag_id: None,
+            })
+            .await?;
+        let market = markets
+            .first()
+            .ok_or_else(|| ClobError::Other(format!("Market not found: {}", condition_id)))?;
+
+        let token_ids = market.clob_token_ids_parsed()?;
+        if token_ids.len() != 2 {
+            return Err(ClobError::Other(format!(
+                "Expected 2 outcome tokens for {}, found {}",
+                condition_id,
+                token_ids.len()
+            )));
+        }
+
+        let params = token_ids
+            .iter()
+            .map(|token_id| OrderBookParams {
+                token_id: token_id.clone(),
+                side: None,
+            })
+            .collect();
+        let response = self.get_midpoints(params).await?;
+
+        let midpoints = token_ids
+            .iter()
+            .map(|token_id| {
+                response
+                    .get(token_id)
+                    .and_then(|mid| mid.parse().ok())
+                    .ok_or_else(|| {
+                        ClobError::Other(format!("No midpoint returned for {}", token_id))
+                    })
+            })
+            .collect::<ClobResult<Vec<f64>>>()?;
+
+        let sum: f64 = midpoints.iter().sum();
+        let deviation = (sum - 1.0).abs();
+
+        Ok(ConsistencyReport {
+            condition_id: condition_id.to_string(),
+            token_ids,
+            midpoints,
+            sum,
+            deviation,
+            flagged: deviation > MARKET_CONSISTENCY_TOLERANCE,
+        })
+    }
+
     pub async fn get_market_by_id(&self, id: &str) -> ClobResult<Market> {
         let endpoint = format!("{}{}", endpoints::GET_MARKET, id);
-        self.gamma_api_client.get(&endpoint, None, None).await
+        self.gamma_api_client.get_json(&endpoint, None, None).await
     }
 
     pub async fn get_market_by_slug(&self, slug: &str) -> ClobResult<Market> {
         let endpoint = format!("{}{}", endpoints::GET_MARKET_BY_SLUG, slug);
-        self.gamma_api_client.get(&endpoint, None, None).await
+        self.gamma_api_client.get_json(&endpoint, None, None).await
+    }
+
+    /// Gets the markets currently eligible for liquidity rewards, from the CLOB host (with
+    /// automatic pagination)
+    pub async fn get_sampling_markets(&self) -> ClobResult<Vec<SimplifiedMarket>> {
+        self.get_clob_markets(endpoints::GET_SAMPLING_MARKETS).await
+    }
+
+    /// Gets all CLOB markets in simplified form (with automatic pagination)
+    pub async fn get_simplified_markets(&self) -> ClobResult<Vec<SimplifiedMarket>> {
+        self.get_clob_markets(endpoints::GET_SIMPLIFIED_MARKETS)
+            .await
+    }
+
+    /// Gets the reward-eligible CLOB markets in simplified form (with automatic pagination)
+    pub async fn get_sampling_simplified_markets(&self) -> ClobResult<Vec<SimplifiedMarket>> {
+        self.get_clob_markets(endpoints::GET_SAMPLING_SIMPLIFIED_MARKETS)
+            .await
+    }
+
+    /// Walks a cursor-paginated CLOB market listing from `INITIAL_CURSOR` to `END_CURSOR`
+    async fn get_clob_markets(&self, endpoint: &str) -> ClobResult<Vec<SimplifiedMarket>> {
+        let mut results = Vec::new();
+        let mut next_cursor = INITIAL_CURSOR.to_string();
+
+        while next_cursor != END_CURSOR {
+            let mut query_params = HashMap::new();
+            query_params.insert("next_cursor".to_string(), next_cursor.clone());
+
+            let response: PaginationPayload<SimplifiedMarket> = self
+                .http_client
+                .get_json(endpoint, None, Some(query_params))
+                .await?;
+
+            next_cursor = response.next_cursor;
+            results.extend(response.data);
+        }
+
+        Ok(results)
     }
 
     // Orderbook
     pub async fn get_order_book(&self, token_id: &str) -> ClobResult<OrderBookSummary> {
+        if let Some(result) = self.batched_order_book(token_id).await {
+            return result;
+        }
+
         let mut params = HashMap::new();
         params.insert("token_id".to_string(), token_id.to_string());
 
         self.http_client
-            .get(endpoints::GET_ORDER_BOOK, None, Some(params))
+            .get_json(endpoints::GET_ORDER_BOOK, None, Some(params))
             .await
     }
 
+    /// Gets a token's tick size, minimum order size and neg-risk flag from a single `/book`
+    /// fetch, instead of the separate tick size and neg-risk endpoints
+    pub async fn get_market_constraints(&self, token_id: &str) -> ClobResult<MarketConstraints> {
+        self.get_order_book(token_id).await?.constraints()
+    }
+
+    /// Gets orderbooks for several tokens
+    ///
+    /// Duplicate token ids are sent once; match results by `asset_id`.
     pub async fn get_order_books(
         &self,
         params: Vec<OrderBookParams>,
     ) -> ClobResult<Vec<OrderBookSummary>> {
+        let params = dedup_by_key(params, |p| p.token_id.clone());
         self.http_client
-            .post(endpoints::GET_ORDER_BOOKS, None, Some(params), None)
+            .post_json(endpoints::GET_ORDER_BOOKS, None, Some(params), None)
             .await
     }
 
+    /// Gets a token's orderbook and checks its `hash` against the contents
+    ///
+    /// Fails with `OrderbookHashMismatch` if the book was corrupted or truncated.
+    pub async fn get_verified_order_book(&self, token_id: &str) -> ClobResult<OrderBookSummary> {
+        let orderbook = self.get_order_book(token_id).await?;
+        let actual = self.get_order_book_hash(&mut orderbook.clone());
+        if actual != orderbook.hash {
+            return Err(ClobError::OrderbookHashMismatch {
+                expected: orderbook.hash,
+                actual,
+            });
+        }
+        Ok(orderbook)
+    }
+
     pub fn get_order_book_hash(&self, orderbook: &mut OrderBookSummary) -> String {
         crate::utilities::generate_orderbook_summary_hash(orderbook)
     }
 
     // Token
-    pub async fn get_spreads(&self, params: Vec<SpreadsParams>) -> ClobResult<serde_json::Value> {
-        self.http_client
-            .post(endpoints::GET_SPREADS, None, Some(params), None)
-            .await
+    /// Gets spreads for several tokens, keyed by token id
+    ///
+    /// Duplicate token ids are sent once.
+    pub async fn get_spreads(&self, params: Vec<SpreadsParams>) -> ClobResult<PricesResponse> {
+        let params = dedup_by_key(params, |p| p.token_id.clone());
+        let response = self
+            .http_client
+            .post_json(endpoints::GET_SPREADS, None, Some(params), None)
+            .await?;
+        prices_from_value(response)
     }
 
     pub async fn get_tick_size(&self, token_id: &str) -> ClobResult<TickSize> {
         // Check cache first
-        if let Some(tick_size) = self.tick_sizes.read().unwrap().get(token_id) {
-            return Ok(*tick_size);
+        if let Some(tick_size) = self.tick_sizes.get(token_id) {
+            return Ok(tick_size);
         }
 
         // Fetch from API
-        let mut params = HashMap::new();
-        params.insert("token_id".to_string(), token_id.to_string());
+        let tick_size = self.get_tick_size_info(token_id).await?.tick_size()?;
 
-        #[derive(Deserialize)]
-        struct TickSizeResponse {
-            minimum_tick_size: f64,
+        // Cache the result
+        self.tick_sizes.insert(token_id.to_string(), tick_size);
+
+        Ok(tick_size)
+    }
+
+    /// Gets a token's minimum order size in shares, from its orderbook
+    ///
+    /// Cached like `get_tick_size`; orders below it are rejected by the `create_*` methods.
+    pub async fn get_min_order_size(&self, token_id: &str) -> ClobResult<f64> {
+        // Check cache first
+        if let Some(min_order_size) = self.min_order_sizes.get(token_id) {
+            return Ok(min_order_size);
         }
 
-        let response: TickSizeResponse = self
-            .http_client
-            .get(endpoints::GET_TICK_SIZE, None, Some(params))
-            .await?;
-        let tick_size_str = format!("{}", response.minimum_tick_size);
-        let tick_size = crate::utilities::parse_tick_size(&tick_size_str).ok_or_else(|| {
-            ClobError::Other(format!("Invalid tick size: {}", response.minimum_tick_size))
-        })?;
+        // Fetch from API
+        let min_order_size = self.get_market_constraints(token_id).await?.min_order_size;
 
         // Cache the result
-        self.tick_sizes
-            .write()
-            .unwrap()
-            .insert(token_id.to_string(), tick_size);
+        self.min_order_sizes
+            .insert(token_id.to_string(), min_order_size);
 
-        Ok(tick_size)
+        Ok(min_order_size)
+    }
+
+    /// Gets the full `/tick-size` response for a token (always fetched, not cached)
+    pub async fn get_tick_size_info(&self, token_id: &str) -> ClobResult<TickSizeInfo> {
+        let mut params = HashMap::new();
+        params.insert("token_id".to_string(), token_id.to_string());
+
+        self.http_client
+            .get_json(endpoints::GET_TICK_SIZE, None, Some(params))
+            .await
     }
 
     pub async fn get_neg_risk(&self, token_id: &str) -> ClobResult<bool> {
         // Check cache first
-        if let Some(&neg_risk) = self.neg_risk.read().unwrap().get(token_id) {
+        if let Some(neg_risk) = self.neg_risk.get(token_id) {
             return Ok(neg_risk);
         }
 
@@ -214,23 +414,22 @@ impl ClobClient {
 
         let response: NegRiskResponse = self
             .http_client
-            .get(endpoints::GET_NEG_RISK, None, Some(params))
+            .get_json(endpoints::GET_NEG_RISK, None, Some(params))
             .await?;
 
         // Cache the result
         self.neg_risk
-            .write()
-            .unwrap()
             .insert(token_id.to_string(), response.neg_risk);
 
         Ok(response.neg_risk)
     }
 
+    /// Gets a token's fee rate, cached for `CacheTtlConfig::fee_rate` (10 minutes by default)
     pub async fn get_fee_rate_bps(&self, token_id: &str) -> ClobResult<u32> {
         // Check cache first
-        // if let Some(&fee_rate) = self.fee_rates.borrow().get(token_id) {
-        //     return Ok(fee_rate);
-        // }
+        if let Some(fee_rate) = self.fee_rates.get(token_id) {
+            return Ok(fee_rate);
+        }
 
         // Fetch from API
         let mut params = HashMap::new();
@@ -243,53 +442,156 @@ impl ClobClient {
 
         let response: FeeRateResponse = self
             .http_client
-            .get(endpoints::GET_FEE_RATE, None, Some(params))
+            .get_json(endpoints::GET_FEE_RATE, None, Some(params))
             .await?;
 
         // Cache the result
         self.fee_rates
-            .write()
-            .unwrap()
             .insert(token_id.to_string(), response.base_fee);
 
         Ok(response.base_fee)
     }
 
+    /// Gets the tick sizes of many tokens, fetching uncached ones concurrently
+    ///
+    /// At most `MAX_CONCURRENT_REQUESTS` lookups are in flight; results are cached like
+    /// `get_tick_size`. The first failed lookup fails the whole call.
+    pub async fn get_tick_sizes(&self, token_ids: &[&str]) -> ClobResult<TickSizes> {
+        fetch_per_token(token_ids, |token_id| self.get_tick_size(token_id)).await
+    }
+
+    /// Gets the neg-risk flags of many tokens, fetching uncached ones concurrently
+    ///
+    /// At most `MAX_CONCURRENT_REQUESTS` lookups are in flight; results are cached like
+    /// `get_neg_risk`. The first failed lookup fails the whole call.
+    pub async fn get_neg_risks(&self, token_ids: &[&str]) -> ClobResult<NegRisk> {
+        fetch_per_token(token_ids, |token_id| self.get_neg_risk(token_id)).await
+    }
+
+    /// Gets the fee rates of many tokens, fetching uncached or stale ones concurrently
+    ///
+    /// At most `MAX_CONCURRENT_REQUESTS` lookups are in flight; results are cached like
+    /// `get_fee_rate_bps`. The first failed lookup fails the whole call.
+    pub async fn get_fee_rates(&self, token_ids: &[&str]) -> ClobResult<FeeRates> {
+        fetch_per_token(token_ids, |token_id| self.get_fee_rate_bps(token_id)).await
+    }
+
+    /// Gets the (YES, NO) token ids of a market by its condition id
+    ///
+    /// Fetched from the CLOB market and cached for `CacheTtlConfig::token_pairs` (an hour by
+    /// default). Tokens are matched by their "Yes"/"No" outcome, falling back to the order
+    /// the CLOB lists them in.
+    pub async fn token_pair_for_condition(
+        &self,
+        condition_id: &str,
+    ) -> ClobResult<(String, String)> {
+        // Check cache first
+        if let Some(pair) = self.token_pairs.get(condition_id) {
+            return Ok(pair);
+        }
+
+        // Fetch from API
+        #[derive(Deserialize)]
+        struct ClobMarketToken {
+            token_id: String,
+            #[serde(default)]
+            outcome: String,
+        }
+
+        #[derive(Deserialize)]
+        struct ClobMarketResponse {
+            tokens: Vec<ClobMarketToken>,
+        }
+
+        let endpoint = format!("{}{}", endpoints::GET_CLOB_MARKET, condition_id);
+        let response: ClobMarketResponse = self.http_client.get_json(&endpoint, None, None).await?;
+
+        let token_count = response.tokens.len();
+        let [first, second]: [ClobMarketToken; 2] = response.tokens.try_into().map_err(|_| {
+            ClobError::Other(format!(
+                "Market {} has {} tokens, expected 2",
+                condition_id, token_count
+            ))
+        })?;
+        let pair = if first.outcome.eq_ignore_ascii_case("no")
+            && second.outcome.eq_ignore_ascii_case("yes")
+        {
+            (second.token_id, first.token_id)
+        } else {
+            (first.token_id, second.token_id)
+        };
+
+        // Cache the result
+        self.token_pairs
+            .insert(condition_id.to_string(), pair.clone());
+
+        Ok(pair)
+    }
+
     // Prices
     pub async fn get_price(&self, params: PriceParams) -> ClobResult<Price> {
+        if let Some(result) = self.batched_price(&params).await {
+            return result;
+        }
+
         let mut query_params = HashMap::new();
         query_params.insert("token_id".to_string(), params.token_id.to_string());
         query_params.insert("side".to_string(), params.side.to_uppercase());
 
         self.http_client
-            .get(endpoints::GET_PRICE, None, Some(query_params))
+            .get_json(endpoints::GET_PRICE, None, Some(query_params))
             .await
     }
 
-    pub async fn get_prices(&self, params: Vec<PriceParams>) -> ClobResult<serde_json::Value> {
+    /// Gets prices for several tokens, keyed by token id then side
+    ///
+    /// Duplicate (token_id, side) entries are sent once.
+    pub async fn get_prices(&self, params: Vec<PriceParams>) -> ClobResult<PricesResponse> {
+        prices_from_value(self.get_prices_raw(params).await?)
+    }
+
+    /// Same as `get_prices`, returning the response body untouched (for fields this client
+    /// doesn't know yet)
+    pub async fn get_prices_raw(&self, params: Vec<PriceParams>) -> ClobResult<serde_json::Value> {
+        let params = dedup_by_key(params, |p| (p.token_id.clone(), p.side));
         self.http_client
-            .post(endpoints::GET_PRICES, None, Some(params), None)
+            .post_json(endpoints::GET_PRICES, None, Some(params), None)
             .await
     }
 
     pub async fn get_midpoint(&self, token_id: &str) -> ClobResult<Midpoint> {
+        if let Some(result) = self.batched_midpoint(token_id).await {
+            return result;
+        }
+
         let mut params = HashMap::new();
         params.insert("token_id".to_string(), token_id.to_string());
 
         self.http_client
-            .get(endpoints::GET_MIDPOINT, None, Some(params))
+            .get_json(endpoints::GET_MIDPOINT, None, Some(params))
             .await
     }
 
+    /// Gets midpoints for several tokens, keyed by token id
+    ///
+    /// Duplicate token ids are sent once.
     pub async fn get_midpoints(
         &self,
         params: Vec<OrderBookParams>,
-    ) -> ClobResult<serde_json::Value> {
-        self.http_client
-            .post(endpoints::GET_MIDPOINTS, None, Some(params), None)
-            .await
+    ) -> ClobResult<MidpointsResponse> {
+        let params = dedup_by_key(params, |p| p.token_id.clone());
+        let response: HashMap<String, serde_json::Value> = self
+            .http_client
+            .post_json(endpoints::GET_MIDPOINTS, None, Some(params), None)
+            .await?;
+
+        Ok(response
+            .into_iter()
+            .filter_map(|(token_id, mid)| value_to_string(&mid).map(|mid| (token_id, mid)))
+            .collect())
     }
 
+    /// Gets the price history of a token, sorted by timestamp (oldest first)
     pub async fn get_prices_history(&self, params: PriceHistoryParams) -> ClobResult<HistoryPrice> {
         // Validate: either (start_ts AND end_ts) OR interval must be provided
         let has_time_range = params.start_ts.is_some() && params.end_ts.is_some();
@@ -300,6 +602,20 @@ impl ClobClient {
                 "Either (start_ts and end_ts) or interval must be provided".to_string(),
             ));
         }
+        if let (Some(start_ts), Some(end_ts)) = (params.start_ts, params.end_ts) {
+            if start_ts >= end_ts {
+                return Err(ClobError::Other(format!(
+                    "start_ts ({}) must be before end_ts ({})",
+                    start_ts, end_ts
+                )));
+            }
+        }
+        // Fidelity is the spacing of points in minutes
+        if params.fidelity == 0 {
+            return Err(ClobError::Other(
+                "fidelity must be at least 1 minute".to_string(),
+            ));
+        }
 
         let mut query_params = HashMap::new();
 
@@ -312,29 +628,93 @@ impl ClobClient {
             query_params.insert("endTs".to_string(), end_ts.to_string());
         }
         if let Some(interval) = params.interval {
-            query_params.insert("interval".to_string(), interval.to_string());
+            query_params.insert("interval".to_string(), interval.as_str().to_string());
         }
 
+        let mut history: HistoryPrice = self
+            .http_client
+            .get_json(endpoints::GET_PRICES_HISTORY, None, Some(query_params))
+            .await?;
+        history.sort();
+
+        Ok(history)
+    }
+
+    /// Gets the price and side of a token's most recent trade
+    pub async fn get_last_trade_price(&self, token_id: &str) -> ClobResult<LastTradePrice> {
+        let mut params = HashMap::new();
+        params.insert("token_id".to_string(), token_id.to_string());
+
         self.http_client
-            .get(endpoints::GET_PRICES_HISTORY, None, Some(query_params))
+            .get_json(endpoints::GET_LAST_TRADE_PRICE, None, Some(params))
             .await
     }
 
-    pub async fn get_last_trade_price(&self, token_id: &str) -> ClobResult<serde_json::Value> {
+    /// Same as `get_last_trade_price`, with the price parsed
+    pub async fn get_last_trade_price_f64(&self, token_id: &str) -> ClobResult<f64> {
+        self.get_last_trade_price(token_id).await?.price_f64()
+    }
+
+    /// Same as `get_last_trade_price`, returning the response body untyped
+    pub async fn get_last_trade_price_raw(&self, token_id: &str) -> ClobResult<serde_json::Value> {
         let mut params = HashMap::new();
         params.insert("token_id".to_string(), token_id.to_string());
 
         self.http_client
-            .get(endpoints::GET_LAST_TRADE_PRICE, None, Some(params))
+            .get_json(endpoints::GET_LAST_TRADE_PRICE, None, Some(params))
             .await
     }
 
+    /// Gets last trade prices for several tokens
+    ///
+    /// Duplicate token ids are sent once; match results by `token_id`.
     pub async fn get_last_trades_prices(
         &self,
         params: Vec<LastTradePriceParams>,
     ) -> ClobResult<serde_json::Value> {
+        let params = dedup_by_key(params, |p| p.token_id.clone());
         self.http_client
-            .post(endpoints::GET_LAST_TRADES_PRICES, None, Some(params), None)
+            .post_json(endpoints::GET_LAST_TRADES_PRICES, None, Some(params), None)
             .await
     }
-}
\ No newline at end of file
+}
+
+/// Runs a per-token lookup for each distinct token, `MAX_CONCURRENT_REQUESTS` at a time
+async fn fetch_per_token<'a, T, F, Fut>(
+    token_ids: &[&'a str],
+    fetch: F,
+) -> ClobResult<HashMap<String, T>>
+where
+    F: Fn(&'a str) -> Fut,
+    Fut: Future<Output = ClobResult<T>>,
+{
+    let mut unique: Vec<&str> = token_ids.to_vec();
+    unique.sort_unstable();
+    unique.dedup();
+
+    stream::iter(unique)
+        .map(|token_id| {
+            let lookup = fetch(token_id);
+            async move { Ok((token_id.to_string(), lookup.await?)) }
+        })
+        .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+        .try_collect()
+        .await
+}
+
+/// Parses a `{ token_id: { "BUY"|"SELL": price } }` body, accepting numeric prices too
+fn prices_from_value(response: serde_json::Value) -> ClobResult<PricesResponse> {
+    let response: HashMap<String, HashMap<Side, serde_json::Value>> =
+        serde_json::from_value(response)?;
+
+    Ok(response
+        .into_iter()
+        .map(|(token_id, sides)| {
+            let sides = sides
+                .into_iter()
+                .filter_map(|(side, price)| value_to_string(&price).map(|price| (side, price)))
+                .collect();
+            (token_id, sides)
+        })
+        .collect())
+}
@@ -1,13 +1,64 @@
 use crate::client::ClobClient;
-use crate::constants::{END_CURSOR, INITIAL_CURSOR};
+use crate::constants::{END_CURSOR, INITIAL_CURSOR, MAX_CONCURRENT_REQUESTS};
 use crate::endpoints::endpoints;
 use crate::errors::{ClobError, ClobResult};
-use crate::headers::create_l2_headers;
 use crate::types::*;
+use futures::stream::{self, StreamExt, TryStreamExt};
 use serde::Deserialize;
 use std::collections::HashMap;
 
 impl ClobClient {
+    // ===================================
+    // Public Methods
+    // ===================================
+
+    /// Gets raw rewards entries for a market (with automatic pagination)
+    pub async fn get_raw_rewards_for_market(
+        &self,
+        condition_id: &str,
+    ) -> ClobResult<Vec<MarketReward>> {
+        let endpoint = format!("{}{}", endpoints::GET_REWARDS_MARKETS, condition_id);
+
+        let mut results = Vec::new();
+        let mut next_cursor = INITIAL_CURSOR.to_string();
+
+        while next_cursor != END_CURSOR {
+            let mut query_params = HashMap::new();
+            query_params.insert("next_cursor".to_string(), next_cursor.clone());
+
+            #[derive(Deserialize)]
+            struct MarketRewardResponse {
+                data: Vec<MarketReward>,
+                next_cursor: String,
+            }
+
+            let response: MarketRewardResponse = self
+                .http_client
+                .get_json(&endpoint, None, Some(query_params))
+                .await?;
+
+            next_cursor = response.next_cursor;
+            results.extend(response.data);
+        }
+
+        Ok(results)
+    }
+
+    /// Gets the current rewards config for a market
+    ///
+    /// Selects the latest `rewards_config` entry and merges it with the market's
+    /// `rewards_max_spread`/`rewards_min_size` constraints.
+    pub async fn get_market_reward_config(
+        &self,
+        condition_id: &str,
+    ) -> ClobResult<MarketRewardConfig> {
+        let rewards = self.get_raw_rewards_for_market(condition_id).await?;
+
+        MarketRewardConfig::from_market_rewards(&rewards).ok_or_else(|| {
+            ClobError::Other(format!("No rewards config for market {}", condition_id))
+        })
+    }
+
     // ===================================
     // L2 Auth Methods
     // ===================================
@@ -16,25 +67,12 @@ impl ClobClient {
     pub async fn get_earnings_for_user_for_day(&self, date: &str) -> ClobResult<Vec<UserEarning>> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
         let endpoint_path = endpoints::GET_EARNINGS_FOR_USER_FOR_DAY;
 
         let mut results = Vec::new();
         let mut next_cursor = INITIAL_CURSOR.to_string();
 
         while next_cursor != END_CURSOR {
-            let timestamp = if self.use_server_time {
-                Some(self.get_server_time().await?)
-            } else {
-                None
-            };
-
-            let headers = create_l2_headers(wallet, creds, "GET", endpoint_path, None, timestamp)
-                .await?
-                .to_headers();
-
             let mut query_params = HashMap::new();
             query_params.insert("date".to_string(), date.to_string());
             query_params.insert(
@@ -50,8 +88,7 @@ impl ClobClient {
             }
 
             let response: EarningsResponse = self
-                .http_client
-                .get(endpoint_path, Some(headers), Some(query_params))
+                .send_l2("GET", endpoint_path, None, Some(query_params))
                 .await?;
 
             next_cursor = response.next_cursor;
@@ -68,67 +105,41 @@ impl ClobClient {
     ) -> ClobResult<Vec<TotalUserEarning>> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
         let endpoint_path = endpoints::GET_TOTAL_EARNINGS_FOR_USER_FOR_DAY;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-
-        let headers = create_l2_headers(wallet, creds, "GET", endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
 
         let mut query_params = HashMap::new();
         query_params.insert("date".to_string(), date.to_string());
 
-        self.http_client
-            .get(endpoint_path, Some(headers), Some(query_params))
+        self.send_l2("GET", endpoint_path, None, Some(query_params))
             .await
     }
 
     /// Gets detailed earnings and markets config for the user (with automatic pagination)
     pub async fn get_user_earnings_and_markets_config(
         &self,
-        date: &str,
-        order_by: &str,
-        position: &str,
-        no_competition: bool,
+        params: UserEarningsConfigParams,
     ) -> ClobResult<Vec<UserRewardsEarning>> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
         let endpoint_path = endpoints::GET_REWARDS_EARNINGS_PERCENTAGES;
 
         let mut results = Vec::new();
         let mut next_cursor = INITIAL_CURSOR.to_string();
 
         while next_cursor != END_CURSOR {
-            let timestamp = if self.use_server_time {
-                Some(self.get_server_time().await?)
-            } else {
-                None
-            };
-
-            let headers = create_l2_headers(wallet, creds, "GET", endpoint_path, None, timestamp)
-                .await?
-                .to_headers();
-
             let mut query_params = HashMap::new();
-            query_params.insert("date".to_string(), date.to_string());
+            query_params.insert("date".to_string(), params.date.clone());
             query_params.insert(
                 "signature_type".to_string(),
                 self.signature_type.to_string(),
             );
             query_params.insert("next_cursor".to_string(), next_cursor.clone());
-            query_params.insert("order_by".to_string(), order_by.to_string());
-            query_params.insert("position".to_string(), position.to_string());
-            query_params.insert("no_competition".to_string(), no_competition.to_string());
+            query_params.insert("order_by".to_string(), params.order_by.clone());
+            query_params.insert("position".to_string(), params.position.clone());
+            query_params.insert(
+                "no_competition".to_string(),
+                params.no_competition.to_string(),
+            );
 
             #[derive(Deserialize)]
             struct UserRewardsEarningResponse {
@@ -137,8 +148,7 @@ impl ClobClient {
             }
 
             let response: UserRewardsEarningResponse = self
-                .http_client
-                .get(endpoint_path, Some(headers), Some(query_params))
+                .send_l2("GET", endpoint_path, None, Some(query_params))
                 .await?;
 
             next_cursor = response.next_cursor;
@@ -148,82 +158,78 @@ impl ClobClient {
         Ok(results)
     }
 
-    /// Gets reward distribution percentages
+    /// Gets reward percentages from the user earnings/markets endpoint (`/rewards/user/markets`)
+    ///
+    /// This is the endpoint behind [`ClobClient::get_user_earnings_and_markets_config`]. For the
+    /// user's liquidity reward percentage in each market, use
+    /// [`ClobClient::get_liquidity_reward_percentages`].
     pub async fn get_reward_percentages(&self) -> ClobResult<RewardsPercentages> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
         let endpoint_path = endpoints::GET_REWARDS_EARNINGS_PERCENTAGES;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-
-        let headers = create_l2_headers(wallet, creds, "GET", endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
-
-        self.http_client
-            .get(endpoint_path, Some(headers), None)
-            .await
+
+        self.send_l2("GET", endpoint_path, None, None).await
+    }
+
+    /// Gets the user's liquidity reward percentage in each market (`/rewards/user/percentages`),
+    /// keyed by condition id
+    pub async fn get_liquidity_reward_percentages(&self) -> ClobResult<RewardsPercentages> {
+        self.can_l2_auth()?;
+
+        let endpoint_path = endpoints::GET_LIQUIDITY_REWARD_PERCENTAGES;
+
+        self.send_l2("GET", endpoint_path, None, None).await
     }
 
     /// Checks if an order is eligible for rewards
     pub async fn is_order_scoring(&self, params: OrderScoringParams) -> ClobResult<OrderScoring> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
         let endpoint_path = endpoints::IS_ORDER_SCORING;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-
-        let headers = create_l2_headers(wallet, creds, "GET", endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
 
         let mut query_params = HashMap::new();
         query_params.insert("order_id".to_string(), params.order_id);
 
-        self.http_client
-            .get(endpoint_path, Some(headers), Some(query_params))
+        self.send_l2("GET", endpoint_path, None, Some(query_params))
             .await
     }
 
     /// Checks if multiple orders are eligible for rewards
+    ///
+    /// Every requested id is present in the result; ids the server omitted map to `None`.
+    /// Lists longer than the client's max batch (`set_max_batch`) are checked in several
+    /// concurrent requests.
     pub async fn are_orders_scoring(
         &self,
         params: OrdersScoringParams,
     ) -> ClobResult<OrdersScoring> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
         let endpoint_path = endpoints::ARE_ORDERS_SCORING;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-
-        let headers = create_l2_headers(wallet, creds, "GET", endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
 
-        let mut query_params = HashMap::new();
-        query_params.insert("order_ids".to_string(), params.order_ids.join(","));
-
-        self.http_client
-            .get(endpoint_path, Some(headers), Some(query_params))
-            .await
+        let scoring: HashMap<String, bool> = stream::iter(params.order_ids.chunks(self.max_batch))
+            .map(|chunk| {
+                let mut query_params = HashMap::new();
+                query_params.insert("order_ids".to_string(), chunk.join(","));
+                self.send_l2::<HashMap<String, bool>>(
+                    "GET",
+                    endpoint_path,
+                    None,
+                    Some(query_params),
+                )
+            })
+            .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+            .try_concat()
+            .await?;
+
+        Ok(params
+            .order_ids
+            .into_iter()
+            .map(|order_id| {
+                let is_scoring = scoring.get(&order_id).copied();
+                (order_id, is_scoring)
+            })
+            .collect())
     }
 }
 
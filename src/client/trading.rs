@@ -1,12 +1,24 @@
 use crate::client::ClobClient;
-use crate::constants::{END_CURSOR, INITIAL_CURSOR};
+use crate::constants::{INITIAL_CURSOR, MAX_CONCURRENT_REQUESTS};
 use crate::endpoints::endpoints;
 use crate::errors::{ClobError, ClobResult};
-use crate::headers::create_l2_headers;
-use crate::order_builder::{calculate_buy_market_price, calculate_sell_market_price};
+use crate::order_builder::{
+    calculate_buy_market_price, calculate_liquidity_to_price, calculate_market_order_estimate,
+    calculate_marketable_limit_price, calculate_sell_market_price, check_order_against_book,
+    check_price_bounds, exchange_address, validate_expiration, validate_order_size,
+};
+use crate::pagination::{Page, Paginator};
+use crate::signing::eip712::order_hash;
 use crate::types::*;
+use crate::utilities::{normalize_order_signature, side_wire};
+use alloy_primitives::Address;
+use futures::future::try_join_all;
+use futures::stream::{self, StreamExt, TryStreamExt};
 use rs_order_utils::SignedOrder;
+#[cfg(feature = "decimal")]
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use std::collections::HashMap;
+use std::str::FromStr;
 
 impl ClobClient {
     // ===================================
@@ -29,31 +41,89 @@ impl ClobClient {
         options: Option<CreateOrderOptions>,
     ) -> ClobResult<serde_json::Value> {
         self.can_l1_auth()?;
+        // Also checked when signing; fail here before the lookups below
+        validate_expiration(user_limit_order.order_type, user_limit_order.expiration)?;
 
         let token_id = &user_limit_order.token_id;
-
-        // Resolve tick size
-        let tick_size = if let Some(opts) = &options {
-            opts.tick_size
-        } else {
-            self.get_tick_size(token_id).await?
-        };
-
-        // Resolve fee rate
+        let create_options = self._resolve_create_options(token_id, options).await?;
+        self._check_order_size(token_id, user_limit_order.size).await?;
         let fee_rate_bps = self
             ._resolve_fee_rate_bps(token_id, user_limit_order.fee_rate_bps)
             .await?;
 
-        // Resolve neg_risk
-        let neg_risk = if let Some(opts) = &options {
-            opts.neg_risk.unwrap_or_else(|| false)
-        } else {
-            self.get_neg_risk(token_id).await?
-        };
+        self.create_order_offline(user_limit_order, create_options, fee_rate_bps)
+            .await
+    }
+
+    /// Creates many signed limit orders at once
+    ///
+    /// Tick size, neg-risk, minimum order size and fee rate are looked up once per distinct token (at most
+    /// `MAX_CONCURRENT_REQUESTS` in flight), then every order is signed concurrently.
+    /// `options`, when given, applies to all orders. Results are in the same order as
+    /// `orders`; the first failure fails the whole batch.
+    pub async fn create_orders(
+        &self,
+        orders: &[UserLimitOrder],
+        options: Option<CreateOrderOptions>,
+    ) -> ClobResult<Vec<serde_json::Value>> {
+        self.can_l1_auth()?;
+
+        let mut token_ids: Vec<&str> = orders.iter().map(|order| order.token_id.as_str()).collect();
+        token_ids.sort_unstable();
+        token_ids.dedup();
+
+        let lookups: HashMap<&str, (CreateOrderOptions, f64, u32)> = stream::iter(token_ids)
+            .map(|token_id| {
+                let options = options.clone();
+                async move {
+                    let create_options = self._resolve_create_options(token_id, options).await?;
+                    let min_size = self.get_min_order_size(token_id).await?;
+                    let market_fee = self.get_fee_rate_bps(token_id).await?;
+                    Ok::<_, ClobError>((token_id, (create_options, min_size, market_fee)))
+                }
+            })
+            .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+            .try_collect()
+            .await?;
+
+        try_join_all(orders.iter().map(|order| {
+            let (create_options, min_size, market_fee) = &lookups[order.token_id.as_str()];
+            async move {
+                validate_order_size(order.size, *min_size)?;
+                let fee_rate_bps = check_fee_rate_bps(order.fee_rate_bps, *market_fee)?;
+                self.create_order_offline(order, create_options.clone(), fee_rate_bps)
+                    .await
+            }
+        }))
+        .await
+    }
+
+    /// Creates a signed limit order without any network lookups
+    ///
+    /// Signs purely from the given tick size, neg-risk flag (`false` when unset) and fee
+    /// rate, which replaces `user_limit_order.fee_rate_bps`. Unlike `create_limit_order`,
+    /// the fee rate isn't checked against the market's; a wrong one is rejected on post.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_limit_order` - Order parameters (token_id, price, size, side, etc.)
+    /// * `options` - Tick size and neg-risk flag of the market
+    /// * `fee_rate_bps` - Market fee rate in basis points
+    ///
+    /// # Returns
+    ///
+    /// A JSON representation of the signed order ready for posting
+    pub async fn create_order_offline(
+        &self,
+        user_limit_order: &UserLimitOrder,
+        options: CreateOrderOptions,
+        fee_rate_bps: u32,
+    ) -> ClobResult<serde_json::Value> {
+        self.can_l1_auth()?;
 
         let create_options = CreateOrderOptions {
-            tick_size,
-            neg_risk: Some(neg_risk),
+            tick_size: options.tick_size,
+            neg_risk: Some(options.neg_risk.unwrap_or(false)),
         };
 
         let mut order = user_limit_order.clone();
@@ -68,6 +138,45 @@ impl ClobClient {
         self.signed_order_to_json(signed_order)
     }
 
+    /// Creates a signed limit order along with its order hash
+    ///
+    /// The hash is the order's EIP-712 hash, which the CLOB uses as the order id
+    /// (`get_order`, `cancel_order`), so it is known before the order is posted.
+    ///
+    /// # Returns
+    ///
+    /// The signed order JSON (as from `create_limit_order`) and its `0x`-prefixed hash
+    pub async fn create_order_with_hash(
+        &self,
+        user_limit_order: &UserLimitOrder,
+        options: Option<CreateOrderOptions>,
+    ) -> ClobResult<(serde_json::Value, String)> {
+        let neg_risk = options.as_ref().map(|opts| opts.neg_risk.unwrap_or(false));
+        let order = self.create_limit_order(user_limit_order, options).await?;
+        let neg_risk = match neg_risk {
+            Some(neg_risk) => neg_risk,
+            // Cached by the lookup in `create_limit_order`
+            None => self.get_neg_risk(&user_limit_order.token_id).await?,
+        };
+        let order_hash = self.order_hash(&order, Some(neg_risk))?;
+        Ok((order, order_hash))
+    }
+
+    /// Computes the order id the CLOB assigns to a signed order from the `create_*` methods
+    ///
+    /// `neg_risk` selects the exchange the order was signed for, as in `CreateOrderOptions`.
+    pub fn order_hash(
+        &self,
+        order: &serde_json::Value,
+        neg_risk: Option<bool>,
+    ) -> ClobResult<String> {
+        let exchange = exchange_address(self.chain_id, neg_risk)?;
+        let exchange = Address::from_str(exchange)
+            .map_err(|e| ClobError::Other(format!("Invalid exchange address: {}", e)))?;
+        let hash = order_hash(order, self.chain_id.chain_id(), exchange)?;
+        Ok(format!("{:#x}", hash))
+    }
+
     /// Creates a signed market order
     ///
     /// # Arguments
@@ -84,56 +193,158 @@ impl ClobClient {
         options: Option<CreateOrderOptions>,
     ) -> ClobResult<serde_json::Value> {
         self.can_l1_auth()?;
+        // Also checked when signing; fail here before the lookups below
+        validate_expiration(user_market_order.order_type, user_market_order.expiration)?;
 
         let token_id = &user_market_order.token_id;
+        let create_options = self._resolve_create_options(token_id, options).await?;
+        let fee_rate_bps = self
+            ._resolve_fee_rate_bps(token_id, user_market_order.fee_rate_bps)
+            .await?;
 
-        // Resolve tick size
-        let tick_size = if let Some(opts) = &options {
-            opts.tick_size
-        } else {
-            self.get_tick_size(token_id).await?
-        };
+        let mut order = user_market_order.clone();
+        order.fee_rate_bps = Some(fee_rate_bps);
 
-        // Resolve fee rate
+        // Calculate market price if not provided
+        if order.price.is_none() {
+            let price = self
+                .calculate_market_price(
+                    token_id,
+                    order.side,
+                    order.amount,
+                    order.order_type.unwrap_or(OrderType::Fok),
+                )
+                .await?;
+            check_price_bounds(price, order.max_price, order.min_price)?;
+            order.price = Some(price);
+        }
+
+        if let Some(price) = order.price {
+            self._check_order_size(token_id, market_order_shares(order.side, order.amount, price))
+                .await?;
+        }
+
+        let order_builder = self
+            .order_builder
+            .as_ref()
+            .ok_or(ClobError::L1AuthUnavailable)?;
+
+        let signed_order = order_builder
+            .build_market_order(&order, &create_options)
+            .await?;
+        self.signed_order_to_json(signed_order)
+    }
+
+    /// Creates a signed limit order from a decimal price and size
+    ///
+    /// Same as `create_limit_order`, but the on-chain amounts are computed without going
+    /// through `f64`, so inputs like `0.07 * 3` produce exact amounts. Requires the
+    /// `decimal` feature.
+    ///
+    /// # Returns
+    ///
+    /// A JSON representation of the signed order ready for posting
+    #[cfg(feature = "decimal")]
+    pub async fn create_order_decimal(
+        &self,
+        user_order: &UserOrderDecimal,
+        options: Option<CreateOrderOptions>,
+    ) -> ClobResult<serde_json::Value> {
+        self.can_l1_auth()?;
+        // Also checked when signing; fail here before the lookups below
+        validate_expiration(user_order.order_type, user_order.expiration)?;
+
+        let token_id = &user_order.token_id;
+        let create_options = self._resolve_create_options(token_id, options).await?;
+        self._check_order_size(token_id, user_order.size.to_f64().unwrap_or_default())
+            .await?;
         let fee_rate_bps = self
-            ._resolve_fee_rate_bps(token_id, user_market_order.fee_rate_bps)
+            ._resolve_fee_rate_bps(token_id, user_order.fee_rate_bps)
             .await?;
 
-        // Resolve neg_risk
-        let neg_risk = if let Some(opts) = &options {
-            opts.neg_risk.unwrap_or(false)
-        } else {
-            self.get_neg_risk(token_id).await?
-        };
+        let mut order = user_order.clone();
+        order.fee_rate_bps = Some(fee_rate_bps);
 
-        let create_options = CreateOrderOptions {
-            tick_size,
-            neg_risk: Some(neg_risk),
-        };
+        let order_builder = self
+            .order_builder
+            .as_ref()
+            .ok_or(ClobError::L1AuthUnavailable)?;
+
+        let signed_order = order_builder
+            .build_limit_order_decimal(&order, &create_options)
+            .await?;
+        self.signed_order_to_json(signed_order)
+    }
+
+    /// Creates a signed market order from a decimal amount and optional price
+    ///
+    /// Same as `create_market_order`; a price taken from the orderbook is parsed exactly
+    /// from the book level. Requires the `decimal` feature.
+    ///
+    /// # Returns
+    ///
+    /// A JSON representation of the signed order ready for posting
+    #[cfg(feature = "decimal")]
+    pub async fn create_market_order_decimal(
+        &self,
+        user_market_order: &UserMarketOrderDecimal,
+        options: Option<CreateOrderOptions>,
+    ) -> ClobResult<serde_json::Value> {
+        self.can_l1_auth()?;
+        // Also checked when signing; fail here before the lookups below
+        validate_expiration(user_market_order.order_type, user_market_order.expiration)?;
+
+        let token_id = &user_market_order.token_id;
+        let create_options = self._resolve_create_options(token_id, options).await?;
+        let fee_rate_bps = self
+            ._resolve_fee_rate_bps(token_id, user_market_order.fee_rate_bps)
+            .await?;
 
         let mut order = user_market_order.clone();
         order.fee_rate_bps = Some(fee_rate_bps);
 
         // Calculate market price if not provided
         if order.price.is_none() {
+            let amount = order.amount.to_f64().ok_or_else(|| {
+                ClobError::Other(format!("Amount {} is out of range", order.amount))
+            })?;
             let price = self
                 .calculate_market_price(
                     token_id,
                     order.side,
-                    order.amount,
+                    amount,
                     order.order_type.unwrap_or(OrderType::Fok),
                 )
                 .await?;
+            check_price_bounds(
+                price,
+                order.max_price.and_then(|limit| limit.to_f64()),
+                order.min_price.and_then(|limit| limit.to_f64()),
+            )?;
+            // Book prices are short decimal strings, which f64 formatting round-trips
+            let price = Decimal::from_str(&price.to_string())
+                .map_err(|e| ClobError::Other(format!("Invalid market price {}: {}", price, e)))?;
             order.price = Some(price);
         }
 
+        if let Some(price) = order.price {
+            let shares = match order.side {
+                Side::Buy => order.amount.checked_div(price),
+                Side::Sell => Some(order.amount),
+            };
+            // A zero price is left for the price validation to reject
+            if let Some(shares) = shares.and_then(|shares| shares.to_f64()) {
+                self._check_order_size(token_id, shares).await?;
+            }
+        }
+
         let order_builder = self
             .order_builder
             .as_ref()
             .ok_or(ClobError::L1AuthUnavailable)?;
 
         let signed_order = order_builder
-            .build_market_order(&order, &create_options)
+            .build_market_order_decimal(&order, &create_options)
             .await?;
         self.signed_order_to_json(signed_order)
     }
@@ -153,15 +364,20 @@ impl ClobClient {
     ///
     /// # Returns
     ///
-    /// API response with order status
+    /// API response with order status. GTD orders without an expiration are rejected with
+    /// `MissingExpiration`, and other order types with an expiration are rejected too.
     pub async fn create_and_post_limit_order(
         &self,
         user_limit_order: &UserLimitOrder,
         options: Option<CreateOrderOptions>,
         order_type: OrderType,
-    ) -> ClobResult<serde_json::Value> {
-        let order = self.create_limit_order(user_limit_order, options).await?;
-        self.post_order(order, order_type).await
+    ) -> ClobResult<PostOrderResponse> {
+        let user_limit_order = UserLimitOrder {
+            order_type: Some(order_type),
+            ..user_limit_order.clone()
+        };
+        let order = self.create_limit_order(&user_limit_order, options).await?;
+        self.post_order(order, order_type, false).await
     }
 
     /// Creates and posts a market order in one call
@@ -180,28 +396,71 @@ impl ClobClient {
         user_market_order: &UserMarketOrder,
         options: Option<CreateOrderOptions>,
         order_type: OrderType,
-    ) -> ClobResult<serde_json::Value> {
-        let order = self.create_market_order(user_market_order, options).await?;
-        self.post_order(order, order_type).await
+    ) -> ClobResult<PostOrderResponse> {
+        let user_market_order = UserMarketOrder {
+            order_type: Some(order_type),
+            ..user_market_order.clone()
+        };
+        let order = self
+            .create_market_order(&user_market_order, options)
+            .await?;
+        self.post_order(order, order_type, false).await
+    }
+
+    /// Posts a limit order priced to fill immediately, crossing the book by at most
+    /// `max_slippage_bps` from the best opposite price
+    ///
+    /// The order's price is replaced by the price of the level at which the opposite side's
+    /// cumulative size covers `size`.
+    ///
+    /// # Returns
+    ///
+    /// API response with order status, or `InsufficientLiquidity` if the book within the
+    /// slippage cap cannot fill the order
+    pub async fn post_marketable_limit(
+        &self,
+        user_limit_order: &UserLimitOrder,
+        max_slippage_bps: u32,
+        order_type: OrderType,
+    ) -> ClobResult<PostOrderResponse> {
+        let orderbook = self.get_order_book(&user_limit_order.token_id).await?;
+        let price = calculate_marketable_limit_price(
+            &orderbook,
+            user_limit_order.side,
+            user_limit_order.size,
+            max_slippage_bps,
+        )?;
+
+        let order = UserLimitOrder {
+            price,
+            ..user_limit_order.clone()
+        };
+        self.create_and_post_limit_order(&order, None, order_type)
+            .await
     }
 
     /// Gets all trade history with automatic pagination
     /// Note: The trades history only includes trades that have been executed, does not include limit orders
     pub async fn get_trades(&self, params: Option<TradeParams>) -> ClobResult<Vec<Trade>> {
         self.can_l2_auth()?;
+        self.get_trades_stream(params).collect_all().await
+    }
 
-        let mut results = Vec::new();
-        let mut next_cursor = INITIAL_CURSOR.to_string();
-
-        while next_cursor != END_CURSOR {
-            let response = self
-                .get_trades_paginated(params.clone(), Some(next_cursor.clone()))
-                .await?;
-            next_cursor = response.next_cursor;
-            results.extend(response.data);
-        }
-
-        Ok(results)
+    /// Streams trade history page by page, without collecting it in memory
+    ///
+    /// Pages are fetched as the stream is polled; a failed page (including missing L2
+    /// auth) is yielded as an error and ends the stream.
+    pub fn get_trades_stream(&self, params: Option<TradeParams>) -> Paginator<'_, Trade> {
+        Paginator::new(move |cursor| {
+            let params = params.clone();
+            async move {
+                let response = self.get_trades_paginated(params, Some(cursor)).await?;
+                Ok(Page {
+                    data: response.data,
+                    next_cursor: response.next_cursor,
+                })
+            }
+        })
     }
 
     /// Gets trades with pagination support
@@ -212,19 +471,7 @@ impl ClobClient {
     ) -> ClobResult<TradesPaginatedResponse> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
         let endpoint_path = endpoints::GET_TRADES;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-
-        let headers = create_l2_headers(wallet, creds, "GET", endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
 
         let mut query_params = HashMap::new();
 
@@ -256,57 +503,139 @@ impl ClobClient {
             }
         }
 
-        self.http_client
-            .get(endpoint_path, Some(headers), Some(query_params))
+        self.send_l2("GET", endpoint_path, None, Some(query_params))
             .await
     }
 
-    /// Gets an open order by ID
+    /// Sums the user's trades matched between `start_ts` and `end_ts` (unix seconds, inclusive)
+    ///
+    /// Trades are fetched page by page and folded into the summary as they arrive.
+    pub async fn get_volume_summary(
+        &self,
+        start_ts: u64,
+        end_ts: u64,
+    ) -> ClobResult<VolumeSummary> {
+        let params = TradeParams {
+            after: Some(start_ts.to_string()),
+            before: Some(end_ts.to_string()),
+            ..Default::default()
+        };
+
+        let mut summary = VolumeSummary::default();
+        let mut trades = self.get_trades_stream(Some(params));
+
+        while let Some(trade) = trades.next().await {
+            let trade = trade?;
+            // The server filters by window too; this guards against boundary differences
+            let in_window = trade
+                .match_time
+                .parse::<u64>()
+                .ok()
+                .is_none_or(|t| (start_ts..=end_ts).contains(&t));
+            if in_window {
+                summary.add(&trade)?;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Gets an order by ID from `/data/order/{id}`
+    ///
+    /// Despite the name, matched and cancelled orders are returned too; check
+    /// `OpenOrder::status` (or use `is_order_open`). Unknown ids fail with a 404 `ApiError`.
     pub async fn get_open_order(&self, order_id: &str) -> ClobResult<OpenOrder> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
         let endpoint_path = format!("{}{}", endpoints::GET_ORDER, order_id);
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
 
-        let headers = create_l2_headers(wallet, creds, "GET", &endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
+        self.send_l2("GET", &endpoint_path, None, None).await
+    }
 
-        self.http_client
-            .get(&endpoint_path, Some(headers), None)
-            .await
+    /// Gets multiple orders by ID
+    ///
+    /// Orders are fetched concurrently (at most `MAX_CONCURRENT_REQUESTS` in flight) and
+    /// returned in the same order as `order_ids`. Ids the server doesn't know (404) are omitted.
+    pub async fn get_orders(&self, order_ids: &[String]) -> ClobResult<Vec<OpenOrder>> {
+        self.can_l2_auth()?;
+
+        let results: Vec<ClobResult<OpenOrder>> = stream::iter(order_ids)
+            .map(|order_id| self.get_open_order(order_id))
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .collect()
+            .await;
+
+        let mut orders = Vec::with_capacity(results.len());
+        for result in results {
+            match result {
+                Ok(order) => orders.push(order),
+                Err(ClobError::ApiError { status: 404, .. }) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(orders)
     }
 
-    /// Gets open orders for the user
+    /// Checks whether an order is still open (live or partially filled)
+    ///
+    /// Returns `false` for matched or cancelled orders, and for order ids the server
+    /// doesn't know about (404).
+    pub async fn is_order_open(&self, order_id: &str) -> ClobResult<bool> {
+        match self.get_open_order(order_id).await {
+            Ok(order) => Ok(order.is_open()),
+            Err(ClobError::ApiError { status: 404, .. }) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Gets all open orders for the user with automatic pagination
     pub async fn get_open_orders(
         &self,
         params: Option<OpenOrderParams>,
     ) -> ClobResult<OpenOrdersResponse> {
         self.can_l2_auth()?;
+        self.get_open_orders_stream(params).collect_all().await
+    }
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
+    /// Streams open orders page by page, without collecting them in memory
+    ///
+    /// Pages are fetched as the stream is polled; a failed page is yielded as an error and
+    /// ends the stream.
+    pub fn get_open_orders_stream(
+        &self,
+        params: Option<OpenOrderParams>,
+    ) -> Paginator<'_, OpenOrder> {
+        Paginator::new(move |cursor| {
+            let params = params.clone();
+            async move {
+                let response = self.get_open_orders_paginated(params, Some(cursor)).await?;
+                Ok(Page {
+                    data: response.data,
+                    next_cursor: response.next_cursor,
+                })
+            }
+        })
+    }
 
-        let endpoint_path = endpoints::GET_OPEN_ORDERS;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+    /// Gets one page of open orders, starting at `cursor` (`INITIAL_CURSOR` if `None`)
+    pub async fn get_open_orders_paginated(
+        &self,
+        params: Option<OpenOrderParams>,
+        cursor: Option<String>,
+    ) -> ClobResult<OpenOrdersPaginatedResponse> {
+        self.can_l2_auth()?;
 
-        let headers = create_l2_headers(wallet, creds, "GET", endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
+        let endpoint_path = endpoints::GET_OPEN_ORDERS;
 
         let mut query_params = HashMap::new();
 
+        // Add cursor
+        query_params.insert(
+            "next_cursor".to_string(),
+            cursor.unwrap_or_else(|| INITIAL_CURSOR.to_string()),
+        );
+
         if let Some(p) = params {
             if let Some(id) = p.id {
                 query_params.insert("id".to_string(), id);
@@ -319,245 +648,225 @@ impl ClobClient {
             }
         }
 
-        self.http_client
-            .get(
-                endpoint_path,
-                Some(headers),
-                (!query_params.is_empty()).then_some(query_params),
-            )
+        self.send_l2("GET", endpoint_path, None, Some(query_params))
             .await
     }
 
     /// Posts an order to the exchange
+    ///
+    /// With `defer_exec`, the exchange defers on-chain execution of the match so it can be
+    /// settled together with other matches. A response with `success: false` is returned as
+    /// `ApiError`, with the server's `errorMsg` as its `error_code`.
     pub async fn post_order(
         &self,
         order: serde_json::Value,
         order_type: OrderType,
-    ) -> ClobResult<serde_json::Value> {
+        defer_exec: bool,
+    ) -> ClobResult<PostOrderResponse> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
-        // Prepare order payload
-        let order_payload = self.order_to_json(order, order_type)?;
-        let body = serde_json::to_string(&order_payload)?;
-
-        // Create L2 headers with body
-        let endpoint_path = endpoints::POST_ORDER;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-
-        let headers =
-            create_l2_headers(wallet, creds, "POST", endpoint_path, Some(&body), timestamp).await?;
-
-        // Inject builder headers if available
-        let final_headers = if self.can_builder_auth() {
-            match self
-                ._generate_builder_headers(headers.clone(), "POST", endpoint_path, Some(&body))
-                .await?
-            {
-                Some(builder_headers) => builder_headers.to_headers(),
-                None => headers.to_headers(),
-            }
-        } else {
-            headers.to_headers()
-        };
+        // The payload names the API key as its owner, so it's rebuilt if the key is re-derived
+        let order = &order;
+        let result = self
+            .with_reauth(|| async move {
+                let order_payload = self.order_to_json(order.clone(), order_type, defer_exec)?;
+                self.post_order_payload(endpoints::POST_ORDER, order_payload)
+                    .await
+            })
+            .await;
+
+        self.log_order_event(OrderEventKind::Post, &[], &result);
+
+        // The server reports some rejections in a 200 body with `success: false`
+        let body = result?;
+        let response: PostOrderResponse = serde_json::from_value(body.clone())?;
+        if !response.is_success() {
+            return Err(ClobError::ApiError {
+                message: body.to_string(),
+                status: 200,
+                error_code: response.error_msg.filter(|msg| !msg.is_empty()),
+            });
+        }
 
-        // Make request
-        self.http_client
-            .post(
-                endpoint_path,
-                Some(final_headers),
-                Some(order_payload),
-                None,
-            )
-            .await
+        Ok(response)
     }
 
     /// Posts multiple orders to the exchange
-    pub async fn post_orders(&self, orders: Vec<PostOrdersArgs>) -> ClobResult<serde_json::Value> {
+    ///
+    /// `defer_exec` applies to every order, as in `post_order`.
+    pub async fn post_orders(
+        &self,
+        orders: Vec<PostOrdersArgs>,
+        defer_exec: bool,
+    ) -> ClobResult<serde_json::Value> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
-        // Convert each order to payload format
-        let owner = &creds.key;
-        let payloads: Vec<_> = orders
-            .iter()
-            .map(|arg| {
-                serde_json::json!({
-                    "order": arg.order,
-                    "owner": owner,
-                    "orderType": arg.order_type,
-                    "deferExec": false
-                })
+        let orders = &orders;
+        let result = self
+            .with_reauth(|| async move {
+                // Convert each order to payload format
+                let payloads = orders
+                    .iter()
+                    .map(|arg| self.order_to_json(arg.order.clone(), arg.order_type, defer_exec))
+                    .collect::<ClobResult<Vec<_>>>()?;
+
+                self.post_order_payload(endpoints::POST_ORDERS, serde_json::Value::Array(payloads))
+                    .await
             })
-            .collect();
-
-        let body = serde_json::to_string(&payloads)?;
+            .await;
 
-        let endpoint_path = endpoints::POST_ORDERS;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        self.log_order_event(OrderEventKind::Post, &[], &result);
+        result
+    }
 
-        let headers =
-            create_l2_headers(wallet, creds, "POST", endpoint_path, Some(&body), timestamp).await?;
+    /// Posts two complementary legs (e.g. YES buy + NO buy) in a single batch request
+    ///
+    /// Both signed orders go out together via `post_orders`; the exchange still matches
+    /// each leg independently, so check both results.
+    ///
+    /// # Returns
+    ///
+    /// The (yes, no) leg results, in submission order
+    pub async fn submit_pair(
+        &self,
+        yes_order: serde_json::Value,
+        no_order: serde_json::Value,
+        order_type: OrderType,
+    ) -> ClobResult<(PostOrderResponse, PostOrderResponse)> {
+        let response = self
+            .post_orders(
+                vec![
+                    PostOrdersArgs {
+                        order: yes_order,
+                        order_type,
+                    },
+                    PostOrdersArgs {
+                        order: no_order,
+                        order_type,
+                    },
+                ],
+                false,
+            )
+            .await?;
 
-        // Inject builder headers if available
-        let final_headers = if self.can_builder_auth() {
-            match self
-                ._generate_builder_headers(headers.clone(), "POST", endpoint_path, Some(&body))
-                .await?
-            {
-                Some(builder_headers) => builder_headers.to_headers(),
-                None => headers.to_headers(),
-            }
-        } else {
-            headers.to_headers()
-        };
+        let mut legs: Vec<PostOrderResponse> = serde_json::from_value(response)?;
+        if legs.len() != 2 {
+            return Err(ClobError::Other(format!(
+                "Expected 2 order results for pair, got {}",
+                legs.len()
+            )));
+        }
 
-        self.http_client
-            .post(endpoint_path, Some(final_headers), Some(payloads), None)
-            .await
+        let no_leg = legs.pop().unwrap();
+        let yes_leg = legs.pop().unwrap();
+        Ok((yes_leg, no_leg))
     }
 
     /// Cancels a single order by ID
     pub async fn cancel_order(&self, order_id: &str) -> ClobResult<serde_json::Value> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
         let payload = OrderPayload {
             order_id: order_id.to_string(),
         };
-        let body = serde_json::to_string(&payload)?;
 
-        let endpoint_path = endpoints::CANCEL_ORDER;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-
-        let headers = create_l2_headers(
-            wallet,
-            creds,
-            "DELETE",
-            endpoint_path,
-            Some(&body),
-            timestamp,
-        )
-        .await?
-        .to_headers();
+        let result = self
+            .send_l2(
+                "DELETE",
+                endpoints::CANCEL_ORDER,
+                Some(serde_json::to_value(&payload)?),
+                None,
+            )
+            .await;
 
-        self.http_client
-            .delete(endpoint_path, Some(headers), Some(payload), None)
-            .await
+        self.log_order_event(OrderEventKind::Cancel, &[order_id.to_string()], &result);
+        result
     }
 
     /// Cancels multiple orders by IDs
+    ///
+    /// Lists longer than the client's max batch (`set_max_batch`) are cancelled in several
+    /// concurrent requests, merged into one response. A failed request's ids are reported
+    /// under `not_canceled` with its error; the error is returned only if every request fails.
     pub async fn cancel_orders(&self, order_ids: Vec<String>) -> ClobResult<serde_json::Value> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
-        #[derive(serde::Serialize)]
-        struct CancelOrdersPayload {
-            order_ids: Vec<String>,
-        }
-
-        let payload = CancelOrdersPayload { order_ids };
-        let body = serde_json::to_string(&payload)?;
+        let responses: Vec<(&[String], ClobResult<serde_json::Value>)> =
+            stream::iter(order_ids.chunks(self.max_batch))
+                .map(|chunk| async move {
+                    let payload = serde_json::json!({ "order_ids": chunk });
+                    let result = self
+                        .send_l2("DELETE", endpoints::CANCEL_ORDERS, Some(payload), None)
+                        .await;
+                    (chunk, result)
+                })
+                .buffered(MAX_CONCURRENT_REQUESTS)
+                .collect()
+                .await;
 
-        let endpoint_path = endpoints::CANCEL_ORDERS;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let result = merge_cancel_responses(responses);
+        self.log_order_event(OrderEventKind::Cancel, &order_ids, &result);
+        result
+    }
 
-        let headers = create_l2_headers(
-            wallet,
-            creds,
-            "DELETE",
-            endpoint_path,
-            Some(&body),
-            timestamp,
-        )
-        .await?
-        .to_headers();
+    /// Cancels orders, then re-queries them and returns the ids that are actually gone
+    ///
+    /// A cancel acknowledgement doesn't guarantee the order didn't fill first, so only ids
+    /// now reported as cancelled, or unknown to the server (404), are returned, in the
+    /// order requested. Orders that matched or are still live are left out.
+    pub async fn cancel_orders_confirmed(&self, order_ids: Vec<String>) -> ClobResult<Vec<String>> {
+        self.cancel_orders(order_ids.clone()).await?;
+
+        let orders = self.get_orders(&order_ids).await?;
+        let confirmed = order_ids
+            .into_iter()
+            .filter(|order_id| {
+                orders
+                    .iter()
+                    .find(|order| &order.id == order_id)
+                    .is_none_or(|order| order.is_cancelled())
+            })
+            .collect();
 
-        self.http_client
-            .delete(endpoint_path, Some(headers), Some(payload), None)
-            .await
+        Ok(confirmed)
     }
 
     /// Cancels all open orders
     pub async fn cancel_all(&self) -> ClobResult<serde_json::Value> {
         self.can_l2_auth()?;
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
         let endpoint_path = endpoints::CANCEL_ALL;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
 
-        let headers = create_l2_headers(wallet, creds, "DELETE", endpoint_path, None, timestamp)
-            .await?
-            .to_headers();
+        let result = self.send_l2("DELETE", endpoint_path, None, None).await;
 
-        self.http_client
-            .delete(endpoint_path, Some(headers), None::<()>, None)
-            .await
+        self.log_order_event(OrderEventKind::Cancel, &[], &result);
+        result
     }
 
     /// Cancels orders for a specific market or asset
+    ///
+    /// Returns `MissingCancelTarget` without sending anything if neither `market` nor
+    /// `asset_id` is set.
     pub async fn cancel_market_orders(
         &self,
         params: OrderMarketCancelParams,
     ) -> ClobResult<serde_json::Value> {
         self.can_l2_auth()?;
+        if params.market.is_none() && params.asset_id.is_none() {
+            return Err(ClobError::MissingCancelTarget);
+        }
 
-        let wallet = self.wallet.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
-        let creds = self.creds.as_ref().ok_or(ClobError::L2AuthNotAvailable)?;
-
-        let body = serde_json::to_string(&params)?;
-
-        let endpoint_path = endpoints::CANCEL_MARKET_ORDERS;
-        let timestamp = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-
-        let headers = create_l2_headers(
-            wallet,
-            creds,
-            "DELETE",
-            endpoint_path,
-            Some(&body),
-            timestamp,
-        )
-        .await?
-        .to_headers();
+        let result = self
+            .send_l2(
+                "DELETE",
+                endpoints::CANCEL_MARKET_ORDERS,
+                Some(serde_json::to_value(&params)?),
+                None,
+            )
+            .await;
 
-        self.http_client
-            .delete(endpoint_path, Some(headers), Some(params), None)
-            .await
+        self.log_order_event(OrderEventKind::Cancel, &[], &result);
+        result
     }
 
     // ===================================
@@ -601,7 +910,7 @@ impl ClobClient {
         }
 
         self.http_client
-            .get(endpoint_path, Some(headers), Some(query_params))
+            .get_json(endpoint_path, Some(headers), Some(query_params))
             .await
     }
 
@@ -620,7 +929,8 @@ impl ClobClient {
     ///
     /// # Returns
     ///
-    /// Calculated execution price with buffer
+    /// Calculated execution price with buffer. Fails with `NoOrderbook` if the matching side
+    /// of the book is empty and with `InsufficientLiquidity` if a FOK amount exceeds its depth.
     pub async fn calculate_market_price(
         &self,
         token_id: &str,
@@ -628,27 +938,126 @@ impl ClobClient {
         amount: f64,
         order_type: OrderType,
     ) -> ClobResult<f64> {
+        let quote = self
+            .quote_market_price(token_id, side, amount, order_type)
+            .await?;
+        Ok(quote.price)
+    }
+
+    /// Same as `calculate_market_price`, also reporting the book's `neg_risk` flag
+    ///
+    /// Neg-risk books already include liquidity from the complementary outcomes (the
+    /// neg-risk adapter mirrors it into each token's book), so the price math is identical;
+    /// the flag tells callers the order must be signed for the neg-risk exchange.
+    pub async fn quote_market_price(
+        &self,
+        token_id: &str,
+        side: Side,
+        amount: f64,
+        order_type: OrderType,
+    ) -> ClobResult<MarketPriceQuote> {
         let orderbook = self.get_order_book(token_id).await?;
-        match side {
+        let price = match side {
             Side::Buy => {
                 if orderbook.asks.is_empty() {
-                    return Err(ClobError::NoMatch);
+                    return Err(ClobError::NoOrderbook);
                 }
-                calculate_buy_market_price(&orderbook.asks, amount, order_type)
+                calculate_buy_market_price(&orderbook.asks, amount, order_type)?
             }
             Side::Sell => {
                 if orderbook.bids.is_empty() {
-                    return Err(ClobError::NoMatch);
+                    return Err(ClobError::NoOrderbook);
                 }
-                calculate_sell_market_price(&orderbook.bids, amount, order_type)
+                calculate_sell_market_price(&orderbook.bids, amount, order_type)?
             }
+        };
+
+        Ok(MarketPriceQuote {
+            price,
+            neg_risk: orderbook.neg_risk,
+        })
+    }
+
+    /// Estimates how a market order would fill against the current book
+    ///
+    /// Reports the average and worst price and how much of `amount` (USDC for Buy, shares
+    /// for Sell) the book can fill. Fails with `NoOrderbook` if the matching side is empty
+    /// and with `InsufficientLiquidity` if a FOK amount exceeds its depth; a FAK estimate
+    /// reports the partial fill instead.
+    pub async fn preview_market_order(
+        &self,
+        token_id: &str,
+        side: Side,
+        amount: f64,
+        order_type: OrderType,
+    ) -> ClobResult<MarketOrderEstimate> {
+        let orderbook = self.get_order_book(token_id).await?;
+        let levels = match side {
+            Side::Buy => &orderbook.asks,
+            Side::Sell => &orderbook.bids,
+        };
+        if levels.is_empty() {
+            return Err(ClobError::NoOrderbook);
         }
+        calculate_market_order_estimate(levels, side, amount, order_type)
+    }
+
+    /// Computes how much size rests on `side` at or better than `price`
+    ///
+    /// Sums bids at or above `price` for Buy and asks at or below it for Sell; useful for
+    /// sizing passive quotes relative to the existing queue.
+    pub async fn liquidity_to_price(
+        &self,
+        token_id: &str,
+        side: Side,
+        price: f64,
+    ) -> ClobResult<f64> {
+        let orderbook = self.get_order_book(token_id).await?;
+        calculate_liquidity_to_price(&orderbook, side, price)
+    }
+
+    /// Checks a limit order against a freshly fetched orderbook
+    ///
+    /// Lets makers confirm a quote stays passive before posting: reports whether the order
+    /// would cross the spread and how much opposite-side depth it would match against.
+    pub async fn validate_against_book(
+        &self,
+        user_limit_order: &UserLimitOrder,
+    ) -> ClobResult<OrderBookCheck> {
+        let orderbook = self.get_order_book(&user_limit_order.token_id).await?;
+        check_order_against_book(user_limit_order, &orderbook)
     }
 
     // ===================================
     // Private Helper Methods
     // ===================================
 
+    /// Resolves tick size and neg_risk for a token, from `options` when given
+    pub(crate) async fn _resolve_create_options(
+        &self,
+        token_id: &str,
+        options: Option<CreateOrderOptions>,
+    ) -> ClobResult<CreateOrderOptions> {
+        let (tick_size, neg_risk) = match options {
+            Some(opts) => (opts.tick_size, opts.neg_risk.unwrap_or(false)),
+            None => (
+                self.get_tick_size(token_id).await?,
+                self.get_neg_risk(token_id).await?,
+            ),
+        };
+
+        Ok(CreateOrderOptions {
+            tick_size,
+            neg_risk: Some(neg_risk),
+        })
+    }
+
+    /// Rejects an order of `size` shares below the token's minimum order size
+    pub(crate) async fn _check_order_size(&self, token_id: &str, size: f64) -> ClobResult<()> {
+        let min_size = self.get_min_order_size(token_id).await?;
+        validate_order_size(size, min_size)
+    }
+
     /// Resolves the fee rate for a token
     ///
     /// If the user provides a fee rate and it doesn't match the market fee rate,
@@ -659,17 +1068,58 @@ impl ClobClient {
         user_fee: Option<u32>,
     ) -> ClobResult<u32> {
         let market_fee = self.get_fee_rate_bps(token_id).await?;
+        check_fee_rate_bps(user_fee, market_fee)
+    }
 
-        if let Some(user_provided) = user_fee {
-            if market_fee > 0 && user_provided != market_fee {
-                return Err(ClobError::InvalidFeeRate {
-                    user_fee_rate: user_provided,
-                    market_fee_rate: market_fee,
-                });
-            }
+    /// Appends the outcome of an order post/cancel request to the client's event log
+    fn log_order_event(
+        &self,
+        kind: OrderEventKind,
+        requested_ids: &[String],
+        result: &ClobResult<serde_json::Value>,
+    ) {
+        let (mut order_ids, error) = match result {
+            Ok(response) => (order_ids_from_response(response), response_error(response)),
+            Err(e) => (Vec::new(), Some(e.to_string())),
+        };
+        if order_ids.is_empty() {
+            order_ids = requested_ids.to_vec();
         }
 
-        Ok(market_fee)
+        self.record_order_event(OrderEvent {
+            timestamp: self.time_provider.now_millis(),
+            kind,
+            order_ids,
+            success: error.is_none(),
+            error,
+        });
+    }
+
+    /// Posts order payloads with L2 headers, plus builder headers when builder auth is set
+    async fn post_order_payload(
+        &self,
+        endpoint_path: &str,
+        payload: serde_json::Value,
+    ) -> ClobResult<serde_json::Value> {
+        let body = serde_json::to_string(&payload)?;
+        let headers = self.l2_headers("POST", endpoint_path, Some(&body)).await?;
+
+        // Inject builder headers if available
+        let final_headers = if self.can_builder_auth() {
+            match self
+                ._generate_builder_headers(headers.clone(), "POST", endpoint_path, Some(&body))
+                .await?
+            {
+                Some(builder_headers) => builder_headers.to_headers(),
+                None => headers.to_headers(),
+            }
+        } else {
+            headers.to_headers()
+        };
+
+        self.http_client
+            .post_json(endpoint_path, Some(final_headers), Some(payload), None)
+            .await
     }
 
     /// Converts order to JSON payload for API submission
@@ -677,37 +1127,148 @@ impl ClobClient {
         &self,
         order: serde_json::Value,
         order_type: OrderType,
+        defer_exec: bool,
     ) -> ClobResult<serde_json::Value> {
-        let owner = self
-            .creds
-            .as_ref()
-            .ok_or(ClobError::L2AuthNotAvailable)?
-            .key
-            .clone();
+        let owner = self.api_key().ok_or(ClobError::L2AuthNotAvailable)?;
 
         // Wrap the order in the expected payload format
         Ok(serde_json::json!({
             "order": order,
             "owner": owner,
             "orderType": order_type,
+            "deferExec": defer_exec,
         }))
     }
 
     /// Converts a SignedOrder to JSON format for API submission
-    fn signed_order_to_json(&self, signed_order: SignedOrder) -> ClobResult<serde_json::Value> {
+    ///
+    /// Use it to post orders signed outside the client, such as one from
+    /// `signed_order_from_typed_data`.
+    pub fn signed_order_to_json(&self, signed_order: SignedOrder) -> ClobResult<serde_json::Value> {
         let mut json = serde_json::to_value(&signed_order).map_err(|e| ClobError::JsonError(e))?;
+
+        // Reject malformed signatures before the exchange does ("invalid signature")
+        if let Some(signature) = json.get("signature").and_then(|s| s.as_str()) {
+            let signature = normalize_order_signature(signature, self.signature_type)?;
+            json["signature"] = serde_json::Value::String(signature);
+        }
         
         // Convert numeric side ("0" or "1") to string side ("BUY" or "SELL")
         // The API expects "BUY"/"SELL" strings, not numeric values
         if let Some(side) = json.get("side") {
-            let side_str = match side.as_str() {
-                Some("0") => "BUY",
-                Some("1") => "SELL",
-                _ => return Ok(json), // Keep as-is if already correct format
-            };
-            json["side"] = serde_json::Value::String(side_str.to_string());
+            let side = side_wire::from_wire(side)?;
+            json["side"] = serde_json::Value::String(side_wire::to_api_string(side).to_string());
         }
         
         Ok(json)
     }
 }
+
+/// Merges the responses of a chunked cancel into one `{"canceled", "not_canceled"}` response
+///
+/// A single response is returned as is. Ids of failed requests go to `not_canceled` with the
+/// error, unless all of them failed, in which case the first error is returned.
+fn merge_cancel_responses(
+    mut responses: Vec<(&[String], ClobResult<serde_json::Value>)>,
+) -> ClobResult<serde_json::Value> {
+    let all_failed = responses.iter().all(|(_, result)| result.is_err());
+    if responses.len() == 1 || (all_failed && !responses.is_empty()) {
+        return responses.remove(0).1;
+    }
+
+    let mut canceled = Vec::new();
+    let mut not_canceled = serde_json::Map::new();
+    for (order_ids, result) in responses {
+        match result {
+            Ok(response) => {
+                if let Some(ids) = response.get("canceled").and_then(|ids| ids.as_array()) {
+                    canceled.extend(ids.iter().cloned());
+                }
+                if let Some(reasons) = response.get("not_canceled").and_then(|r| r.as_object()) {
+                    not_canceled.extend(reasons.clone());
+                }
+            }
+            Err(e) => {
+                for order_id in order_ids {
+                    not_canceled.insert(order_id.clone(), e.to_string().into());
+                }
+            }
+        }
+    }
+
+    Ok(serde_json::json!({ "canceled": canceled, "not_canceled": not_canceled }))
+}
+
+/// Size in shares of a market order: buy amounts are in USDC, sell amounts in shares
+fn market_order_shares(side: Side, amount: f64, price: f64) -> f64 {
+    match side {
+        Side::Buy => amount / price,
+        Side::Sell => amount,
+    }
+}
+
+/// Checks a user provided fee rate against the market's, returning the market fee rate
+fn check_fee_rate_bps(user_fee: Option<u32>, market_fee: u32) -> ClobResult<u32> {
+    if let Some(user_provided) = user_fee {
+        if market_fee > 0 && user_provided != market_fee {
+            return Err(ClobError::InvalidFeeRate {
+                user_fee_rate: user_provided,
+                market_fee_rate: market_fee,
+            });
+        }
+    }
+
+    Ok(market_fee)
+}
+
+/// Collects order ids from post (`orderID`) and cancel (`canceled`) responses
+fn order_ids_from_response(response: &serde_json::Value) -> Vec<String> {
+    match response {
+        serde_json::Value::Array(items) => items.iter().flat_map(order_ids_from_response).collect(),
+        serde_json::Value::Object(_) => {
+            let mut ids = Vec::new();
+            if let Some(id) = response.get("orderID").and_then(|id| id.as_str()) {
+                if !id.is_empty() {
+                    ids.push(id.to_string());
+                }
+            }
+            if let Some(canceled) = response.get("canceled").and_then(|c| c.as_array()) {
+                ids.extend(
+                    canceled
+                        .iter()
+                        .filter_map(|id| id.as_str())
+                        .map(String::from),
+                );
+            }
+            ids
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Extracts the failure reason from a post/cancel response, if any
+fn response_error(response: &serde_json::Value) -> Option<String> {
+    match response {
+        serde_json::Value::Array(items) => {
+            let errors: Vec<String> = items.iter().filter_map(response_error).collect();
+            (!errors.is_empty()).then(|| errors.join("; "))
+        }
+        serde_json::Value::Object(_) => {
+            if let Some(msg) = response.get("errorMsg").and_then(|m| m.as_str()) {
+                if !msg.is_empty() {
+                    return Some(msg.to_string());
+                }
+            }
+            if response.get("success").and_then(|s| s.as_bool()) == Some(false) {
+                return Some("Request was not successful".to_string());
+            }
+            match response.get("not_canceled").and_then(|n| n.as_object()) {
+                Some(not_canceled) if !not_canceled.is_empty() => {
+                    Some(format!("Not canceled: {}", response["not_canceled"]))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -1,3 +1,7 @@
+use crate::errors::{ClobError, ClobResult};
+use crate::types::Chain;
+use std::time::Duration;
+
 #[derive(Debug, Clone)]
 pub struct ContractConfig {
     pub exchange: &'static str,
@@ -23,6 +27,14 @@ pub const MATIC_CONTRACTS: ContractConfig = ContractConfig {
     conditional_tokens: "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045",
 };
 
+// Polymarket proxy wallet factory (signature type 1) on Polygon mainnet; proxy wallets are
+// deployed with CREATE2, so their address follows from the owner's EOA
+pub const POLYGON_PROXY_FACTORY: &str = "0xaB45c5A4B0c941a2F231C04C3f49182e1A254052";
+
+// keccak256 of the proxy wallet init code used by the proxy factory
+pub const PROXY_INIT_CODE_HASH: &str =
+    "0xd21df8dc65880a8606f09fe0ce3df9b8869287ab0b058be05aa9e8af6330a00b";
+
 pub const COLLATERAL_TOKEN_DECIMALS: u8 = 6;
 pub const CONDITIONAL_TOKEN_DECIMALS: u8 = 6;
 
@@ -30,16 +42,80 @@ pub const CONDITIONAL_TOKEN_DECIMALS: u8 = 6;
 pub const INITIAL_CURSOR: &str = "MA==";
 pub const END_CURSOR: &str = "LTE=";
 
+// Default User-Agent sent with every request, identifying this client and its version
+pub const DEFAULT_USER_AGENT: &str = concat!("rs-clob-client/", env!("CARGO_PKG_VERSION"));
+
+// Header carrying the per-request correlation id
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+// Maximum deviation of YES + NO midpoints from 1.0 before a market is flagged
+pub const MARKET_CONSISTENCY_TOLERANCE: f64 = 0.01;
+
+// Page size used when paginating Gamma API listings by offset
+pub const GAMMA_PAGE_LIMIT: u64 = 500;
+
+// Upper bound on in-flight requests when fanning out per-item lookups
+pub const MAX_CONCURRENT_REQUESTS: usize = 8;
+
+// Most order ids sent in one `cancel_orders` or `are_orders_scoring` request; longer lists
+// are split, since the CLOB rejects oversized batches as a whole
+pub const DEFAULT_MAX_BATCH: usize = 100;
+
+// Seconds added to GTD expirations before signing, since the matching engine expires
+// GTD orders a safety window early
+pub const SECONDS_DELAY: u64 = 60;
+
+// Order salts are drawn below 2^53 so they stay exact as JSON numbers in JavaScript
+pub const MAX_ORDER_SALT: u64 = 1 << 53;
+
+// How long condition id -> (YES, NO) token id lookups are cached
+pub const TOKEN_PAIR_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+// Default for how long token fee rate lookups are cached
+pub const FEE_RATE_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+// Upper bound on the delay between notification polls after repeated errors
+pub const NOTIFICATION_POLL_MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+// How long a measured server clock offset is trusted before `/time` is queried again
+pub const SERVER_TIME_OFFSET_TTL: Duration = Duration::from_secs(5 * 60);
+
+// Largest local clock skew tolerated before a rejected L2 request is reported as ClockDrift
+pub const CLOCK_DRIFT_TOLERANCE_SECS: i64 = 30;
+
+// Default number of order events kept by ClobClient::recent_order_events
+pub const DEFAULT_ORDER_EVENT_CAPACITY: usize = 100;
+
+// WebSocket channels: public market data, and order/trade updates for an API key
+pub const WS_MARKET_CHANNEL_URL: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/market";
+pub const WS_USER_CHANNEL_URL: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/user";
+
+// Interval between keep-alive `PING` messages on WebSocket channels
+pub const WS_PING_INTERVAL: Duration = Duration::from_secs(10);
+
+// Delay before reconnecting a dropped WebSocket channel, doubled per consecutive failure
+// up to WS_RECONNECT_MAX_DELAY
+pub const WS_RECONNECT_BASE_DELAY: Duration = Duration::from_millis(500);
+pub const WS_RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+
 // EIP-712 constants for CLOB authentication
 pub const CLOB_DOMAIN_NAME: &str = "ClobAuthDomain";
 pub const CLOB_VERSION: &str = "1";
 pub const MSG_TO_SIGN: &str = "This message attests that I control the given wallet";
 
-pub fn get_contract_config(chain_id: u64) -> Result<&'static ContractConfig, String> {
-    match chain_id {
-        137 => Ok(&MATIC_CONTRACTS),
-        80002 => Ok(&AMOY_CONTRACTS),
-        _ => Err(format!("Invalid network: chain ID {}", chain_id)),
+// EIP-712 domain of the CTF exchange contracts (neg-risk exchange included)
+pub const EXCHANGE_DOMAIN_NAME: &str = "Polymarket CTF Exchange";
+pub const EXCHANGE_DOMAIN_VERSION: &str = "1";
+
+/// Exchange contracts for a chain id; `ConfigError` for a chain without deployments
+pub fn get_contract_config(chain_id: u64) -> ClobResult<&'static ContractConfig> {
+    match Chain::from_chain_id(chain_id) {
+        Some(Chain::Polygon) => Ok(&MATIC_CONTRACTS),
+        Some(Chain::Amoy) => Ok(&AMOY_CONTRACTS),
+        None => Err(ClobError::ConfigError(format!(
+            "No contracts configured for chain ID {}",
+            chain_id
+        ))),
     }
 }
 
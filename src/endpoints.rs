@@ -28,6 +28,12 @@ pub mod endpoints {
     pub const GET_MARKETS: &str = "/markets";
     pub const GET_MARKET: &str = "/markets/";
     pub const GET_MARKET_BY_SLUG: &str = "/markets/slug/";
+    // CLOB market by condition id
+    pub const GET_CLOB_MARKET: &str = "/markets/";
+    // CLOB market listings (cursor-paginated)
+    pub const GET_SAMPLING_MARKETS: &str = "/sampling-markets";
+    pub const GET_SIMPLIFIED_MARKETS: &str = "/simplified-markets";
+    pub const GET_SAMPLING_SIMPLIFIED_MARKETS: &str = "/sampling-simplified-markets";
 
     // Orderbook
     pub const GET_ORDER_BOOK: &str = "/book";
@@ -75,6 +81,9 @@ pub mod endpoints {
     pub const GET_LIQUIDITY_REWARD_PERCENTAGES: &str = "/rewards/user/percentages";
     pub const GET_REWARDS_EARNINGS_PERCENTAGES: &str = "/rewards/user/markets";
 
+    // Market Rewards
+    pub const GET_REWARDS_MARKETS: &str = "/rewards/markets/";
+
     // Builder endpoints
     pub const GET_BUILDER_TRADES: &str = "/builder/trades";
 }
@@ -1,3 +1,4 @@
+use serde::Deserialize;
 use thiserror::Error;
 
 /// Errors that can occur when using the CLOB client
@@ -31,6 +32,14 @@ pub enum ClobError {
     #[error("Invalid price ({price}), min: {min} - max: {max}")]
     InvalidPrice { price: f64, min: f64, max: f64 },
 
+    /// Price that is not a multiple of the market's tick size
+    #[error("Price {price} is not a multiple of the tick size {tick_size}")]
+    PriceNotOnTick { price: f64, tick_size: f64 },
+
+    /// Order smaller than the market's minimum order size
+    #[error("Order size {size} is below the market minimum of {min_size}")]
+    OrderSizeTooSmall { size: f64, min_size: f64 },
+
     /// Invalid tick size
     #[error("Invalid tick size ({tick_size}), minimum for the market is {min_tick_size}")]
     InvalidTickSize {
@@ -45,6 +54,14 @@ pub enum ClobError {
         market_fee_rate: u32,
     },
 
+    /// GTD order without an expiration
+    #[error("GTD orders require a non-zero expiration")]
+    MissingExpiration,
+
+    /// Market cancel without a `market` or `asset_id` to cancel
+    #[error("Market cancel needs a market or asset_id")]
+    MissingCancelTarget,
+
     /// No orderbook available
     #[error("No orderbook available")]
     NoOrderbook,
@@ -53,6 +70,22 @@ pub enum ClobError {
     #[error("No match found in orderbook")]
     NoMatch,
 
+    /// Orderbook whose `hash` doesn't match its contents
+    #[error("Orderbook hash mismatch: server sent {expected}, contents hash to {actual}")]
+    OrderbookHashMismatch { expected: String, actual: String },
+
+    /// Market order reached signing without a price
+    #[error("Market order has no price; set one or let the client resolve it from the book")]
+    MissingMarketPrice,
+
+    /// Not enough resting size to fill the order (within the allowed slippage, if any)
+    #[error("Insufficient liquidity: requested {requested}, available {available}")]
+    InsufficientLiquidity { requested: f64, available: f64 },
+
+    /// Market price computed from the book is outside the order's `max_price`/`min_price`
+    #[error("Slippage exceeded: market price {computed} is beyond the limit {limit}")]
+    SlippageExceeded { limit: f64, computed: f64 },
+
     /// Ethereum wallet error
     #[error("Ethereum wallet error: {0}")]
     WalletError(String),
@@ -69,14 +102,66 @@ pub enum ClobError {
     #[error("Invalid configuration: {0}")]
     ConfigError(String),
 
+    /// L2 auth rejected while the local clock is off the server's by more than
+    /// `CLOCK_DRIFT_TOLERANCE_SECS` (`skew` is server minus local, in seconds)
+    #[error("Local clock is {skew}s off the server (local {local}, server {server}); sync the system clock or enable use_server_time")]
+    ClockDrift { local: u64, server: u64, skew: i64 },
+
     /// API error response
     #[error("API error: {message}")]
-    ApiError { message: String, status: u16 },
+    ApiError {
+        /// Raw response body
+        message: String,
+        status: u16,
+        /// Error string reported by the server (`errorMsg`, else `error`), if the body is JSON
+        error_code: Option<String>,
+    },
 
     /// Generic error
     #[error("{0}")]
     Other(String),
 }
 
+impl ClobError {
+    /// Builds an `ApiError` from an error response, extracting the server's error string
+    ///
+    /// The CLOB reports errors as `{"error": "..."}`, and order posts as
+    /// `{"error": "...", "errorMsg": "..."}`; `errorMsg` is preferred when present.
+    pub fn api_error(status: u16, body: String) -> Self {
+        let error_code = serde_json::from_str::<ApiErrorBody>(&body)
+            .ok()
+            .and_then(|parsed| {
+                parsed
+                    .error_msg
+                    .filter(|msg| !msg.is_empty())
+                    .or(parsed.error)
+            })
+            .filter(|code| !code.is_empty());
+
+        ClobError::ApiError {
+            message: body,
+            status,
+            error_code,
+        }
+    }
+
+    /// Server error string of an `ApiError`, e.g. `"not enough balance / allowance"`
+    pub fn error_code(&self) -> Option<&str> {
+        match self {
+            ClobError::ApiError { error_code, .. } => error_code.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Error body returned by the CLOB API
+#[derive(Deserialize)]
+struct ApiErrorBody {
+    #[serde(default)]
+    error: Option<String>,
+    #[serde(rename = "errorMsg", default)]
+    error_msg: Option<String>,
+}
+
 /// Result type alias for CLOB operations
 pub type ClobResult<T> = Result<T, ClobError>;
@@ -1,21 +1,20 @@
 use crate::errors::ClobResult;
 use crate::signing::eip712::build_clob_eip712_signature;
+use crate::signing::ClobSigner;
+use crate::time::TimeProvider;
 use crate::types::L1PolyHeader;
-use alloy_signer_local::PrivateKeySigner;
 
 /// Creates L1 authentication headers using EIP-712 signature for API key management
+///
+/// Signs at `timestamp`, or at the current time of `time` when it is `None`.
 pub async fn create_l1_headers(
-    wallet: &PrivateKeySigner,
+    wallet: &dyn ClobSigner,
     chain_id: u64,
     nonce: Option<u64>,
     timestamp: Option<u64>,
+    time: &dyn TimeProvider,
 ) -> ClobResult<L1PolyHeader> {
-    let ts = timestamp.unwrap_or_else(|| {
-        std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs()
-    });
+    let ts = timestamp.unwrap_or_else(|| time.now_secs());
 
     let n = nonce.unwrap_or(0);
     let signature = build_clob_eip712_signature(wallet, chain_id, ts, n).await?;
@@ -1,30 +1,28 @@
+use crate::builder_sdk::BuilderHeaderPayload;
 use crate::errors::ClobResult;
 use crate::signing::hmac::build_poly_hmac_signature;
+use crate::time::TimeProvider;
 use crate::types::{ApiKeyCreds, L2PolyHeader, L2WithBuilderHeader};
-use alloy_signer_local::PrivateKeySigner;
-use rs_builder_signing_sdk::BuilderHeaderPayload;
+use alloy_primitives::Address;
 
 /// Creates L2 authentication headers using HMAC-SHA256 for trading operations
+///
+/// Signs at `timestamp`, or at the current time of `time` when it is `None`.
 pub async fn create_l2_headers(
-    wallet: &PrivateKeySigner,
+    address: Address,
     creds: &ApiKeyCreds,
     method: &str,
     request_path: &str,
     body: Option<&str>,
     timestamp: Option<u64>,
+    time: &dyn TimeProvider,
 ) -> ClobResult<L2PolyHeader> {
-    let ts = timestamp.unwrap_or_else(|| {
-        std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs()
-    });
+    let ts = timestamp.unwrap_or_else(|| time.now_secs());
 
     let signature = build_poly_hmac_signature(&creds.secret, ts, method, request_path, body)?;
-    let address = format!("{:#x}", wallet.address());
 
     Ok(L2PolyHeader {
-        poly_address: address,
+        poly_address: format!("{:#x}", address),
         poly_signature: signature,
         poly_timestamp: ts.to_string(),
         poly_api_key: creds.key.clone(),
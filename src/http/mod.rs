@@ -1,3 +1,7 @@
 mod client;
+mod transport;
 
 pub use client::HttpClient;
+pub use transport::HttpTransport;
+#[cfg(test)]
+pub(crate) use transport::MockTransport;
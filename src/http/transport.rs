@@ -0,0 +1,230 @@
+use crate::errors::{ClobError, ClobResult};
+use crate::types::{RequestIdGenerator, RetryConfig};
+use crate::utilities::BoxFuture;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Transport the client sends its CLOB and Gamma requests through
+///
+/// `HttpClient` is the default implementation. Bodies travel as JSON values so the trait
+/// stays object safe; `get_json`/`post_json`/`delete_json` on `dyn HttpTransport` handle
+/// the conversion to and from typed values.
+pub trait HttpTransport: Send + Sync {
+    /// Copy of the transport for a cloned client
+    fn clone_box(&self) -> Box<dyn HttpTransport>;
+
+    /// Send a GET request
+    fn get<'a>(
+        &'a self,
+        endpoint: &'a str,
+        headers: Option<HashMap<String, String>>,
+        params: Option<HashMap<String, String>>,
+    ) -> BoxFuture<'a, ClobResult<Value>>;
+
+    /// Send a POST request
+    fn post<'a>(
+        &'a self,
+        endpoint: &'a str,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Value>,
+        params: Option<HashMap<String, String>>,
+    ) -> BoxFuture<'a, ClobResult<Value>>;
+
+    /// Send a DELETE request
+    fn delete<'a>(
+        &'a self,
+        endpoint: &'a str,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Value>,
+        params: Option<HashMap<String, String>>,
+    ) -> BoxFuture<'a, ClobResult<Value>>;
+
+    /// Override the User-Agent sent with every request (ignored by default)
+    fn set_user_agent(&mut self, _user_agent: String) {}
+
+    /// Set the retry policy for transient failures (ignored by default)
+    fn set_retry_config(&mut self, _retry_config: Option<RetryConfig>) {}
+
+    /// Set the generator for `X-Request-Id` headers (ignored by default)
+    fn set_request_id_generator(&mut self, _generator: Option<RequestIdGenerator>) {}
+
+    /// Set the geo-block token sent as a query parameter (ignored by default)
+    fn set_geo_block_token(&mut self, _geo_block_token: Option<String>) {}
+
+    /// Underlying reqwest client, for transports backed by one
+    #[cfg(test)]
+    fn reqwest_client(&self) -> Option<&std::sync::Arc<reqwest::Client>> {
+        None
+    }
+}
+
+impl Clone for Box<dyn HttpTransport> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+impl dyn HttpTransport {
+    /// Send a GET request and decode the response
+    pub async fn get_json<T>(
+        &self,
+        endpoint: &str,
+        headers: Option<HashMap<String, String>>,
+        params: Option<HashMap<String, String>>,
+    ) -> ClobResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self.get(endpoint, headers, params).await?;
+        decode(endpoint, response)
+    }
+
+    /// Send a POST request and decode the response
+    pub async fn post_json<T, B>(
+        &self,
+        endpoint: &str,
+        headers: Option<HashMap<String, String>>,
+        body: Option<B>,
+        params: Option<HashMap<String, String>>,
+    ) -> ClobResult<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let body = body.map(serde_json::to_value).transpose()?;
+        let response = self.post(endpoint, headers, body, params).await?;
+        decode(endpoint, response)
+    }
+
+    /// Send a DELETE request and decode the response
+    pub async fn delete_json<T, B>(
+        &self,
+        endpoint: &str,
+        headers: Option<HashMap<String, String>>,
+        body: Option<B>,
+        params: Option<HashMap<String, String>>,
+    ) -> ClobResult<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let body = body.map(serde_json::to_value).transpose()?;
+        let response = self.delete(endpoint, headers, body, params).await?;
+        decode(endpoint, response)
+    }
+}
+
+/// Decode a JSON response into the type the caller expects
+fn decode<T: DeserializeOwned>(endpoint: &str, response: Value) -> ClobResult<T> {
+    serde_json::from_value(response).map_err(|e| {
+        let error_msg = format!("Failed to parse JSON response: {}", e);
+        tracing::error!(endpoint, "{}", error_msg);
+        ClobError::Other(error_msg)
+    })
+}
+
+/// A request recorded by `MockTransport`
+#[cfg(test)]
+#[derive(Debug, Clone)]
+pub(crate) struct MockRequest {
+    pub method: &'static str,
+    pub endpoint: String,
+    pub params: HashMap<String, String>,
+}
+
+/// Transport answering each request with the next canned response, in order
+///
+/// Clones share the response queue and the recorded requests.
+#[cfg(test)]
+#[derive(Default, Clone)]
+pub(crate) struct MockTransport {
+    responses: std::sync::Arc<std::sync::Mutex<std::collections::VecDeque<ClobResult<Value>>>>,
+    requests: std::sync::Arc<std::sync::Mutex<Vec<MockRequest>>>,
+}
+
+#[cfg(test)]
+impl MockTransport {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a successful JSON response
+    pub(crate) fn respond(self, response: Value) -> Self {
+        self.responses.lock().unwrap().push_back(Ok(response));
+        self
+    }
+
+    /// Queue a failed response
+    pub(crate) fn fail(self, error: ClobError) -> Self {
+        self.responses.lock().unwrap().push_back(Err(error));
+        self
+    }
+
+    /// Handle on the requests sent so far, still readable once the transport is boxed
+    pub(crate) fn requests(&self) -> std::sync::Arc<std::sync::Mutex<Vec<MockRequest>>> {
+        self.requests.clone()
+    }
+
+    fn answer(
+        &self,
+        method: &'static str,
+        endpoint: &str,
+        params: Option<HashMap<String, String>>,
+    ) -> BoxFuture<'static, ClobResult<Value>> {
+        self.requests.lock().unwrap().push(MockRequest {
+            method,
+            endpoint: endpoint.to_string(),
+            params: params.unwrap_or_default(),
+        });
+        let response = self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap_or_else(|| {
+                Err(ClobError::Other(format!(
+                    "No mock response left for {} {}",
+                    method, endpoint
+                )))
+            });
+        Box::pin(async move { response })
+    }
+}
+
+#[cfg(test)]
+impl HttpTransport for MockTransport {
+    fn clone_box(&self) -> Box<dyn HttpTransport> {
+        Box::new(self.clone())
+    }
+
+    fn get<'a>(
+        &'a self,
+        endpoint: &'a str,
+        _headers: Option<HashMap<String, String>>,
+        params: Option<HashMap<String, String>>,
+    ) -> BoxFuture<'a, ClobResult<Value>> {
+        self.answer("GET", endpoint, params)
+    }
+
+    fn post<'a>(
+        &'a self,
+        endpoint: &'a str,
+        _headers: Option<HashMap<String, String>>,
+        _body: Option<Value>,
+        params: Option<HashMap<String, String>>,
+    ) -> BoxFuture<'a, ClobResult<Value>> {
+        self.answer("POST", endpoint, params)
+    }
+
+    fn delete<'a>(
+        &'a self,
+        endpoint: &'a str,
+        _headers: Option<HashMap<String, String>>,
+        _body: Option<Value>,
+        params: Option<HashMap<String, String>>,
+    ) -> BoxFuture<'a, ClobResult<Value>> {
+        self.answer("DELETE", endpoint, params)
+    }
+}
@@ -1,3 +1,7 @@
+// wasm32 has no system clock or timers without `js-sys`
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+compile_error!("building for wasm32 needs the `wasm` feature");
+
 // Public modules
 pub mod client;
 pub mod constants;
@@ -7,19 +11,27 @@ pub mod types;
 pub mod utilities;
 pub mod headers;
 pub mod signing;
+pub mod pagination;
+pub mod time;
+#[cfg(feature = "ws")]
+pub mod ws;
 
 // Internal modules
+mod builder_sdk;
 mod http;
 mod order_builder;
 
 // Re-exports for convenience
-pub use client::ClobClient;
+pub use client::{ClobClient, ClobClientBuilder};
 pub use errors::{ClobError, ClobResult};
+pub use pagination::{Page, Paginator};
+#[cfg(feature = "decimal")]
+pub use rust_decimal::Decimal;
 pub use types::*;
 
 // Prelude module for common imports
 pub mod prelude {
-    pub use crate::client::ClobClient;
+    pub use crate::client::{ClobClient, ClobClientBuilder};
     pub use crate::errors::{ClobError, ClobResult};
     pub use crate::types::{ApiKeyCreds, Chain, OrderType, Side, UserMarketOrder, UserLimitOrder};
 }
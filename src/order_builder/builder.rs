@@ -1,5 +1,6 @@
 use crate::errors::ClobResult;
-use crate::types::{Chain, CreateOrderOptions, UserMarketOrder, UserLimitOrder};
+use crate::signing::ClobSigner;
+use crate::types::{Chain, CreateOrderOptions, UserLimitOrder, UserMarketOrder};
 use alloy_primitives::Address;
 use alloy_signer_local::PrivateKeySigner;
 use rs_order_utils::{SignatureType, SignedOrder};
@@ -7,7 +8,11 @@ use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 
-use super::helpers::{create_market_order, create_limit_order};
+#[cfg(feature = "decimal")]
+use super::decimal::{create_limit_order_decimal, create_market_order_decimal};
+use super::helpers::{create_limit_order, create_market_order};
+#[cfg(feature = "decimal")]
+use crate::types::{UserMarketOrderDecimal, UserOrderDecimal};
 
 /// Type alias for dynamic signer resolver function
 type GetSignerFn = Arc<
@@ -15,8 +20,9 @@ type GetSignerFn = Arc<
 >;
 
 /// OrderBuilder creates and signs orders for the Polymarket CLOB
+#[derive(Clone)]
 pub struct OrderBuilder {
-    wallet: PrivateKeySigner,
+    signer: Arc<dyn ClobSigner>,
     chain_id: Chain,
     signature_type: SignatureType,
     /// Optional funder address for smart contract wallets
@@ -28,14 +34,14 @@ pub struct OrderBuilder {
 impl OrderBuilder {
     /// Creates a new OrderBuilder
     pub fn new(
-        wallet: PrivateKeySigner,
+        signer: Arc<dyn ClobSigner>,
         chain_id: Chain,
         signature_type: Option<SignatureType>,
         funder_address: Option<Address>,
         get_signer: Option<GetSignerFn>,
     ) -> Self {
         Self {
-            wallet,
+            signer,
             chain_id,
             signature_type: signature_type.unwrap_or(SignatureType::Eoa),
             funder_address,
@@ -51,7 +57,7 @@ impl OrderBuilder {
     ) -> ClobResult<SignedOrder> {
         let signer = self.resolve_signer().await?;
         create_limit_order(
-            signer,
+            signer.as_ref(),
             self.chain_id,
             self.signature_type,
             self.funder_address,
@@ -69,7 +75,45 @@ impl OrderBuilder {
     ) -> ClobResult<SignedOrder> {
         let signer = self.resolve_signer().await?;
         create_market_order(
-            signer,
+            signer.as_ref(),
+            self.chain_id,
+            self.signature_type,
+            self.funder_address,
+            user_market_order,
+            options,
+        )
+        .await
+    }
+
+    /// Generates and signs a limit order with decimal price and size
+    #[cfg(feature = "decimal")]
+    pub async fn build_limit_order_decimal(
+        &self,
+        user_order: &UserOrderDecimal,
+        options: &CreateOrderOptions,
+    ) -> ClobResult<SignedOrder> {
+        let signer = self.resolve_signer().await?;
+        create_limit_order_decimal(
+            signer.as_ref(),
+            self.chain_id,
+            self.signature_type,
+            self.funder_address,
+            user_order,
+            options,
+        )
+        .await
+    }
+
+    /// Generates and signs a market order with decimal price and amount
+    #[cfg(feature = "decimal")]
+    pub async fn build_market_order_decimal(
+        &self,
+        user_market_order: &UserMarketOrderDecimal,
+        options: &CreateOrderOptions,
+    ) -> ClobResult<SignedOrder> {
+        let signer = self.resolve_signer().await?;
+        create_market_order_decimal(
+            signer.as_ref(),
             self.chain_id,
             self.signature_type,
             self.funder_address,
@@ -79,12 +123,12 @@ impl OrderBuilder {
         .await
     }
 
-    /// Resolves the signer: uses get_signer if provided, otherwise returns the static wallet
-    async fn resolve_signer(&self) -> ClobResult<PrivateKeySigner> {
+    /// Resolves the signer: uses get_signer if provided, otherwise returns the static signer
+    async fn resolve_signer(&self) -> ClobResult<Arc<dyn ClobSigner>> {
         if let Some(ref get_signer_fn) = self.get_signer {
-            get_signer_fn().await
+            Ok(Arc::new(get_signer_fn().await?))
         } else {
-            Ok(self.wallet.clone())
+            Ok(self.signer.clone())
         }
     }
 }
@@ -96,7 +140,7 @@ mod tests {
     #[test]
     fn test_order_builder_creation() {
         let wallet = PrivateKeySigner::random();
-        let builder = OrderBuilder::new(wallet, Chain::Amoy, None, None, None);
+        let builder = OrderBuilder::new(Arc::new(wallet), Chain::Amoy, None, None, None);
 
         assert_eq!(builder.chain_id, Chain::Amoy);
         assert_eq!(builder.signature_type, SignatureType::Eoa);
@@ -110,7 +154,7 @@ mod tests {
         let funder = wallet.address();
 
         let builder = OrderBuilder::new(
-            wallet,
+            Arc::new(wallet),
             Chain::Polygon,
             Some(SignatureType::PolyProxy),
             Some(funder),
@@ -127,7 +171,7 @@ mod tests {
         let wallet = PrivateKeySigner::random();
         let original_address = wallet.address();
 
-        let builder = OrderBuilder::new(wallet, Chain::Amoy, None, None, None);
+        let builder = OrderBuilder::new(Arc::new(wallet), Chain::Amoy, None, None, None);
 
         let resolved = builder.resolve_signer().await.unwrap();
         assert_eq!(resolved.address(), original_address);
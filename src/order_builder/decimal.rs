@@ -0,0 +1,394 @@
+use super::helpers::{
+    build_order, exchange_address, exchange_side, get_rounding_config, limit_order_expiration,
+    parse_token_id, validate_expiration, MARKET_ORDER_MAKER_DECIMALS, MARKET_ORDER_TAKER_DECIMALS,
+};
+use crate::constants::COLLATERAL_TOKEN_DECIMALS;
+use crate::errors::{ClobError, ClobResult};
+use crate::signing::ClobSigner;
+use crate::types::{
+    Chain, CreateOrderOptions, RoundConfig, Side, TickSize, UserMarketOrderDecimal,
+    UserOrderDecimal,
+};
+use alloy_primitives::{Address, U256};
+use rs_order_utils::{OrderData, SignatureType, SignedOrder};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// Decimal counterpart of [`RawAmounts`](super::helpers::RawAmounts)
+pub struct RawAmountsDecimal {
+    pub side: Side,
+    pub raw_maker_amt: Decimal,
+    pub raw_taker_amt: Decimal,
+}
+
+fn round_down(value: Decimal, decimals: u32) -> Decimal {
+    value.round_dp_with_strategy(decimals, RoundingStrategy::ToZero)
+}
+
+fn invalid_price(price: Decimal, tick: Decimal) -> ClobError {
+    ClobError::InvalidPrice {
+        price: price.to_f64().unwrap_or(f64::NAN),
+        min: tick.to_f64().unwrap_or(f64::NAN),
+        max: (Decimal::ONE - tick).to_f64().unwrap_or(f64::NAN),
+    }
+}
+
+/// Rejects prices outside `[tick, 1 - tick]`, the range the exchange accepts
+pub fn validate_price_decimal(price: Decimal, tick_size: TickSize) -> ClobResult<()> {
+    let tick = Decimal::new(1, get_rounding_config(tick_size).price);
+    if price < tick || price > Decimal::ONE - tick {
+        return Err(invalid_price(price, tick));
+    }
+    Ok(())
+}
+
+/// Computes limit order amounts exactly, rejecting prices that are not a multiple of the
+/// tick size
+pub fn get_order_raw_amounts_decimal(
+    side: Side,
+    size: Decimal,
+    price: Decimal,
+    round_config: &RoundConfig,
+) -> ClobResult<RawAmountsDecimal> {
+    if price.normalize().scale() > round_config.price {
+        return Err(ClobError::PriceNotOnTick {
+            price: price.to_f64().unwrap_or(f64::NAN),
+            tick_size: Decimal::new(1, round_config.price)
+                .to_f64()
+                .unwrap_or(f64::NAN),
+        });
+    }
+
+    let size = round_down(size, round_config.size);
+    let amount = round_down(size * price, round_config.amount);
+
+    Ok(match side {
+        Side::Buy => RawAmountsDecimal {
+            side,
+            raw_maker_amt: amount,
+            raw_taker_amt: size,
+        },
+        Side::Sell => RawAmountsDecimal {
+            side,
+            raw_maker_amt: size,
+            raw_taker_amt: amount,
+        },
+    })
+}
+
+/// Computes market order amounts exactly, with the API's 2 (maker) and 5 (taker) decimal
+/// precision limits
+pub fn get_market_order_raw_amounts_decimal(
+    side: Side,
+    amount: Decimal,
+    price: Decimal,
+    round_config: &RoundConfig,
+) -> ClobResult<RawAmountsDecimal> {
+    let raw_price = round_down(price, round_config.price);
+    let raw_maker_amt = round_down(amount, MARKET_ORDER_MAKER_DECIMALS);
+
+    let raw_taker_amt = match side {
+        // Maker pays USDC and receives shares
+        Side::Buy => raw_maker_amt
+            .checked_div(raw_price)
+            .ok_or_else(|| invalid_price(price, Decimal::new(1, round_config.price)))?,
+        // Maker sells shares and receives USDC
+        Side::Sell => raw_maker_amt * raw_price,
+    };
+
+    Ok(RawAmountsDecimal {
+        side,
+        raw_maker_amt,
+        raw_taker_amt: round_down(raw_taker_amt, MARKET_ORDER_TAKER_DECIMALS),
+    })
+}
+
+/// Converts a decimal amount to its integer on-chain representation with `decimals` places,
+/// truncating any further digits. Negative values map to zero.
+pub(crate) fn to_units(value: Decimal, decimals: u32) -> U256 {
+    round_down(value, decimals)
+        .checked_mul(Decimal::from(10_u64.pow(decimals)))
+        .and_then(|units| units.to_u128())
+        .map(U256::from)
+        .unwrap_or(U256::ZERO)
+}
+
+pub fn build_limit_order_creation_args_decimal(
+    signer_address: Address,
+    maker: Address,
+    signature_type: SignatureType,
+    user_order: &UserOrderDecimal,
+    round_config: &RoundConfig,
+) -> ClobResult<OrderData> {
+    let raw_amounts = get_order_raw_amounts_decimal(
+        user_order.side,
+        user_order.size,
+        user_order.price,
+        round_config,
+    )?;
+
+    validate_expiration(user_order.order_type, user_order.expiration)?;
+
+    let decimals = COLLATERAL_TOKEN_DECIMALS as u32;
+    Ok(OrderData {
+        maker,
+        taker: user_order.taker.unwrap_or(Address::ZERO),
+        token_id: parse_token_id(&user_order.token_id)?,
+        maker_amount: to_units(raw_amounts.raw_maker_amt, decimals),
+        taker_amount: to_units(raw_amounts.raw_taker_amt, decimals),
+        side: exchange_side(raw_amounts.side),
+        fee_rate_bps: U256::from(user_order.fee_rate_bps.unwrap_or(0)),
+        nonce: U256::from(user_order.nonce.unwrap_or(0)),
+        signer: Some(signer_address),
+        expiration: limit_order_expiration(user_order.expiration, user_order.expiration_buffer),
+        signature_type: Some(signature_type),
+    })
+}
+
+pub fn build_market_order_creation_args_decimal(
+    signer_address: Address,
+    maker: Address,
+    signature_type: SignatureType,
+    user_market_order: &UserMarketOrderDecimal,
+    round_config: &RoundConfig,
+) -> ClobResult<OrderData> {
+    let price = user_market_order
+        .price
+        .ok_or(ClobError::MissingMarketPrice)?;
+
+    let raw_amounts = get_market_order_raw_amounts_decimal(
+        user_market_order.side,
+        user_market_order.amount,
+        price,
+        round_config,
+    )?;
+
+    validate_expiration(user_market_order.order_type, user_market_order.expiration)?;
+
+    let decimals = COLLATERAL_TOKEN_DECIMALS as u32;
+    Ok(OrderData {
+        maker,
+        taker: user_market_order.taker.unwrap_or(Address::ZERO),
+        token_id: parse_token_id(&user_market_order.token_id)?,
+        maker_amount: to_units(raw_amounts.raw_maker_amt, decimals),
+        taker_amount: to_units(raw_amounts.raw_taker_amt, decimals),
+        side: exchange_side(raw_amounts.side),
+        fee_rate_bps: U256::from(user_market_order.fee_rate_bps.unwrap_or(0)),
+        nonce: U256::from(user_market_order.nonce.unwrap_or(0)),
+        signer: Some(signer_address),
+        expiration: Some(U256::from(user_market_order.expiration.unwrap_or(0))),
+        signature_type: Some(signature_type),
+    })
+}
+
+pub async fn create_limit_order_decimal(
+    signer: &dyn ClobSigner,
+    chain_id: Chain,
+    signature_type: SignatureType,
+    funder_address: Option<Address>,
+    user_order: &UserOrderDecimal,
+    options: &CreateOrderOptions,
+) -> ClobResult<SignedOrder> {
+    let signer_address = signer.address();
+    let maker = funder_address.unwrap_or(signer_address);
+
+    validate_price_decimal(user_order.price, options.tick_size)?;
+
+    let order_data = build_limit_order_creation_args_decimal(
+        signer_address,
+        maker,
+        signature_type,
+        user_order,
+        &get_rounding_config(options.tick_size),
+    )?;
+
+    let exchange_contract = exchange_address(chain_id, options.neg_risk)?;
+    build_order(signer, exchange_contract, chain_id.chain_id(), order_data).await
+}
+
+pub async fn create_market_order_decimal(
+    signer: &dyn ClobSigner,
+    chain_id: Chain,
+    signature_type: SignatureType,
+    funder_address: Option<Address>,
+    user_market_order: &UserMarketOrderDecimal,
+    options: &CreateOrderOptions,
+) -> ClobResult<SignedOrder> {
+    let signer_address = signer.address();
+    let maker = funder_address.unwrap_or(signer_address);
+
+    if let Some(price) = user_market_order.price {
+        validate_price_decimal(price, options.tick_size)?;
+    }
+
+    let order_data = build_market_order_creation_args_decimal(
+        signer_address,
+        maker,
+        signature_type,
+        user_market_order,
+        &get_rounding_config(options.tick_size),
+    )?;
+
+    let exchange_contract = exchange_address(chain_id, options.neg_risk)?;
+    build_order(signer, exchange_contract, chain_id.chain_id(), order_data).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn limit_order(side: Side, price: Decimal, size: Decimal) -> UserOrderDecimal {
+        UserOrderDecimal {
+            token_id: "1234".to_string(),
+            price,
+            size,
+            side,
+            fee_rate_bps: None,
+            nonce: None,
+            expiration: None,
+            expiration_buffer: None,
+            taker: None,
+            order_type: None,
+        }
+    }
+
+    fn market_order(side: Side, price: Decimal, amount: Decimal) -> UserMarketOrderDecimal {
+        UserMarketOrderDecimal {
+            token_id: "1234".to_string(),
+            price: Some(price),
+            amount,
+            side,
+            fee_rate_bps: None,
+            nonce: None,
+            taker: None,
+            expiration: None,
+            order_type: None,
+            max_price: None,
+            min_price: None,
+        }
+    }
+
+    fn limit_units(order: &UserOrderDecimal, tick_size: TickSize) -> (U256, U256) {
+        let data = build_limit_order_creation_args_decimal(
+            Address::ZERO,
+            Address::ZERO,
+            SignatureType::Eoa,
+            order,
+            &get_rounding_config(tick_size),
+        )
+        .unwrap();
+        (data.maker_amount, data.taker_amount)
+    }
+
+    #[test]
+    fn test_limit_amounts_are_exact() {
+        // 0.07 * 3 is 0.21000000000000002 as f64
+        let price = dec("0.07") * Decimal::from(3);
+        let size = dec("0.07") * Decimal::from(3) * Decimal::from(100);
+
+        let buy = limit_order(Side::Buy, price, size);
+        assert_eq!(
+            limit_units(&buy, TickSize::ZeroPointZeroOne),
+            (U256::from(4_410_000u64), U256::from(21_000_000u64))
+        );
+
+        let sell = limit_order(Side::Sell, price, size);
+        assert_eq!(
+            limit_units(&sell, TickSize::ZeroPointZeroOne),
+            (U256::from(21_000_000u64), U256::from(4_410_000u64))
+        );
+    }
+
+    #[test]
+    fn test_limit_amounts_truncate_size() {
+        let order = limit_order(Side::Buy, dec("0.5"), dec("5.55555"));
+        assert_eq!(
+            limit_units(&order, TickSize::ZeroPointZeroOne),
+            (U256::from(2_775_000u64), U256::from(5_550_000u64))
+        );
+    }
+
+    #[test]
+    fn test_limit_rejects_off_tick_price() {
+        let result = get_order_raw_amounts_decimal(
+            Side::Buy,
+            dec("10"),
+            dec("0.505"),
+            &get_rounding_config(TickSize::ZeroPointZeroOne),
+        );
+        assert!(matches!(result, Err(ClobError::PriceNotOnTick { .. })));
+
+        // Trailing zeros are not extra precision
+        assert!(get_order_raw_amounts_decimal(
+            Side::Buy,
+            dec("10"),
+            dec("0.5000"),
+            &get_rounding_config(TickSize::ZeroPointZeroOne)
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_validate_price_decimal_bounds() {
+        assert!(validate_price_decimal(dec("0.01"), TickSize::ZeroPointZeroOne).is_ok());
+        assert!(validate_price_decimal(dec("0.99"), TickSize::ZeroPointZeroOne).is_ok());
+        assert!(validate_price_decimal(dec("0.001"), TickSize::ZeroPointZeroOne).is_err());
+        assert!(validate_price_decimal(dec("1"), TickSize::ZeroPointZeroOne).is_err());
+    }
+
+    #[test]
+    fn test_market_amounts_are_exact() {
+        let round_config = get_rounding_config(TickSize::ZeroPointZeroOne);
+        let amount = dec("0.1") * Decimal::from(3);
+
+        let buy = market_order(Side::Buy, dec("0.3"), amount);
+        let data = build_market_order_creation_args_decimal(
+            Address::ZERO,
+            Address::ZERO,
+            SignatureType::Eoa,
+            &buy,
+            &round_config,
+        )
+        .unwrap();
+        assert_eq!(data.maker_amount, U256::from(300_000u64));
+        assert_eq!(data.taker_amount, U256::from(1_000_000u64));
+
+        let sell = market_order(Side::Sell, dec("0.07") * Decimal::from(3), dec("10"));
+        let data = build_market_order_creation_args_decimal(
+            Address::ZERO,
+            Address::ZERO,
+            SignatureType::Eoa,
+            &sell,
+            &round_config,
+        )
+        .unwrap();
+        assert_eq!(data.maker_amount, U256::from(10_000_000u64));
+        assert_eq!(data.taker_amount, U256::from(2_100_000u64));
+    }
+
+    #[test]
+    fn test_market_amounts_respect_api_precision() {
+        let raw = get_market_order_raw_amounts_decimal(
+            Side::Buy,
+            dec("10.129"),
+            dec("0.33"),
+            &get_rounding_config(TickSize::ZeroPointZeroOne),
+        )
+        .unwrap();
+        assert_eq!(raw.raw_maker_amt, dec("10.12"));
+        // 10.12 / 0.33 = 30.666...
+        assert_eq!(raw.raw_taker_amt, dec("30.66666"));
+    }
+
+    #[test]
+    fn test_to_units() {
+        assert_eq!(to_units(dec("5.55555"), 6), U256::from(5_555_550u64));
+        assert_eq!(to_units(dec("0.1234567"), 6), U256::from(123_456u64));
+        assert_eq!(to_units(dec("-1"), 6), U256::ZERO);
+    }
+}
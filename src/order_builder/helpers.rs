@@ -1,13 +1,18 @@
-use crate::constants::{get_contract_config, COLLATERAL_TOKEN_DECIMALS};
+use crate::constants::{
+    get_contract_config, COLLATERAL_TOKEN_DECIMALS, MAX_ORDER_SALT, SECONDS_DELAY,
+};
 use crate::errors::{ClobError, ClobResult};
+use crate::signing::eip712::{order_hash, order_typed_data};
+use crate::signing::signer::{signature_to_hex, ClobSigner};
 use crate::types::{
-    Chain, CreateOrderOptions, OrderSummary, OrderType, RoundConfig, Side, TickSize,
-    UserMarketOrder, UserLimitOrder,
+    Chain, CreateOrderOptions, MarketOrderEstimate, OrderBookCheck, OrderBookSummary, OrderSummary,
+    OrderType, RoundConfig, Side, TickSize, UserLimitOrder, UserMarketOrder,
 };
-use crate::utilities::{decimal_places, round_down, round_normal, round_up};
-use alloy_primitives::{Address, U256};
-use alloy_signer_local::PrivateKeySigner;
-use rs_order_utils::{ExchangeOrderBuilder, OrderData, SignatureType, SignedOrder};
+use crate::utilities::{decimal_places, price_valid, round_down, round_normal, round_up};
+use alloy_primitives::{Address, Signature, U256};
+use rand::Rng;
+use rs_order_utils::{OrderData, SignatureType, SignedOrder};
+use serde_json::Value;
 use std::str::FromStr;
 
 pub fn get_rounding_config(tick_size: TickSize) -> RoundConfig {
@@ -41,15 +46,26 @@ pub struct RawAmounts {
     pub raw_taker_amt: f64,
 }
 
+/// Tolerance for float noise when checking that a price sits on a tick boundary
+const TICK_EPSILON: f64 = 1e-9;
+
+/// Computes limit order amounts, rejecting prices that are not a multiple of the tick size
+/// (the server would reject such an order after it has been signed)
 pub fn get_order_raw_amounts(
     side: Side,
     size: f64,
     price: f64,
     round_config: &RoundConfig,
-) -> RawAmounts {
+) -> ClobResult<RawAmounts> {
     let raw_price = round_normal(price, round_config.price);
+    if (price - raw_price).abs() > TICK_EPSILON {
+        return Err(ClobError::PriceNotOnTick {
+            price,
+            tick_size: 10_f64.powi(-(round_config.price as i32)),
+        });
+    }
 
-    match side {
+    let raw_amounts = match side {
         Side::Buy => {
             let raw_taker_amt = round_down(size, round_config.size);
             let mut raw_maker_amt = raw_taker_amt * raw_price;
@@ -84,14 +100,16 @@ pub fn get_order_raw_amounts(
                 raw_taker_amt,
             }
         }
-    }
+    };
+
+    Ok(raw_amounts)
 }
 
 /// Polymarket API precision limits for market orders:
 /// - maker_amount: max 2 decimal places
 /// - taker_amount: max 5 decimal places
-const MARKET_ORDER_MAKER_DECIMALS: u32 = 2;
-const MARKET_ORDER_TAKER_DECIMALS: u32 = 5;
+pub(super) const MARKET_ORDER_MAKER_DECIMALS: u32 = 2;
+pub(super) const MARKET_ORDER_TAKER_DECIMALS: u32 = 5;
 
 pub fn get_market_order_raw_amounts(
     side: Side,
@@ -131,28 +149,23 @@ pub fn get_market_order_raw_amounts(
     }
 }
 
+/// Walks the asks from the lowest price up and returns the price of the level at which the
+/// cumulative notional covers `amount_to_match`
+///
+/// Levels are sorted locally, so the order they were returned in doesn't matter. If the book
+/// can't cover the amount, FOK fails and other order types get the highest ask.
 pub fn calculate_buy_market_price(
     positions: &[OrderSummary],
     amount_to_match: f64,
     order_type: OrderType,
 ) -> ClobResult<f64> {
-    if positions.is_empty() {
+    let levels = levels_best_first(positions, Side::Buy)?;
+    let Some(&(worst_price, _)) = levels.last() else {
         return Err(ClobError::NoMatch);
-    }
+    };
 
     let mut sum = 0.0;
-
-    for i in (0..positions.len()).rev() {
-        let p = &positions[i];
-        let price: f64 = p
-            .price
-            .parse()
-            .map_err(|_| ClobError::Other("Invalid price in orderbook".to_string()))?;
-        let size: f64 = p
-            .size
-            .parse()
-            .map_err(|_| ClobError::Other("Invalid size in orderbook".to_string()))?;
-
+    for &(price, size) in &levels {
         sum += size * price;
         if sum >= amount_to_match {
             return Ok(price);
@@ -160,38 +173,32 @@ pub fn calculate_buy_market_price(
     }
 
     if order_type == OrderType::Fok {
-        return Err(ClobError::NoMatch);
+        return Err(ClobError::InsufficientLiquidity {
+            requested: amount_to_match,
+            available: sum,
+        });
     }
 
-    let first_price: f64 = positions[0]
-        .price
-        .parse()
-        .map_err(|_| ClobError::Other("Invalid price in orderbook".to_string()))?;
-    Ok(first_price)
+    Ok(worst_price)
 }
 
+/// Walks the bids from the highest price down and returns the price of the level at which
+/// the cumulative size covers `amount_to_match`
+///
+/// Levels are sorted locally, as in `calculate_buy_market_price`. If the book can't cover
+/// the amount, FOK fails and other order types get the lowest bid.
 pub fn calculate_sell_market_price(
     positions: &[OrderSummary],
     amount_to_match: f64,
     order_type: OrderType,
 ) -> ClobResult<f64> {
-    if positions.is_empty() {
+    let levels = levels_best_first(positions, Side::Sell)?;
+    let Some(&(worst_price, _)) = levels.last() else {
         return Err(ClobError::NoMatch);
-    }
+    };
 
     let mut sum = 0.0;
-
-    for i in (0..positions.len()).rev() {
-        let p = &positions[i];
-        let price: f64 = p
-            .price
-            .parse()
-            .map_err(|_| ClobError::Other("Invalid price in orderbook".to_string()))?;
-        let size: f64 = p
-            .size
-            .parse()
-            .map_err(|_| ClobError::Other("Invalid size in orderbook".to_string()))?;
-
+    for &(price, size) in &levels {
         sum += size;
         if sum >= amount_to_match {
             return Ok(price);
@@ -199,18 +206,250 @@ pub fn calculate_sell_market_price(
     }
 
     if order_type == OrderType::Fok {
+        return Err(ClobError::InsufficientLiquidity {
+            requested: amount_to_match,
+            available: sum,
+        });
+    }
+
+    Ok(worst_price)
+}
+
+/// Walks the opposite side of the book for a market order of `amount` (USDC for Buy, shares
+/// for Sell) and reports what would fill, including a partial fill
+///
+/// `positions` are the asks for Buy and the bids for Sell, in any order. FOK fails with
+/// `InsufficientLiquidity` if the book can't cover the amount; other order types get the
+/// partial estimate.
+pub fn calculate_market_order_estimate(
+    positions: &[OrderSummary],
+    side: Side,
+    amount: f64,
+    order_type: OrderType,
+) -> ClobResult<MarketOrderEstimate> {
+    let levels = levels_best_first(positions, side)?;
+    if levels.is_empty() {
         return Err(ClobError::NoMatch);
     }
 
-    let first_price: f64 = positions[0]
+    let mut remaining = amount;
+    let mut shares = 0.0;
+    let mut notional = 0.0;
+    let mut worst_price = levels[0].0;
+    for (price, size) in levels {
+        if remaining <= 0.0 {
+            break;
+        }
+        // What this level holds in the order's unit
+        let capacity = match side {
+            Side::Buy => size * price,
+            Side::Sell => size,
+        };
+        let taken = remaining.min(capacity);
+        remaining -= taken;
+        worst_price = price;
+        match side {
+            Side::Buy => {
+                notional += taken;
+                shares += taken / price;
+            }
+            Side::Sell => {
+                shares += taken;
+                notional += taken * price;
+            }
+        }
+    }
+
+    let fillable_amount = amount - remaining.max(0.0);
+    let fully_filled = remaining <= 0.0;
+    if !fully_filled && order_type == OrderType::Fok {
+        return Err(ClobError::InsufficientLiquidity {
+            requested: amount,
+            available: fillable_amount,
+        });
+    }
+
+    Ok(MarketOrderEstimate {
+        avg_price: if shares > 0.0 { notional / shares } else { 0.0 },
+        worst_price,
+        fillable_amount,
+        fully_filled,
+    })
+}
+
+/// Rejects a computed market price above `max_price` or below `min_price`
+pub(crate) fn check_price_bounds(
+    computed: f64,
+    max_price: Option<f64>,
+    min_price: Option<f64>,
+) -> ClobResult<()> {
+    if let Some(limit) = max_price.filter(|&limit| computed > limit) {
+        return Err(ClobError::SlippageExceeded { limit, computed });
+    }
+    if let Some(limit) = min_price.filter(|&limit| computed < limit) {
+        return Err(ClobError::SlippageExceeded { limit, computed });
+    }
+    Ok(())
+}
+
+/// Parses the levels a `side` order takes from and sorts them best first: asks ascending
+/// for Buy, bids descending for Sell
+fn levels_best_first(positions: &[OrderSummary], side: Side) -> ClobResult<Vec<(f64, f64)>> {
+    let mut levels = positions
+        .iter()
+        .map(parse_level)
+        .collect::<ClobResult<Vec<_>>>()?;
+    match side {
+        Side::Buy => levels.sort_by(|a, b| a.0.total_cmp(&b.0)),
+        Side::Sell => levels.sort_by(|a, b| b.0.total_cmp(&a.0)),
+    }
+    Ok(levels)
+}
+
+/// Parses an orderbook level into `(price, size)`
+pub(crate) fn parse_level(level: &OrderSummary) -> ClobResult<(f64, f64)> {
+    let price: f64 = level
         .price
         .parse()
         .map_err(|_| ClobError::Other("Invalid price in orderbook".to_string()))?;
-    Ok(first_price)
+    let size: f64 = level
+        .size
+        .parse()
+        .map_err(|_| ClobError::Other("Invalid size in orderbook".to_string()))?;
+    Ok((price, size))
+}
+
+/// Checks whether a limit order would rest passively or take liquidity from the book.
+///
+/// Does not rely on the level ordering of the book: the best bid is the highest bid
+/// price and the best ask the lowest ask price.
+pub fn check_order_against_book(
+    user_limit_order: &UserLimitOrder,
+    orderbook: &OrderBookSummary,
+) -> ClobResult<OrderBookCheck> {
+    let bids = orderbook
+        .bids
+        .iter()
+        .map(parse_level)
+        .collect::<ClobResult<Vec<_>>>()?;
+    let asks = orderbook
+        .asks
+        .iter()
+        .map(parse_level)
+        .collect::<ClobResult<Vec<_>>>()?;
+
+    let best_bid = bids.iter().map(|(price, _)| *price).reduce(f64::max);
+    let best_ask = asks.iter().map(|(price, _)| *price).reduce(f64::min);
+
+    let price = user_limit_order.price;
+    let (would_take, available_depth) = match user_limit_order.side {
+        Side::Buy => (
+            best_ask.is_some_and(|ask| price >= ask),
+            asks.iter()
+                .filter(|(ask, _)| *ask <= price)
+                .map(|(_, size)| size)
+                .sum(),
+        ),
+        Side::Sell => (
+            best_bid.is_some_and(|bid| price <= bid),
+            bids.iter()
+                .filter(|(bid, _)| *bid >= price)
+                .map(|(_, size)| size)
+                .sum(),
+        ),
+    };
+
+    Ok(OrderBookCheck {
+        would_take,
+        best_bid,
+        best_ask,
+        available_depth,
+    })
+}
+
+/// Sums the resting size on `side`'s own side of the book from the best level down to
+/// `price` (inclusive): bids at or above `price` for Buy, asks at or below it for Sell.
+///
+/// Every level is checked, so the result doesn't depend on level order or duplicates.
+pub fn calculate_liquidity_to_price(
+    orderbook: &OrderBookSummary,
+    side: Side,
+    price: f64,
+) -> ClobResult<f64> {
+    let levels = match side {
+        Side::Buy => &orderbook.bids,
+        Side::Sell => &orderbook.asks,
+    };
+    let levels = levels
+        .iter()
+        .map(parse_level)
+        .collect::<ClobResult<Vec<_>>>()?;
+
+    Ok(levels
+        .iter()
+        .filter(|(level_price, _)| match side {
+            Side::Buy => *level_price >= price,
+            Side::Sell => *level_price <= price,
+        })
+        .map(|(_, size)| size)
+        .sum())
+}
+
+/// Computes the limit price needed to fill `size` shares immediately against the opposite
+/// side of the book, allowing at most `max_slippage_bps` of slippage from the best level.
+///
+/// Walks asks upward (Buy) or bids downward (Sell) from the best price and returns the
+/// price of the level at which the cumulative size covers `size`. Returns
+/// `InsufficientLiquidity` if the levels within the cap cannot fill the order.
+pub fn calculate_marketable_limit_price(
+    orderbook: &OrderBookSummary,
+    side: Side,
+    size: f64,
+    max_slippage_bps: u32,
+) -> ClobResult<f64> {
+    let levels = match side {
+        Side::Buy => &orderbook.asks,
+        Side::Sell => &orderbook.bids,
+    };
+    // Best level first, regardless of the order the server returned
+    let levels = levels_best_first(levels, side)?;
+
+    let Some(&(best_price, _)) = levels.first() else {
+        return Err(ClobError::NoMatch);
+    };
+
+    let slippage = max_slippage_bps as f64 / 10_000.0;
+    let cap = match side {
+        Side::Buy => best_price * (1.0 + slippage),
+        Side::Sell => best_price * (1.0 - slippage),
+    };
+
+    let mut available = 0.0;
+    for (price, level_size) in levels {
+        let within_cap = match side {
+            Side::Buy => price <= cap + TICK_EPSILON,
+            Side::Sell => price >= cap - TICK_EPSILON,
+        };
+        if !within_cap {
+            break;
+        }
+        available += level_size;
+        if available >= size {
+            return Ok(price);
+        }
+    }
+
+    Err(ClobError::InsufficientLiquidity {
+        requested: size,
+        available,
+    })
 }
 
+/// Builds an order from `order_data` and signs its EIP-712 hash with `signer`
+///
+/// `order_data.signer` must be `signer`'s address.
 pub async fn build_order(
-    signer: PrivateKeySigner,
+    signer: &dyn ClobSigner,
     exchange_address: &str,
     chain_id: u64,
     order_data: OrderData,
@@ -218,40 +457,227 @@ pub async fn build_order(
     let exchange_addr = Address::from_str(exchange_address)
         .map_err(|e| ClobError::Other(format!("Invalid exchange address: {}", e)))?;
 
-    let builder = ExchangeOrderBuilder::new(exchange_addr, chain_id, signer, None);
+    let mut order = unsigned_order(&order_data);
+    let digest = order_hash(&order, chain_id, exchange_addr)?;
+    let signature = signer.sign_hash(&digest).await?;
+    order["signature"] = serde_json::Value::String(signature_to_hex(&signature));
+
+    Ok(serde_json::from_value(order)?)
+}
+
+/// Builds a limit order and returns its unsigned EIP-712 typed data
+///
+/// For wallets this crate cannot sign with, such as a browser wallet: pass the result to
+/// `eth_signTypedData_v4` from `signer`'s account, then turn the signature into a
+/// `SignedOrder` with `signed_order_from_typed_data`. `maker` is the funder address for
+/// proxy and Safe wallets, otherwise `signer` itself; `signature_type` is numbered as in
+/// `ClobClientBuilder::signature_type`.
+pub async fn build_order_typed_data(
+    user_order: &UserLimitOrder,
+    options: &CreateOrderOptions,
+    maker: Address,
+    signer: Address,
+    chain_id: Chain,
+    signature_type: u8,
+) -> ClobResult<Value> {
+    validate_price(user_order.price, options.tick_size)?;
+
+    let round_config = get_rounding_config(options.tick_size);
+    let order_data = build_limit_order_creation_args(
+        signer,
+        maker,
+        signature_type_from_u8(signature_type),
+        user_order,
+        &round_config,
+    )?;
+
+    let exchange = Address::from_str(exchange_address(chain_id, options.neg_risk)?)
+        .map_err(|e| ClobError::Other(format!("Invalid exchange address: {}", e)))?;
+    order_typed_data(&unsigned_order(&order_data), chain_id.chain_id(), exchange)
+}
+
+/// Order of `order_data` in the wire form of `SignedOrder` (decimal string amounts, numeric
+/// side), with a fresh salt and an empty signature
+fn unsigned_order(order_data: &OrderData) -> Value {
+    let side = match order_data.side {
+        rs_order_utils::Side::Buy => "0",
+        rs_order_utils::Side::Sell => "1",
+    };
+    let signature_type: u8 = match order_data.signature_type.unwrap_or(SignatureType::Eoa) {
+        SignatureType::Eoa => 0,
+        SignatureType::PolyProxy => 1,
+        SignatureType::PolyGnosisSafe => 2,
+    };
+
+    serde_json::json!({
+        "salt": rand::thread_rng().gen_range(0..MAX_ORDER_SALT),
+        "maker": order_data.maker.to_checksum(None),
+        "signer": order_data.signer.unwrap_or(order_data.maker).to_checksum(None),
+        "taker": order_data.taker.to_checksum(None),
+        "tokenId": order_data.token_id.to_string(),
+        "makerAmount": order_data.maker_amount.to_string(),
+        "takerAmount": order_data.taker_amount.to_string(),
+        "expiration": order_data.expiration.unwrap_or_default().to_string(),
+        "nonce": order_data.nonce.to_string(),
+        "feeRateBps": order_data.fee_rate_bps.to_string(),
+        "side": side,
+        "signatureType": signature_type,
+        "signature": "",
+    })
+}
 
-    builder
-        .build_signed_order(order_data)
-        .await
-        .map_err(|e| ClobError::SigningError(e.to_string()))
+/// Assembles the order of `build_order_typed_data` typed data with a wallet's signature
+///
+/// `signature` is the 65-byte r, s, v signature `eth_signTypedData_v4` returns (hex-decode
+/// it first). Returns `SigningError` if it was not made by the order's `signer` over this
+/// typed data.
+pub fn signed_order_from_typed_data(
+    typed_data: &Value,
+    signature: &[u8],
+) -> ClobResult<SignedOrder> {
+    let invalid = |what: &str| ClobError::Other(format!("Invalid order typed data: {}", what));
+    let message = typed_data
+        .get("message")
+        .ok_or_else(|| invalid("missing `message`"))?;
+    let domain = typed_data
+        .get("domain")
+        .ok_or_else(|| invalid("missing `domain`"))?;
+    let chain_id = domain
+        .get("chainId")
+        .and_then(|chain_id| chain_id.as_u64())
+        .ok_or_else(|| invalid("missing `domain.chainId`"))?;
+    let exchange = domain
+        .get("verifyingContract")
+        .and_then(|exchange| exchange.as_str())
+        .and_then(|exchange| Address::from_str(exchange).ok())
+        .ok_or_else(|| invalid("missing `domain.verifyingContract`"))?;
+
+    let signature = Signature::try_from(signature)
+        .map_err(|e| ClobError::SigningError(format!("Invalid order signature: {}", e)))?;
+    let digest = order_hash(message, chain_id, exchange)?;
+    let recovered = signature
+        .recover_address_from_prehash(&digest)
+        .map_err(|e| ClobError::SigningError(format!("Failed to recover order signer: {}", e)))?;
+    let signer = message
+        .get("signer")
+        .and_then(|signer| signer.as_str())
+        .and_then(|signer| Address::from_str(signer).ok())
+        .ok_or_else(|| invalid("missing `message.signer`"))?;
+    if recovered != signer {
+        return Err(ClobError::SigningError(format!(
+            "Order signed by {}, expected its signer {}",
+            recovered, signer
+        )));
+    }
+
+    // Back to the wire form `build_order` produces: numeric side and string amounts
+    let mut order = message.clone();
+    order["side"] = Value::String(message["side"].to_string());
+    order["signature"] = Value::String(signature_to_hex(&signature));
+    Ok(serde_json::from_value(order)?)
+}
+
+/// Signature type for its number (0 = EOA, 1 = Poly Proxy, 2 = EIP-1271), EOA if unknown
+pub(crate) fn signature_type_from_u8(signature_type: u8) -> SignatureType {
+    match signature_type {
+        1 => SignatureType::PolyProxy,
+        2 => SignatureType::PolyGnosisSafe,
+        _ => SignatureType::Eoa,
+    }
 }
 
+/// Exchange contract for the chain: the neg-risk exchange for neg-risk markets
+pub(crate) fn exchange_address(
+    chain_id: Chain,
+    neg_risk: Option<bool>,
+) -> ClobResult<&'static str> {
+    let contract_config = get_contract_config(chain_id.chain_id())?;
+    Ok(if neg_risk.unwrap_or(false) {
+        contract_config.neg_risk_exchange
+    } else {
+        contract_config.exchange
+    })
+}
+
+/// Recovers the address that signed an order from its signature over the order's EIP-712 hash
+///
+/// Compare the result with the order's `signer` to catch an order signed by the wrong key
+/// (e.g. through a misconfigured `get_signer`) before posting it.
+pub fn recover_signer(
+    signed_order: &SignedOrder,
+    chain: Chain,
+    neg_risk: bool,
+) -> ClobResult<Address> {
+    let order = serde_json::to_value(signed_order)?;
+    let exchange = Address::from_str(exchange_address(chain, Some(neg_risk))?)
+        .map_err(|e| ClobError::Other(format!("Invalid exchange address: {}", e)))?;
+    let digest = order_hash(&order, chain.chain_id(), exchange)?;
+
+    let signature = order
+        .get("signature")
+        .and_then(|signature| signature.as_str())
+        .ok_or_else(|| ClobError::SigningError("Order has no signature".to_string()))?;
+    let bytes = hex::decode(signature.trim_start_matches("0x"))
+        .map_err(|e| ClobError::SigningError(format!("Invalid order signature: {}", e)))?;
+    let signature = Signature::try_from(bytes.as_slice())
+        .map_err(|e| ClobError::SigningError(format!("Invalid order signature: {}", e)))?;
+
+    signature
+        .recover_address_from_prehash(&digest)
+        .map_err(|e| ClobError::SigningError(format!("Failed to recover order signer: {}", e)))
+}
+
+pub(crate) fn parse_token_id(token_id: &str) -> ClobResult<U256> {
+    U256::from_str(token_id).map_err(|e| ClobError::Other(format!("Invalid token_id: {}", e)))
+}
+
+pub(crate) fn exchange_side(side: Side) -> rs_order_utils::Side {
+    match side {
+        Side::Buy => rs_order_utils::Side::Buy,
+        Side::Sell => rs_order_utils::Side::Sell,
+    }
+}
+
+/// Expiration signed for a limit order, padded for the matching engine's safety window
+/// (`SECONDS_DELAY` unless `expiration_buffer` is set); zero means no expiration
+pub(crate) fn limit_order_expiration(
+    expiration: Option<u64>,
+    expiration_buffer: Option<u64>,
+) -> Option<U256> {
+    let expiration_buffer = expiration_buffer.unwrap_or(SECONDS_DELAY);
+    expiration
+        .map(|expiration| match expiration {
+            0 => 0,
+            expiration => expiration.saturating_add(expiration_buffer),
+        })
+        .map(U256::from)
+}
+
+/// Converts a decimal amount to its integer on-chain representation with `decimals` places
+///
+/// Formats the value with exactly `decimals` fractional digits and reads the digits as an
+/// integer, so amounts like `5.55555` are not skewed by a float multiplication. Negative and
+/// non-finite values map to zero.
 fn parse_units(value: f64, decimals: u8) -> U256 {
-    let multiplier = 10_f64.powi(decimals as i32);
-    let raw_value = (value * multiplier).round() as u128;
-    U256::from(raw_value)
+    let formatted = format!("{:.*}", decimals as usize, value);
+    let digits: String = formatted.chars().filter(|c| *c != '.').collect();
+    U256::from_str_radix(&digits, 10).unwrap_or(U256::ZERO)
 }
 
 /// Parse units for market order maker amount (max 2 decimals precision)
 /// Result must be a multiple of 10000 (since USDC has 6 decimals, 2 decimal precision = 10^(6-2) = 10000)
 fn parse_market_maker_units(value: f64, decimals: u8) -> U256 {
-    let multiplier = 10_f64.powi(decimals as i32);
-    let raw_value = (value * multiplier).round() as u128;
     // Align to 10000 (for 2 decimal precision with 6 decimal token)
-    let alignment = 10_u128.pow((decimals - 2) as u32); // 10^4 = 10000
-    let aligned_value = (raw_value / alignment) * alignment;
-    U256::from(aligned_value)
+    let alignment = U256::from(10_u64.pow((decimals - 2) as u32)); // 10^4 = 10000
+    (parse_units(value, decimals) / alignment) * alignment
 }
 
 /// Parse units for market order taker amount (max 5 decimals precision)
 /// Result must be a multiple of 10 (since USDC has 6 decimals, 5 decimal precision = 10^(6-5) = 10)
 fn parse_market_taker_units(value: f64, decimals: u8) -> U256 {
-    let multiplier = 10_f64.powi(decimals as i32);
-    let raw_value = (value * multiplier).round() as u128;
     // Align to 10 (for 5 decimal precision with 6 decimal token)
-    let alignment = 10_u128.pow((decimals - 5) as u32); // 10^1 = 10
-    let aligned_value = (raw_value / alignment) * alignment;
-    U256::from(aligned_value)
+    let alignment = U256::from(10_u64.pow((decimals - 5) as u32)); // 10^1 = 10
+    (parse_units(value, decimals) / alignment) * alignment
 }
 
 pub fn build_limit_order_creation_args(
@@ -266,24 +692,23 @@ pub fn build_limit_order_creation_args(
         user_limit_order.size,
         user_limit_order.price,
         round_config,
-    );
+    )?;
 
     let maker_amount = parse_units(raw_amounts.raw_maker_amt, COLLATERAL_TOKEN_DECIMALS);
     let taker_amount = parse_units(raw_amounts.raw_taker_amt, COLLATERAL_TOKEN_DECIMALS);
 
     let taker = user_limit_order.taker.unwrap_or(Address::ZERO);
 
+    validate_expiration(user_limit_order.order_type, user_limit_order.expiration)?;
+
     let fee_rate_bps = U256::from(user_limit_order.fee_rate_bps.unwrap_or(0));
     let nonce = U256::from(user_limit_order.nonce.unwrap_or(0));
-    let expiration = user_limit_order.expiration.map(U256::from);
+    let expiration = limit_order_expiration(
+        user_limit_order.expiration,
+        user_limit_order.expiration_buffer,
+    );
 
-    let token_id = U256::from_str(&user_limit_order.token_id)
-        .map_err(|e| ClobError::Other(format!("Invalid token_id: {}", e)))?;
-
-    let side = match raw_amounts.side {
-        Side::Buy => rs_order_utils::Side::Buy,
-        Side::Sell => rs_order_utils::Side::Sell,
-    };
+    let token_id = parse_token_id(&user_limit_order.token_id)?;
 
     Ok(OrderData {
         maker,
@@ -291,7 +716,7 @@ pub fn build_limit_order_creation_args(
         token_id,
         maker_amount,
         taker_amount,
-        side,
+        side: exchange_side(raw_amounts.side),
         fee_rate_bps,
         nonce,
         signer: Some(signer_address),
@@ -301,17 +726,17 @@ pub fn build_limit_order_creation_args(
 }
 
 pub async fn create_limit_order(
-    wallet: PrivateKeySigner,
+    signer: &dyn ClobSigner,
     chain_id: Chain,
     signature_type: SignatureType,
     funder_address: Option<Address>,
     user_limit_order: &UserLimitOrder,
     options: &CreateOrderOptions,
 ) -> ClobResult<SignedOrder> {
-    let signer_address = wallet.address();
+    let signer_address = signer.address();
     let maker = funder_address.unwrap_or(signer_address);
-    let contract_config =
-        get_contract_config(chain_id.chain_id()).map_err(|e| ClobError::Other(e))?;
+
+    validate_price(user_limit_order.price, options.tick_size)?;
 
     let round_config = get_rounding_config(options.tick_size);
 
@@ -323,13 +748,51 @@ pub async fn create_limit_order(
         &round_config,
     )?;
 
-    let exchange_contract = if options.neg_risk.unwrap_or(false) {
-        contract_config.neg_risk_exchange
-    } else {
-        contract_config.exchange
-    };
+    let exchange_contract = exchange_address(chain_id, options.neg_risk)?;
+    build_order(signer, exchange_contract, chain_id.chain_id(), order_data).await
+}
+
+/// Rejects prices outside `[tick, 1 - tick]`, the range the exchange accepts
+pub fn validate_price(price: f64, tick_size: TickSize) -> ClobResult<()> {
+    if !price_valid(price, tick_size) {
+        let tick = tick_size.as_f64();
+        return Err(ClobError::InvalidPrice {
+            price,
+            min: tick,
+            max: 1.0 - tick,
+        });
+    }
+    Ok(())
+}
 
-    build_order(wallet, exchange_contract, chain_id.chain_id(), order_data).await
+/// Rejects orders below the market's `min_order_size`, both in shares
+pub fn validate_order_size(size: f64, min_size: f64) -> ClobResult<()> {
+    if size < min_size {
+        return Err(ClobError::OrderSizeTooSmall { size, min_size });
+    }
+    Ok(())
+}
+
+/// Validates that an expiration is present exactly when the order is GTD.
+///
+/// Non-GTD orders must leave the expiration unset (or zero), since the exchange
+/// only honours it for GTD orders.
+pub fn validate_expiration(
+    order_type: Option<OrderType>,
+    expiration: Option<u64>,
+) -> ClobResult<()> {
+    let has_expiration = expiration.is_some_and(|e| e > 0);
+
+    match order_type {
+        Some(OrderType::Gtd) if !has_expiration => Err(ClobError::MissingExpiration),
+        Some(order_type) if order_type != OrderType::Gtd && has_expiration => {
+            Err(ClobError::Other(format!(
+                "Expiration is only supported for GTD orders, got {:?}",
+                order_type
+            )))
+        }
+        _ => Ok(()),
+    }
 }
 
 pub fn build_market_order_creation_args(
@@ -339,7 +802,10 @@ pub fn build_market_order_creation_args(
     user_market_order: &UserMarketOrder,
     round_config: &RoundConfig,
 ) -> ClobResult<OrderData> {
-    let price = user_market_order.price.unwrap_or(1.0);
+    // The client resolves the price from the book; never sign at a placeholder price
+    let price = user_market_order
+        .price
+        .ok_or(ClobError::MissingMarketPrice)?;
 
     let raw_amounts = get_market_order_raw_amounts(
         user_market_order.side,
@@ -349,21 +815,20 @@ pub fn build_market_order_creation_args(
     );
 
     // Use market-specific parsing functions that enforce API precision requirements
-    let maker_amount = parse_market_maker_units(raw_amounts.raw_maker_amt, COLLATERAL_TOKEN_DECIMALS);
-    let taker_amount = parse_market_taker_units(raw_amounts.raw_taker_amt, COLLATERAL_TOKEN_DECIMALS);
+    let maker_amount =
+        parse_market_maker_units(raw_amounts.raw_maker_amt, COLLATERAL_TOKEN_DECIMALS);
+    let taker_amount =
+        parse_market_taker_units(raw_amounts.raw_taker_amt, COLLATERAL_TOKEN_DECIMALS);
 
     let taker = user_market_order.taker.unwrap_or(Address::ZERO);
 
+    validate_expiration(user_market_order.order_type, user_market_order.expiration)?;
+
     let fee_rate_bps = U256::from(user_market_order.fee_rate_bps.unwrap_or(0));
     let nonce = U256::from(user_market_order.nonce.unwrap_or(0));
+    let expiration = U256::from(user_market_order.expiration.unwrap_or(0));
 
-    let token_id = U256::from_str(&user_market_order.token_id)
-        .map_err(|e| ClobError::Other(format!("Invalid token_id: {}", e)))?;
-
-    let side = match raw_amounts.side {
-        Side::Buy => rs_order_utils::Side::Buy,
-        Side::Sell => rs_order_utils::Side::Sell,
-    };
+    let token_id = parse_token_id(&user_market_order.token_id)?;
 
     Ok(OrderData {
         maker,
@@ -371,27 +836,29 @@ pub fn build_market_order_creation_args(
         token_id,
         maker_amount,
         taker_amount,
-        side,
+        side: exchange_side(raw_amounts.side),
         fee_rate_bps,
         nonce,
         signer: Some(signer_address),
-        expiration: Some(U256::ZERO),
+        expiration: Some(expiration),
         signature_type: Some(signature_type),
     })
 }
 
 pub async fn create_market_order(
-    wallet: PrivateKeySigner,
+    signer: &dyn ClobSigner,
     chain_id: Chain,
     signature_type: SignatureType,
     funder_address: Option<Address>,
     user_market_order: &UserMarketOrder,
     options: &CreateOrderOptions,
 ) -> ClobResult<SignedOrder> {
-    let signer_address = wallet.address();
+    let signer_address = signer.address();
     let maker = funder_address.unwrap_or(signer_address);
-    let contract_config =
-        get_contract_config(chain_id.chain_id()).map_err(|e| ClobError::Other(e))?;
+
+    if let Some(price) = user_market_order.price {
+        validate_price(price, options.tick_size)?;
+    }
 
     let round_config = get_rounding_config(options.tick_size);
 
@@ -403,18 +870,14 @@ pub async fn create_market_order(
         &round_config,
     )?;
 
-    let exchange_contract = if options.neg_risk.unwrap_or(false) {
-        contract_config.neg_risk_exchange
-    } else {
-        contract_config.exchange
-    };
-
-    build_order(wallet, exchange_contract, chain_id.chain_id(), order_data).await
+    let exchange_contract = exchange_address(chain_id, options.neg_risk)?;
+    build_order(signer, exchange_contract, chain_id.chain_id(), order_data).await
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_signer_local::PrivateKeySigner;
 
     #[test]
     fn test_get_rounding_config() {
@@ -431,7 +894,7 @@ mod tests {
             size: 2,
             amount: 4,
         };
-        let result = get_order_raw_amounts(Side::Buy, 100.0, 0.55, &round_config);
+        let result = get_order_raw_amounts(Side::Buy, 100.0, 0.55, &round_config).unwrap();
         assert_eq!(result.side, Side::Buy);
         assert_eq!(result.raw_taker_amt, 100.0);
         assert_eq!(result.raw_maker_amt, 55.0);
@@ -444,12 +907,86 @@ mod tests {
             size: 2,
             amount: 4,
         };
-        let result = get_order_raw_amounts(Side::Sell, 100.0, 0.55, &round_config);
+        let result = get_order_raw_amounts(Side::Sell, 100.0, 0.55, &round_config).unwrap();
         assert_eq!(result.side, Side::Sell);
         assert_eq!(result.raw_maker_amt, 100.0);
         assert_eq!(result.raw_taker_amt, 55.0);
     }
 
+    #[test]
+    fn test_get_order_raw_amounts_rejects_off_tick_price() {
+        let round_config = get_rounding_config(TickSize::ZeroPointZeroOne);
+
+        let result = get_order_raw_amounts(Side::Buy, 100.0, 0.523, &round_config);
+        assert!(matches!(
+            result,
+            Err(ClobError::PriceNotOnTick { price, tick_size }) if price == 0.523 && tick_size == 0.01
+        ));
+
+        let result = get_order_raw_amounts(Side::Buy, 100.0, 0.52, &round_config).unwrap();
+        assert_eq!(result.raw_maker_amt, 52.0);
+    }
+
+    #[test]
+    fn test_get_order_raw_amounts_tolerates_float_noise() {
+        let round_config = get_rounding_config(TickSize::ZeroPointOne);
+        let price = 0.1 + 0.2; // 0.30000000000000004
+
+        let result = get_order_raw_amounts(Side::Sell, 10.0, price, &round_config).unwrap();
+        assert_eq!(result.raw_taker_amt, 3.0);
+    }
+
+    #[test]
+    fn test_validate_price_bounds() {
+        let cases = [
+            (TickSize::ZeroPointOne, 0.1, 0.9),
+            (TickSize::ZeroPointZeroOne, 0.01, 0.99),
+            (TickSize::ZeroPointZeroZeroOne, 0.001, 0.999),
+            (TickSize::ZeroPointZeroZeroZeroOne, 0.0001, 0.9999),
+        ];
+
+        for (tick_size, min, max) in cases {
+            assert!(validate_price(min, tick_size).is_ok());
+            assert!(validate_price(max, tick_size).is_ok());
+            assert!(matches!(
+                validate_price(0.0, tick_size),
+                Err(ClobError::InvalidPrice { price, min: m, .. }) if price == 0.0 && m == min
+            ));
+            assert!(validate_price(1.0, tick_size).is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_order_size() {
+        assert!(validate_order_size(5.0, 5.0).is_ok());
+        assert!(validate_order_size(5.01, 5.0).is_ok());
+        assert!(matches!(
+            validate_order_size(4.99, 5.0),
+            Err(ClobError::OrderSizeTooSmall { size, min_size }) if size == 4.99 && min_size == 5.0
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_limit_order_rejects_out_of_range_price() {
+        let order = limit_order(Side::Buy, 0.0);
+        let options = CreateOrderOptions {
+            tick_size: TickSize::ZeroPointZeroOne,
+            neg_risk: Some(false),
+        };
+
+        let result = create_limit_order(
+            &PrivateKeySigner::random(),
+            Chain::Amoy,
+            SignatureType::Eoa,
+            None,
+            &order,
+            &options,
+        )
+        .await;
+
+        assert!(matches!(result, Err(ClobError::InvalidPrice { .. })));
+    }
+
     #[test]
     fn test_calculate_buy_market_price() {
         let positions = vec![
@@ -492,6 +1029,99 @@ mod tests {
         assert_eq!(price, 0.4);
     }
 
+    #[test]
+    fn test_market_price_ignores_level_order() {
+        let level = |price: &str| OrderSummary {
+            price: price.to_string(),
+            size: "100".to_string(),
+        };
+        let levels = [level("0.5"), level("0.55"), level("0.6")];
+        let orders = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+
+        for order in orders {
+            let shuffled: Vec<_> = order.iter().map(|&i| levels[i].clone()).collect();
+            // Asks: 100 * 0.5 = 50 USDC at the best level, 105 through the second
+            assert_eq!(
+                calculate_buy_market_price(&shuffled, 80.0, OrderType::Fok).unwrap(),
+                0.55
+            );
+            assert_eq!(
+                calculate_buy_market_price(&shuffled, 1000.0, OrderType::Fak).unwrap(),
+                0.6
+            );
+            // Bids: 100 shares at the best level, 200 through the second
+            assert_eq!(
+                calculate_sell_market_price(&shuffled, 150.0, OrderType::Fok).unwrap(),
+                0.55
+            );
+            assert_eq!(
+                calculate_sell_market_price(&shuffled, 1000.0, OrderType::Fak).unwrap(),
+                0.5
+            );
+        }
+    }
+
+    fn estimate_levels() -> Vec<OrderSummary> {
+        ["0.5", "0.55", "0.6"]
+            .into_iter()
+            .map(|price| OrderSummary {
+                price: price.to_string(),
+                size: "100".to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_market_order_estimate_full_fill() {
+        // 50 USDC at 0.5, then 30 of the 55 USDC resting at 0.55
+        let estimate =
+            calculate_market_order_estimate(&estimate_levels(), Side::Buy, 80.0, OrderType::Fok)
+                .unwrap();
+        assert!(estimate.fully_filled);
+        assert_eq!(estimate.fillable_amount, 80.0);
+        assert_eq!(estimate.worst_price, 0.55);
+        let shares = 100.0 + 30.0 / 0.55;
+        assert!((estimate.avg_price - 80.0 / shares).abs() < 1e-9);
+
+        // Bids from the highest: 100 @ 0.6, then 50 @ 0.55
+        let estimate =
+            calculate_market_order_estimate(&estimate_levels(), Side::Sell, 150.0, OrderType::Fok)
+                .unwrap();
+        assert!(estimate.fully_filled);
+        assert_eq!(estimate.worst_price, 0.55);
+        assert!((estimate.avg_price - (60.0 + 27.5) / 150.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_market_order_estimate_partial_fak() {
+        let estimate =
+            calculate_market_order_estimate(&estimate_levels(), Side::Sell, 500.0, OrderType::Fak)
+                .unwrap();
+        assert!(!estimate.fully_filled);
+        assert_eq!(estimate.fillable_amount, 300.0);
+        assert_eq!(estimate.worst_price, 0.5);
+        assert!((estimate.avg_price - 0.55).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_market_order_estimate_fok_insufficient_liquidity() {
+        // The asks hold 50 + 55 + 60 = 165 USDC
+        let result =
+            calculate_market_order_estimate(&estimate_levels(), Side::Buy, 200.0, OrderType::Fok);
+        assert!(matches!(
+            result,
+            Err(ClobError::InsufficientLiquidity { requested, available })
+                if requested == 200.0 && (available - 165.0).abs() < 1e-9
+        ));
+    }
+
     #[test]
     fn test_fok_fails_on_insufficient_liquidity() {
         let positions = vec![OrderSummary {
@@ -515,10 +1145,394 @@ mod tests {
         assert_eq!(result.unwrap(), 0.5);
     }
 
+    fn gtd_market_order(expiration: Option<u64>, order_type: Option<OrderType>) -> UserMarketOrder {
+        UserMarketOrder {
+            token_id: "1234".to_string(),
+            price: Some(0.5),
+            amount: 10.0,
+            side: Side::Buy,
+            fee_rate_bps: None,
+            nonce: Some(7),
+            taker: None,
+            expiration,
+            order_type,
+            max_price: None,
+            min_price: None,
+        }
+    }
+
+    #[test]
+    fn test_gtd_market_order_preserves_expiration() {
+        let order = gtd_market_order(Some(1_900_000_000), Some(OrderType::Gtd));
+        let round_config = get_rounding_config(TickSize::ZeroPointZeroOne);
+
+        let order_data = build_market_order_creation_args(
+            Address::ZERO,
+            Address::ZERO,
+            SignatureType::Eoa,
+            &order,
+            &round_config,
+        )
+        .unwrap();
+
+        assert_eq!(order_data.expiration, Some(U256::from(1_900_000_000u64)));
+        assert_eq!(order_data.nonce, U256::from(7));
+    }
+
+    #[test]
+    fn test_gtd_limit_order_expiration_buffer() {
+        let round_config = get_rounding_config(TickSize::ZeroPointZeroOne);
+        let build = |order: &UserLimitOrder| {
+            build_limit_order_creation_args(
+                Address::ZERO,
+                Address::ZERO,
+                SignatureType::Eoa,
+                order,
+                &round_config,
+            )
+            .unwrap()
+            .expiration
+        };
+
+        let mut order = limit_order(Side::Buy, 0.5);
+        assert_eq!(build(&order), None);
+
+        order.expiration = Some(1_900_000_000);
+        let padded = 1_900_000_000u64 + SECONDS_DELAY;
+        assert_eq!(build(&order), Some(U256::from(padded)));
+
+        order.expiration_buffer = Some(0);
+        assert_eq!(build(&order), Some(U256::from(1_900_000_000u64)));
+    }
+
+    #[test]
+    fn test_validate_expiration() {
+        assert!(validate_expiration(Some(OrderType::Gtd), Some(1_900_000_000)).is_ok());
+        assert!(matches!(
+            validate_expiration(Some(OrderType::Gtd), None),
+            Err(ClobError::MissingExpiration)
+        ));
+        assert!(matches!(
+            validate_expiration(Some(OrderType::Gtd), Some(0)),
+            Err(ClobError::MissingExpiration)
+        ));
+        assert!(validate_expiration(Some(OrderType::Fok), Some(1_900_000_000)).is_err());
+        assert!(validate_expiration(Some(OrderType::Fok), None).is_ok());
+        assert!(validate_expiration(None, None).is_ok());
+    }
+
+    fn sample_orderbook() -> OrderBookSummary {
+        OrderBookSummary {
+            market: "0xabc".to_string(),
+            asset_id: "1234".to_string(),
+            timestamp: "1700000000000".to_string(),
+            bids: vec![
+                OrderSummary {
+                    price: "0.45".to_string(),
+                    size: "100".to_string(),
+                },
+                OrderSummary {
+                    price: "0.48".to_string(),
+                    size: "50".to_string(),
+                },
+            ],
+            asks: vec![
+                OrderSummary {
+                    price: "0.55".to_string(),
+                    size: "80".to_string(),
+                },
+                OrderSummary {
+                    price: "0.52".to_string(),
+                    size: "40".to_string(),
+                },
+            ],
+            min_order_size: "5".to_string(),
+            tick_size: "0.01".to_string(),
+            neg_risk: false,
+            hash: String::new(),
+        }
+    }
+
+    fn limit_order(side: Side, price: f64) -> UserLimitOrder {
+        UserLimitOrder {
+            token_id: "1234".to_string(),
+            price,
+            size: 10.0,
+            side,
+            fee_rate_bps: None,
+            nonce: None,
+            expiration: None,
+            expiration_buffer: None,
+            taker: None,
+            order_type: None,
+        }
+    }
+
+    #[test]
+    fn test_public_order_has_zero_taker() {
+        let order = limit_order(Side::Buy, 0.5);
+        let round_config = get_rounding_config(TickSize::ZeroPointZeroOne);
+
+        let order_data = build_limit_order_creation_args(
+            Address::ZERO,
+            Address::ZERO,
+            SignatureType::Eoa,
+            &order,
+            &round_config,
+        )
+        .unwrap();
+
+        assert!(!order.is_private());
+        assert_eq!(order_data.taker, Address::ZERO);
+    }
+
+    #[test]
+    fn test_private_order_sets_taker() {
+        let counterparty = Address::repeat_byte(0x11);
+        let order = limit_order(Side::Buy, 0.5)
+            .private_to(counterparty)
+            .unwrap();
+        let round_config = get_rounding_config(TickSize::ZeroPointZeroOne);
+
+        let order_data = build_limit_order_creation_args(
+            Address::ZERO,
+            Address::ZERO,
+            SignatureType::Eoa,
+            &order,
+            &round_config,
+        )
+        .unwrap();
+
+        assert!(order.is_private());
+        assert_eq!(order_data.taker, counterparty);
+    }
+
+    #[test]
+    fn test_private_order_rejects_zero_taker() {
+        let result = limit_order(Side::Buy, 0.5).private_to(Address::ZERO);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_liquidity_to_price_bids() {
+        let orderbook = sample_orderbook();
+
+        for (price, expected) in [(0.48, 50.0), (0.46, 50.0), (0.45, 150.0), (0.50, 0.0)] {
+            let depth = calculate_liquidity_to_price(&orderbook, Side::Buy, price).unwrap();
+            assert_eq!(depth, expected, "bids at or above {}", price);
+        }
+    }
+
+    #[test]
+    fn test_liquidity_to_price_asks() {
+        let orderbook = sample_orderbook();
+
+        for (price, expected) in [(0.52, 40.0), (0.54, 40.0), (0.55, 120.0), (0.50, 0.0)] {
+            let depth = calculate_liquidity_to_price(&orderbook, Side::Sell, price).unwrap();
+            assert_eq!(depth, expected, "asks at or below {}", price);
+        }
+    }
+
+    #[test]
+    fn test_liquidity_to_price_unsorted_levels() {
+        let level = |price: &str, size: &str| OrderSummary {
+            price: price.to_string(),
+            size: size.to_string(),
+        };
+        let mut orderbook = sample_orderbook();
+        orderbook.bids = vec![
+            level("0.48", "50"),
+            level("0.40", "30"),
+            level("0.45", "100"),
+            level("0.48", "10"),
+        ];
+        orderbook.asks = vec![level("0.52", "40"), level("0.60", "5"), level("0.55", "80")];
+
+        let depth = calculate_liquidity_to_price(&orderbook, Side::Buy, 0.45).unwrap();
+        assert_eq!(depth, 160.0);
+        let depth = calculate_liquidity_to_price(&orderbook, Side::Sell, 0.55).unwrap();
+        assert_eq!(depth, 120.0);
+    }
+
+    #[test]
+    fn test_check_passive_orders() {
+        let book = sample_orderbook();
+
+        let buy = check_order_against_book(&limit_order(Side::Buy, 0.50), &book).unwrap();
+        assert!(!buy.would_take);
+        assert_eq!(buy.best_bid, Some(0.48));
+        assert_eq!(buy.best_ask, Some(0.52));
+        assert_eq!(buy.available_depth, 0.0);
+
+        let sell = check_order_against_book(&limit_order(Side::Sell, 0.50), &book).unwrap();
+        assert!(!sell.would_take);
+        assert_eq!(sell.available_depth, 0.0);
+    }
+
+    #[test]
+    fn test_check_aggressive_orders() {
+        let book = sample_orderbook();
+
+        let buy = check_order_against_book(&limit_order(Side::Buy, 0.55), &book).unwrap();
+        assert!(buy.would_take);
+        assert_eq!(buy.available_depth, 120.0);
+
+        let sell = check_order_against_book(&limit_order(Side::Sell, 0.48), &book).unwrap();
+        assert!(sell.would_take);
+        assert_eq!(sell.available_depth, 50.0);
+    }
+
+    #[test]
+    fn test_marketable_limit_within_cap() {
+        let book = sample_orderbook();
+
+        // 40 @ 0.52 covers a small buy at the best ask
+        let price = calculate_marketable_limit_price(&book, Side::Buy, 10.0, 0).unwrap();
+        assert_eq!(price, 0.52);
+
+        // 0.55 is ~577 bps above 0.52, so 100 shares need a 600 bps cap
+        let price = calculate_marketable_limit_price(&book, Side::Buy, 100.0, 600).unwrap();
+        assert_eq!(price, 0.55);
+
+        // 0.45 is 625 bps below 0.48
+        let price = calculate_marketable_limit_price(&book, Side::Sell, 120.0, 700).unwrap();
+        assert_eq!(price, 0.45);
+    }
+
+    #[test]
+    fn test_marketable_limit_over_cap() {
+        let book = sample_orderbook();
+
+        let result = calculate_marketable_limit_price(&book, Side::Buy, 100.0, 500);
+        assert!(matches!(
+            result,
+            Err(ClobError::InsufficientLiquidity { requested, available })
+                if requested == 100.0 && available == 40.0
+        ));
+
+        let result = calculate_marketable_limit_price(&book, Side::Sell, 200.0, 10_000);
+        assert!(matches!(
+            result,
+            Err(ClobError::InsufficientLiquidity { available, .. }) if available == 150.0
+        ));
+    }
+
+    #[test]
+    fn test_parse_units_exact_amounts() {
+        assert_eq!(parse_units(5.55555, 6), U256::from(5_555_550u64));
+        assert_eq!(parse_units(0.1, 6), U256::from(100_000u64));
+        assert_eq!(parse_units(0.3, 6), U256::from(300_000u64));
+        assert_eq!(parse_units(1234.56, 6), U256::from(1_234_560_000u64));
+        assert_eq!(parse_units(0.0, 6), U256::ZERO);
+        assert_eq!(parse_units(-1.0, 6), U256::ZERO);
+    }
+
+    #[test]
+    fn test_limit_order_amounts_for_fractional_size() {
+        // 5.55555 shares rounds to 5.55 at the 0.01 tick size's 2 size decimals
+        let mut order = limit_order(Side::Buy, 0.5);
+        order.size = 5.55555;
+        let round_config = get_rounding_config(TickSize::ZeroPointZeroOne);
+
+        let order_data = build_limit_order_creation_args(
+            Address::ZERO,
+            Address::ZERO,
+            SignatureType::Eoa,
+            &order,
+            &round_config,
+        )
+        .unwrap();
+
+        assert_eq!(order_data.taker_amount, U256::from(5_550_000u64));
+        assert_eq!(order_data.maker_amount, U256::from(2_775_000u64));
+    }
+
+    #[test]
+    fn test_fok_market_price_reports_insufficient_liquidity() {
+        let book = sample_orderbook();
+
+        // Asks hold 40 * 0.52 + 80 * 0.55 = 64.8 USDC
+        let result = calculate_buy_market_price(&book.asks, 100.0, OrderType::Fok);
+        assert!(matches!(
+            result,
+            Err(ClobError::InsufficientLiquidity { requested, .. }) if requested == 100.0
+        ));
+
+        let result = calculate_sell_market_price(&book.bids, 200.0, OrderType::Fok);
+        assert!(matches!(
+            result,
+            Err(ClobError::InsufficientLiquidity { available, .. }) if available == 150.0
+        ));
+
+        // FAK still falls back to the deepest level
+        let price = calculate_buy_market_price(&book.asks, 100.0, OrderType::Fak).unwrap();
+        assert_eq!(price, 0.55);
+    }
+
+    #[test]
+    fn test_market_order_without_price_is_rejected() {
+        let order = UserMarketOrder {
+            token_id: "1234".to_string(),
+            amount: 10.0,
+            side: Side::Buy,
+            price: None,
+            fee_rate_bps: None,
+            nonce: None,
+            taker: None,
+            expiration: None,
+            order_type: Some(OrderType::Fok),
+            max_price: None,
+            min_price: None,
+        };
+        let round_config = get_rounding_config(TickSize::ZeroPointZeroOne);
+
+        let result = build_market_order_creation_args(
+            Address::ZERO,
+            Address::ZERO,
+            SignatureType::Eoa,
+            &order,
+            &round_config,
+        );
+        assert!(matches!(result, Err(ClobError::MissingMarketPrice)));
+    }
+
     #[test]
     fn test_empty_orderbook() {
         let positions: Vec<OrderSummary> = vec![];
         let result = calculate_buy_market_price(&positions, 10.0, OrderType::Fok);
         assert!(matches!(result, Err(ClobError::NoMatch)));
     }
+
+    #[tokio::test]
+    async fn test_recover_signer_returns_signing_key() {
+        let wallet = PrivateKeySigner::random();
+        let address = wallet.address();
+
+        for neg_risk in [false, true] {
+            let options = CreateOrderOptions {
+                tick_size: TickSize::ZeroPointZeroOne,
+                neg_risk: Some(neg_risk),
+            };
+            let signed = create_limit_order(
+                &wallet,
+                Chain::Amoy,
+                SignatureType::Eoa,
+                None,
+                &limit_order(Side::Buy, 0.37),
+                &options,
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(
+                recover_signer(&signed, Chain::Amoy, neg_risk).unwrap(),
+                address
+            );
+            // Checked against the other exchange's domain, the signature matches no one
+            assert_ne!(
+                recover_signer(&signed, Chain::Amoy, !neg_risk).unwrap(),
+                address
+            );
+        }
+    }
 }
@@ -1,5 +1,13 @@
 mod builder;
+#[cfg(feature = "decimal")]
+mod decimal;
 mod helpers;
 
 pub use builder::OrderBuilder;
-pub use helpers::{calculate_buy_market_price, calculate_sell_market_price};
+pub use helpers::{
+    calculate_buy_market_price, calculate_liquidity_to_price, calculate_market_order_estimate,
+    calculate_marketable_limit_price, calculate_sell_market_price, check_order_against_book,
+    build_order_typed_data, recover_signer, signed_order_from_typed_data, validate_expiration,
+    validate_order_size,
+};
+pub(crate) use helpers::{check_price_bounds, exchange_address, signature_type_from_u8};
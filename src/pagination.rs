@@ -0,0 +1,143 @@
+use crate::constants::{END_CURSOR, INITIAL_CURSOR};
+use crate::errors::ClobResult;
+use crate::utilities::{BoxStream, MaybeSend};
+use futures::stream::{self, Stream, TryStreamExt};
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// One page of a cursor-paginated CLOB listing
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub data: Vec<T>,
+    pub next_cursor: String,
+}
+
+/// Stream over the items of a cursor-paginated listing, fetching pages lazily
+///
+/// Starts at `INITIAL_CURSOR` and stops after the page whose `next_cursor` is `END_CURSOR`
+/// (or empty). A failed page fetch is yielded as an error and ends the stream.
+pub struct Paginator<'a, T> {
+    inner: BoxStream<'a, ClobResult<T>>,
+}
+
+struct PaginatorState<F, T> {
+    fetch_page: F,
+    next_cursor: Option<String>,
+    buffer: VecDeque<T>,
+}
+
+impl<'a, T: MaybeSend + 'a> Paginator<'a, T> {
+    /// Creates a paginator from a closure fetching the page at a given cursor
+    pub fn new<F, Fut>(fetch_page: F) -> Self
+    where
+        F: FnMut(String) -> Fut + MaybeSend + 'a,
+        Fut: Future<Output = ClobResult<Page<T>>> + MaybeSend + 'a,
+    {
+        let state = PaginatorState {
+            fetch_page,
+            next_cursor: Some(INITIAL_CURSOR.to_string()),
+            buffer: VecDeque::new(),
+        };
+
+        let inner = stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(item) = state.buffer.pop_front() {
+                    return Some((Ok(item), state));
+                }
+
+                let cursor = state.next_cursor.take()?;
+                match (state.fetch_page)(cursor).await {
+                    Ok(page) => {
+                        if page.next_cursor != END_CURSOR && !page.next_cursor.is_empty() {
+                            state.next_cursor = Some(page.next_cursor);
+                        }
+                        state.buffer.extend(page.data);
+                    }
+                    Err(e) => return Some((Err(e), state)),
+                }
+            }
+        });
+
+        Self {
+            inner: Box::pin(inner),
+        }
+    }
+
+    /// Fetches every remaining page and collects the items
+    pub async fn collect_all(self) -> ClobResult<Vec<T>> {
+        self.try_collect().await
+    }
+}
+
+impl<T> Stream for Paginator<'_, T> {
+    type Item = ClobResult<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ClobError;
+    use futures::StreamExt;
+
+    fn page(data: Vec<u32>, next_cursor: &str) -> Page<u32> {
+        Page {
+            data,
+            next_cursor: next_cursor.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paginator_follows_cursor() {
+        let mut cursors = Vec::new();
+        let items = Paginator::new(|cursor: String| {
+            cursors.push(cursor.clone());
+            async move {
+                Ok(match cursor.as_str() {
+                    INITIAL_CURSOR => page(vec![1, 2], "MQ=="),
+                    "MQ==" => page(vec![], "Mg=="),
+                    _ => page(vec![3], END_CURSOR),
+                })
+            }
+        })
+        .collect_all()
+        .await
+        .unwrap();
+
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(cursors, vec![INITIAL_CURSOR, "MQ==", "Mg=="]);
+    }
+
+    #[tokio::test]
+    async fn test_paginator_is_lazy() {
+        let mut fetches = 0;
+        let mut paginator = Paginator::new(|_cursor: String| {
+            fetches += 1;
+            async { Ok(page(vec![1, 2], "MQ==")) }
+        });
+
+        assert_eq!(paginator.next().await.unwrap().unwrap(), 1);
+        assert_eq!(paginator.next().await.unwrap().unwrap(), 2);
+        drop(paginator);
+        assert_eq!(fetches, 1);
+    }
+
+    #[tokio::test]
+    async fn test_paginator_ends_after_error() {
+        let mut paginator = Paginator::new(|cursor: String| async move {
+            match cursor.as_str() {
+                INITIAL_CURSOR => Ok(page(vec![1], "MQ==")),
+                _ => Err(ClobError::Other("page failed".to_string())),
+            }
+        });
+
+        assert_eq!(paginator.next().await.unwrap().unwrap(), 1);
+        assert!(paginator.next().await.unwrap().is_err());
+        assert!(paginator.next().await.is_none());
+    }
+}
@@ -1,9 +1,14 @@
-use crate::constants::{CLOB_DOMAIN_NAME, CLOB_VERSION, MSG_TO_SIGN};
+use crate::constants::{
+    CLOB_DOMAIN_NAME, CLOB_VERSION, EXCHANGE_DOMAIN_NAME, EXCHANGE_DOMAIN_VERSION, MSG_TO_SIGN,
+};
 use crate::errors::{ClobError, ClobResult};
+use crate::signing::signer::{signature_to_hex, ClobSigner};
+use crate::types::Side;
+use crate::utilities::{side_wire, value_to_string};
 use alloy_primitives::{keccak256, Address, B256, U256};
-use alloy_signer::Signer;
-use alloy_signer_local::PrivateKeySigner;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::str::FromStr;
 
 /// ClobAuth structure for EIP-712 signing
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -81,7 +86,7 @@ impl ClobAuth {
 
 /// Builds the canonical Polymarket CLOB EIP-712 signature
 pub async fn build_clob_eip712_signature(
-    wallet: &PrivateKeySigner,
+    wallet: &dyn ClobSigner,
     chain_id: u64,
     timestamp: u64,
     nonce: u64,
@@ -99,10 +104,288 @@ pub async fn build_clob_eip712_signature(
     let message_hash = clob_auth.eip712_hash(chain_id);
 
     // Sign the hash
-    let signature = wallet
-        .sign_hash(&message_hash)
-        .await
-        .map_err(|e| ClobError::SigningError(e.to_string()))?;
+    let signature = wallet.sign_hash(&message_hash).await?;
 
-    Ok(format!("0x{}", hex::encode(signature.as_bytes())))
-}
\ No newline at end of file
+    Ok(signature_to_hex(&signature))
+}
+/// EIP-712 type string of an exchange order
+const ORDER_TYPE_STRING: &str = "Order(uint256 salt,address maker,address signer,address taker,uint256 tokenId,uint256 makerAmount,uint256 takerAmount,uint256 expiration,uint256 nonce,uint256 feeRateBps,uint8 side,uint8 signatureType)";
+
+/// Computes the EIP-712 hash of a signed order, which the CLOB uses as the order id
+///
+/// `order` is the JSON returned by the `create_*` order methods; `exchange` is the contract
+/// the order was signed for (the neg-risk exchange for neg-risk markets).
+pub fn order_hash(order: &Value, chain_id: u64, exchange: Address) -> ClobResult<B256> {
+    // EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)
+    let mut domain = Vec::new();
+    domain.extend_from_slice(
+        keccak256(
+            b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)",
+        )
+        .as_slice(),
+    );
+    domain.extend_from_slice(keccak256(EXCHANGE_DOMAIN_NAME.as_bytes()).as_slice());
+    domain.extend_from_slice(keccak256(EXCHANGE_DOMAIN_VERSION.as_bytes()).as_slice());
+    domain.extend_from_slice(&U256::from(chain_id).to_be_bytes::<32>());
+    domain.extend_from_slice(&address_word(exchange));
+    let domain_separator = keccak256(&domain);
+
+    let mut encoded = Vec::new();
+    encoded.extend_from_slice(keccak256(ORDER_TYPE_STRING.as_bytes()).as_slice());
+    encoded.extend_from_slice(&order_uint(order, "salt")?.to_be_bytes::<32>());
+    for key in ["maker", "signer", "taker"] {
+        encoded.extend_from_slice(&address_word(order_address(order, key)?));
+    }
+    for key in [
+        "tokenId",
+        "makerAmount",
+        "takerAmount",
+        "expiration",
+        "nonce",
+        "feeRateBps",
+    ] {
+        encoded.extend_from_slice(&order_uint(order, key)?.to_be_bytes::<32>());
+    }
+    let side = side_wire::from_wire(order.get("side").unwrap_or(&Value::Null))?;
+    let side = match side {
+        Side::Buy => 0u8,
+        Side::Sell => 1u8,
+    };
+    encoded.extend_from_slice(&U256::from(side).to_be_bytes::<32>());
+    encoded.extend_from_slice(&order_uint(order, "signatureType")?.to_be_bytes::<32>());
+    let struct_hash = keccak256(&encoded);
+
+    // "\x19\x01" ‖ domainSeparator ‖ structHash
+    let mut message = vec![0x19, 0x01];
+    message.extend_from_slice(domain_separator.as_slice());
+    message.extend_from_slice(struct_hash.as_slice());
+
+    Ok(keccak256(&message))
+}
+
+/// Fields of the EIP-712 `Order` struct, in `ORDER_TYPE_STRING` order
+const ORDER_FIELDS: [(&str, &str); 12] = [
+    ("salt", "uint256"),
+    ("maker", "address"),
+    ("signer", "address"),
+    ("taker", "address"),
+    ("tokenId", "uint256"),
+    ("makerAmount", "uint256"),
+    ("takerAmount", "uint256"),
+    ("expiration", "uint256"),
+    ("nonce", "uint256"),
+    ("feeRateBps", "uint256"),
+    ("side", "uint8"),
+    ("signatureType", "uint8"),
+];
+
+/// EIP-712 typed data of an order, as `eth_signTypedData_v4` takes it
+///
+/// Signing it produces the same signature as signing `order_hash` of the same order, so a
+/// browser wallet can sign an order built by this crate. `uint256` values are kept as the
+/// order has them (decimal strings or numbers), so the message reads back as the order.
+pub fn order_typed_data(order: &Value, chain_id: u64, exchange: Address) -> ClobResult<Value> {
+    let mut message = serde_json::Map::new();
+    for (key, kind) in ORDER_FIELDS {
+        let value = match kind {
+            "address" => Value::String(order_address(order, key)?.to_checksum(None)),
+            "uint8" if key == "side" => {
+                match side_wire::from_wire(order.get(key).unwrap_or(&Value::Null))? {
+                    Side::Buy => 0.into(),
+                    Side::Sell => 1.into(),
+                }
+            }
+            "uint8" => u8::try_from(order_uint(order, key)?)
+                .map_err(|e| ClobError::Other(format!("Invalid order `{}`: {}", key, e)))?
+                .into(),
+            _ => {
+                order_uint(order, key)?;
+                order[key].clone()
+            }
+        };
+        message.insert(key.to_string(), value);
+    }
+
+    let fields = |fields: &[(&str, &str)]| -> Value {
+        fields
+            .iter()
+            .map(|(name, kind)| serde_json::json!({ "name": name, "type": kind }))
+            .collect()
+    };
+
+    Ok(serde_json::json!({
+        "types": {
+            "EIP712Domain": fields(&[
+                ("name", "string"),
+                ("version", "string"),
+                ("chainId", "uint256"),
+                ("verifyingContract", "address"),
+            ]),
+            "Order": fields(&ORDER_FIELDS),
+        },
+        "primaryType": "Order",
+        "domain": {
+            "name": EXCHANGE_DOMAIN_NAME,
+            "version": EXCHANGE_DOMAIN_VERSION,
+            "chainId": chain_id,
+            "verifyingContract": exchange.to_checksum(None),
+        },
+        "message": message,
+    }))
+}
+
+/// Address left-padded to a 32 byte ABI word
+fn address_word(address: Address) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(address.as_slice());
+    word
+}
+
+fn order_field(order: &Value, key: &str) -> ClobResult<String> {
+    order
+        .get(key)
+        .and_then(value_to_string)
+        .ok_or_else(|| ClobError::Other(format!("Order is missing `{}`", key)))
+}
+
+fn order_uint(order: &Value, key: &str) -> ClobResult<U256> {
+    let value = order_field(order, key)?;
+    U256::from_str(&value)
+        .map_err(|e| ClobError::Other(format!("Invalid order `{}` {}: {}", key, value, e)))
+}
+
+fn order_address(order: &Value, key: &str) -> ClobResult<Address> {
+    let value = order_field(order, key)?;
+    Address::from_str(&value)
+        .map_err(|e| ClobError::Other(format!("Invalid order `{}` {}: {}", key, value, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_sol_types::{eip712_domain, sol, SolStruct};
+
+    sol! {
+        struct Order {
+            uint256 salt;
+            address maker;
+            address signer;
+            address taker;
+            uint256 tokenId;
+            uint256 makerAmount;
+            uint256 takerAmount;
+            uint256 expiration;
+            uint256 nonce;
+            uint256 feeRateBps;
+            uint8 side;
+            uint8 signatureType;
+        }
+    }
+
+    #[test]
+    fn test_order_hash_matches_typed_data_hash() {
+        let maker = Address::from_str("0x6e0c80c90ea6c15917308F820Eac91Ce2724B5b5").unwrap();
+        let exchange = Address::from_str("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E").unwrap();
+        let order = serde_json::json!({
+            "salt": 479249096354_u64,
+            "maker": maker.to_string(),
+            "signer": maker.to_string(),
+            "taker": "0x0000000000000000000000000000000000000000",
+            "tokenId": "1234",
+            "makerAmount": "100000000",
+            "takerAmount": "50000000",
+            "expiration": "0",
+            "nonce": "0",
+            "feeRateBps": "100",
+            "side": "SELL",
+            "signatureType": 0,
+            "signature": "0x"
+        });
+
+        let expected = Order {
+            salt: U256::from(479249096354_u64),
+            maker,
+            signer: maker,
+            taker: Address::ZERO,
+            tokenId: U256::from(1234),
+            makerAmount: U256::from(100000000),
+            takerAmount: U256::from(50000000),
+            expiration: U256::ZERO,
+            nonce: U256::ZERO,
+            feeRateBps: U256::from(100),
+            side: 1,
+            signatureType: 0,
+        }
+        .eip712_signing_hash(&eip712_domain! {
+            name: EXCHANGE_DOMAIN_NAME,
+            version: EXCHANGE_DOMAIN_VERSION,
+            chain_id: 137,
+            verifying_contract: exchange,
+        });
+
+        assert_eq!(order_hash(&order, 137, exchange).unwrap(), expected);
+        // Same order with the numeric side `SignedOrder` serializes
+        let mut numeric_side = order.clone();
+        numeric_side["side"] = "1".into();
+        assert_eq!(order_hash(&numeric_side, 137, exchange).unwrap(), expected);
+        // The domain binds the hash to the exchange and chain
+        assert_ne!(order_hash(&order, 80002, exchange).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_order_typed_data_describes_the_hashed_order() {
+        let maker = Address::from_str("0x6e0c80c90ea6c15917308F820Eac91Ce2724B5b5").unwrap();
+        let exchange = Address::from_str("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E").unwrap();
+        let order = serde_json::json!({
+            "salt": "479249096354",
+            "maker": maker.to_string(),
+            "signer": maker.to_string(),
+            "taker": "0x0000000000000000000000000000000000000000",
+            "tokenId": "1234",
+            "makerAmount": "100000000",
+            "takerAmount": "50000000",
+            "expiration": "0",
+            "nonce": "0",
+            "feeRateBps": "100",
+            "side": "1",
+            "signatureType": 0,
+            "signature": "0x"
+        });
+
+        let typed_data = order_typed_data(&order, 137, exchange).unwrap();
+        assert_eq!(typed_data["primaryType"], "Order");
+        assert_eq!(
+            typed_data["domain"],
+            serde_json::json!({
+                "name": "Polymarket CTF Exchange",
+                "version": "1",
+                "chainId": 137,
+                "verifyingContract": exchange.to_checksum(None),
+            })
+        );
+        let order_type: Vec<String> = typed_data["types"]["Order"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| {
+                format!(
+                    "{} {}",
+                    field["type"].as_str().unwrap(),
+                    field["name"].as_str().unwrap()
+                )
+            })
+            .collect();
+        assert_eq!(
+            format!("Order({})", order_type.join(",")),
+            ORDER_TYPE_STRING
+        );
+
+        let message = &typed_data["message"];
+        assert_eq!(message["side"], 1);
+        assert_eq!(message["signatureType"], 0);
+        assert_eq!(
+            order_hash(message, 137, exchange).unwrap(),
+            order_hash(&order, 137, exchange).unwrap()
+        );
+    }
+}
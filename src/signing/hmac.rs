@@ -5,18 +5,29 @@ use sha2::Sha256;
 
 type HmacSha256 = Hmac<Sha256>;
 
-/// Builds the canonical Polymarket CLOB HMAC signature
-pub fn build_poly_hmac_signature(
-    secret: &str,
+/// Builds the message signed by [`build_poly_hmac_signature`]: `timestamp + method + path + body`
+pub fn build_signing_message(
     timestamp: u64,
     method: &str,
     request_path: &str,
     body: Option<&str>,
-) -> ClobResult<String> {
+) -> String {
     let mut message = format!("{}{}{}", timestamp, method, request_path);
     if let Some(body_str) = body {
         message.push_str(body_str);
     }
+    message
+}
+
+/// Builds the canonical Polymarket CLOB HMAC signature
+pub fn build_poly_hmac_signature(
+    secret: &str,
+    timestamp: u64,
+    method: &str,
+    request_path: &str,
+    body: Option<&str>,
+) -> ClobResult<String> {
+    let message = build_signing_message(timestamp, method, request_path, body);
 
     let secret_bytes = general_purpose::URL_SAFE.decode(secret)?;
 
@@ -28,11 +39,8 @@ pub fn build_poly_hmac_signature(
     let result = mac.finalize();
     let signature_bytes = result.into_bytes();
 
-    let signature = general_purpose::STANDARD.encode(&signature_bytes);
-
-    let url_safe = signature.replace('+', "-").replace('/', "_");
-
-    Ok(url_safe)
+    // URL-safe alphabet with `=` padding, as the JS client and the server produce
+    Ok(general_purpose::URL_SAFE.encode(signature_bytes))
 }
 
 #[cfg(test)]
@@ -58,4 +66,32 @@ mod tests {
 
         assert!(sig.is_ok());
     }
+
+    #[test]
+    fn test_hmac_matches_js_client() {
+        // Vector from the JS clob-client's HMAC signing tests
+        let signature = build_poly_hmac_signature(
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=",
+            1000000,
+            "test-sign",
+            "/orders",
+            Some(r#"{"hash": "0x123"}"#),
+        )
+        .unwrap();
+
+        assert_eq!(signature, "ZwAdJKvoYRlEKDkNMwd5BuwNNtg93kNaR_oU2HrfVvc=");
+    }
+
+    #[test]
+    fn test_signing_message_format() {
+        let body = r#"{"orderID":"0xabc"}"#;
+        assert_eq!(
+            build_signing_message(1234567890, "DELETE", "/order", Some(body)),
+            r#"1234567890DELETE/order{"orderID":"0xabc"}"#
+        );
+        assert_eq!(
+            build_signing_message(1234567890, "GET", "/data/orders", None),
+            "1234567890GET/data/orders"
+        );
+    }
 }
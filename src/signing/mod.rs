@@ -1,5 +1,10 @@
 pub mod eip712;
 pub mod hmac;
+pub mod signer;
 
+pub use crate::order_builder::{
+    build_order_typed_data, recover_signer, signed_order_from_typed_data,
+};
 pub use eip712::build_clob_eip712_signature;
-pub use hmac::build_poly_hmac_signature;
+pub use hmac::{build_poly_hmac_signature, build_signing_message};
+pub use signer::{wallet_from_mnemonic, ClobSigner};
@@ -0,0 +1,56 @@
+use crate::errors::{ClobError, ClobResult};
+use crate::utilities::BoxFuture;
+use alloy_primitives::{Address, Signature, B256};
+use alloy_signer::Signer;
+use alloy_signer_local::coins_bip39::English;
+use alloy_signer_local::{MnemonicBuilder, PrivateKeySigner};
+
+/// Key the client signs L1 auth messages and orders with
+///
+/// Implement it to keep the key outside the process (a KMS, a hardware wallet, a remote
+/// signing service); `PrivateKeySigner` is the local implementation. `sign_hash` is async
+/// so implementations can call out to the device or service.
+///
+/// `address` is deliberately sync: the client reads it once, when the signer is set, and
+/// `ClobClientBuilder::build` is sync. A remote signer should fetch its public key (e.g.
+/// KMS `GetPublicKey`, or the device's address) before it is constructed and return the
+/// stored address here.
+pub trait ClobSigner: Send + Sync {
+    /// Address of the signing key, known up front (the client reads it once when the
+    /// signer is set)
+    fn address(&self) -> Address;
+
+    /// Sign a 32-byte digest (an EIP-712 signing hash) without any prefix
+    fn sign_hash<'a>(&'a self, hash: &'a B256) -> BoxFuture<'a, ClobResult<Signature>>;
+}
+
+impl ClobSigner for PrivateKeySigner {
+    fn address(&self) -> Address {
+        Signer::address(self)
+    }
+
+    fn sign_hash<'a>(&'a self, hash: &'a B256) -> BoxFuture<'a, ClobResult<Signature>> {
+        Box::pin(async move {
+            Signer::sign_hash(self, hash)
+                .await
+                .map_err(|e| ClobError::SigningError(e.to_string()))
+        })
+    }
+}
+
+/// Derives the wallet for account `index` of a BIP-39 mnemonic, on the standard Ethereum
+/// path `m/44'/60'/0'/0/{index}`
+///
+/// Returns `WalletError` if the phrase is not a valid English mnemonic.
+pub fn wallet_from_mnemonic(phrase: &str, index: u32) -> ClobResult<PrivateKeySigner> {
+    MnemonicBuilder::<English>::default()
+        .phrase(phrase)
+        .index(index)
+        .and_then(|builder| builder.build())
+        .map_err(|e| ClobError::WalletError(format!("Invalid mnemonic: {}", e)))
+}
+
+/// Hex encoding of a signature as the CLOB expects it: `0x`, then r, s and v
+pub(crate) fn signature_to_hex(signature: &Signature) -> String {
+    format!("0x{}", hex::encode(signature.as_bytes()))
+}
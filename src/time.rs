@@ -0,0 +1,119 @@
+//! Clock the client signs requests with, and the timers it waits on
+
+/// Source of the current Unix time for request signatures
+///
+/// The client uses `SystemTimeProvider` unless another provider is set; `FixedTime` makes
+/// signatures reproducible in tests. With `use_server_time`, the server offset is applied
+/// on top of this clock.
+pub trait TimeProvider: Send + Sync {
+    /// Current Unix time in seconds
+    fn now_secs(&self) -> u64;
+
+    /// Current Unix time in milliseconds (`now_secs` scaled unless overridden)
+    fn now_millis(&self) -> u64 {
+        self.now_secs().saturating_mul(1000)
+    }
+
+    /// Whether the clock can be read (true unless overridden)
+    ///
+    /// A hook for custom providers only: `SystemTimeProvider` always has a clock, wasm32
+    /// included. A provider reporting `false` makes the client sign with the server's `/time`
+    /// on every request, and must be used with `use_server_time`.
+    fn is_available(&self) -> bool {
+        true
+    }
+}
+
+/// The system clock (`Date.now()` on wasm32, where `std::time::SystemTime` panics)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemTimeProvider;
+
+impl TimeProvider for SystemTimeProvider {
+    fn now_secs(&self) -> u64 {
+        self.now_millis() / 1000
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn now_millis(&self) -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn now_millis(&self) -> u64 {
+        js_sys::Date::now() as u64
+    }
+}
+
+/// A clock stopped at the given Unix time, in seconds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedTime(pub u64);
+
+impl TimeProvider for FixedTime {
+    fn now_secs(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Point in time for cache ages and backoff windows (`std::time::Instant` natively)
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::Instant;
+
+/// Point in time for cache ages and backoff windows
+///
+/// `std::time::Instant` panics on wasm32, so this one is read from `Date.now()`.
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Instant(std::time::Duration);
+
+#[cfg(target_arch = "wasm32")]
+impl Instant {
+    pub(crate) fn now() -> Self {
+        Self(std::time::Duration::from_millis(
+            SystemTimeProvider.now_millis(),
+        ))
+    }
+
+    pub(crate) fn elapsed(&self) -> std::time::Duration {
+        Self::now().0.saturating_sub(self.0)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl std::ops::Add<std::time::Duration> for Instant {
+    type Output = Self;
+
+    fn add(self, duration: std::time::Duration) -> Self {
+        Self(self.0 + duration)
+    }
+}
+
+/// Waits for `duration` (a tokio timer natively, `setTimeout` on wasm32)
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: std::time::Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+/// Waits for `duration` (a tokio timer natively, `setTimeout` on wasm32)
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: std::time::Duration) {
+    let millis = duration.as_millis().min(i32::MAX as u128) as i32;
+    let timer = js_sys::Promise::new(&mut |resolve, _| {
+        let set_timeout = js_sys::Reflect::get(&js_sys::global(), &"setTimeout".into())
+            .ok()
+            .and_then(|set_timeout| {
+                wasm_bindgen::JsCast::dyn_into::<js_sys::Function>(set_timeout).ok()
+            });
+        match set_timeout {
+            Some(set_timeout) => {
+                let _ = set_timeout.call2(&wasm_bindgen::JsValue::NULL, &resolve, &millis.into());
+            }
+            None => {
+                let _ = resolve.call0(&wasm_bindgen::JsValue::NULL);
+            }
+        }
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(timer).await;
+}
@@ -1,20 +1,43 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 
 // ============================================================================
 // Core Authentication & API Keys
 // ============================================================================
 
 /// API key credentials for L2 authentication
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `Debug` masks the secret and passphrase. With the `zeroize` feature, both are
+/// overwritten in memory when the credentials are dropped.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ApiKeyCreds {
     pub key: String,
     pub secret: String,
     pub passphrase: String,
 }
 
+impl fmt::Debug for ApiKeyCreds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiKeyCreds")
+            .field("key", &self.key)
+            .field("secret", &REDACTED)
+            .field("passphrase", &REDACTED)
+            .finish()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for ApiKeyCreds {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+        self.secret.zeroize();
+        self.passphrase.zeroize();
+    }
+}
+
 /// Raw API key response from server
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApiKeyRaw {
     pub api_key: String,
@@ -22,6 +45,19 @@ pub struct ApiKeyRaw {
     pub passphrase: String,
 }
 
+impl fmt::Debug for ApiKeyRaw {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiKeyRaw")
+            .field("api_key", &self.api_key)
+            .field("secret", &REDACTED)
+            .field("passphrase", &REDACTED)
+            .finish()
+    }
+}
+
+/// Stand-in for secret values in `Debug` output
+const REDACTED: &str = "***";
+
 impl From<ApiKeyRaw> for ApiKeyCreds {
     fn from(raw: ApiKeyRaw) -> Self {
         Self {
@@ -57,6 +93,19 @@ pub struct BuilderApiKeyResponse {
     pub revoked_at: Option<String>,
 }
 
+/// Highest authentication level a client is configured for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthLevel {
+    /// No wallet: public endpoints only
+    Public,
+    /// Wallet without API credentials: order signing and API key management
+    L1,
+    /// Wallet and API credentials: trading endpoints
+    L2,
+    /// L2 plus a valid builder config: builder endpoints and attribution
+    L2Builder,
+}
+
 // ============================================================================
 // Authentication Headers
 // ============================================================================
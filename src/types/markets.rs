@@ -1,18 +1,28 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use super::orders::MakerOrder;
-use super::primitives::{AssetType, PriceHistoryInterval, Side, TraderSide};
+use super::primitives::{AssetType, PriceHistoryInterval, Side, TickSize, TraderSide};
+use super::rewards::Token;
+use crate::errors::{ClobError, ClobResult};
+use crate::utilities::{generate_orderbook_summary_hash, parse_tick_size, side_wire};
 
 // ============================================================================
 // Market Data
 // ============================================================================
 
+/// One page of a cursor-paginated listing
+///
+/// `T` is the element type (e.g. `PaginationPayload<SimplifiedMarket>`); it defaults to raw
+/// JSON values.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PaginationPayload {
+pub struct PaginationPayload<T = serde_json::Value> {
+    #[serde(default)]
     pub limit: u32,
+    #[serde(default)]
     pub count: u32,
     pub next_cursor: String,
-    pub data: Vec<serde_json::Value>,
+    pub data: Vec<T>,
 }
 
 pub struct TagParams {
@@ -182,6 +192,7 @@ pub struct MarketParams {
     pub ascending: Option<bool>,
     pub condition_id: Option<String>,
     pub closed: Option<bool>,
+    pub tag_id: Option<u64>,
 }
 
 /// Market from the /markets endpoint
@@ -331,6 +342,104 @@ pub struct Market {
     pub cyom: Option<bool>,
 }
 
+impl Market {
+    /// Whether the market currently accepts orders on the CLOB
+    pub fn is_tradeable(&self) -> bool {
+        self.accepting_orders == Some(true) && self.enable_order_book == Some(true)
+    }
+
+    /// Outcome labels, decoded from the JSON-encoded `outcomes` string
+    pub fn outcomes_parsed(&self) -> ClobResult<Vec<String>> {
+        parse_json_list(self.outcomes.as_deref())
+    }
+
+    /// Outcome prices, decoded from the JSON-encoded `outcome_prices` string
+    ///
+    /// Gamma encodes each price as a decimal string (`"[\"0.55\", \"0.45\"]"`); plain numbers
+    /// are accepted too.
+    pub fn outcome_prices_parsed(&self) -> ClobResult<Vec<f64>> {
+        let prices: Vec<serde_json::Value> = parse_json_list(self.outcome_prices.as_deref())?;
+        prices
+            .iter()
+            .map(|price| {
+                let parsed = match price {
+                    serde_json::Value::String(s) => s.parse().ok(),
+                    other => other.as_f64(),
+                };
+                parsed.ok_or_else(|| ClobError::Other(format!("Invalid outcome price: {price}")))
+            })
+            .collect()
+    }
+
+    /// CLOB token ids, decoded from the JSON-encoded `clob_token_ids` string
+    pub fn clob_token_ids_parsed(&self) -> ClobResult<Vec<String>> {
+        parse_json_list(self.clob_token_ids.as_deref())
+    }
+}
+
+/// Decodes a JSON-encoded list field, treating a missing field as empty
+fn parse_json_list<T: serde::de::DeserializeOwned>(raw: Option<&str>) -> ClobResult<Vec<T>> {
+    match raw {
+        Some(raw) => Ok(serde_json::from_str(raw)?),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Market from the CLOB `/simplified-markets`, `/sampling-markets` and
+/// `/sampling-simplified-markets` endpoints
+///
+/// The simplified endpoints omit `min_order_size`, `tick_size` and `neg_risk`, which are only
+/// set for full markets from `/sampling-markets`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimplifiedMarket {
+    pub condition_id: String,
+    #[serde(default)]
+    pub tokens: Vec<Token>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rewards: Option<SimplifiedMarketRewards>,
+    #[serde(default)]
+    pub active: bool,
+    #[serde(default)]
+    pub closed: bool,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default)]
+    pub accepting_orders: bool,
+    /// Minimum order size in shares
+    #[serde(
+        default,
+        alias = "minimum_order_size",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub min_order_size: Option<f64>,
+    #[serde(
+        default,
+        alias = "minimum_tick_size",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tick_size: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neg_risk: Option<bool>,
+}
+
+/// Liquidity rewards of a CLOB market
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimplifiedMarketRewards {
+    #[serde(default)]
+    pub rates: Option<Vec<RewardsRate>>,
+    #[serde(default)]
+    pub min_size: f64,
+    #[serde(default)]
+    pub max_spread: f64,
+}
+
+/// Daily reward rate for an asset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardsRate {
+    pub asset_address: String,
+    pub rewards_daily_rate: f64,
+}
+
 /// Book parameters for batch requests
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderBookParams {
@@ -338,20 +447,254 @@ pub struct OrderBookParams {
     pub side: Option<Side>,
 }
 
+/// Order constraints of a token's market, parsed from its orderbook
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarketConstraints {
+    pub tick_size: TickSize,
+    /// Minimum order size in shares
+    pub min_order_size: f64,
+    pub neg_risk: bool,
+}
+
 /// Orderbook summary
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
 pub struct OrderBookSummary {
     pub market: String,
     pub asset_id: String,
     pub timestamp: String,
     pub bids: Vec<OrderSummary>,
     pub asks: Vec<OrderSummary>,
+    /// Empty when absent, as in WebSocket book events
+    #[serde(default)]
     pub min_order_size: String,
+    /// Empty when absent, as in WebSocket book events
+    #[serde(default)]
     pub tick_size: String,
+    #[serde(default)]
     pub neg_risk: bool,
     pub hash: String,
 }
 
+impl OrderBookSummary {
+    /// Parses the book's `tick_size`, `min_order_size` and `neg_risk` for pre-trade checks
+    pub fn constraints(&self) -> ClobResult<MarketConstraints> {
+        let tick_size = self
+            .tick_size
+            .parse::<f64>()
+            .ok()
+            .and_then(|tick| parse_tick_size(&tick.to_string()))
+            .ok_or_else(|| ClobError::Other(format!("Invalid tick size: {}", self.tick_size)))?;
+        let min_order_size = self.min_order_size.parse::<f64>().map_err(|_| {
+            ClobError::Other(format!("Invalid min order size: {}", self.min_order_size))
+        })?;
+
+        Ok(MarketConstraints {
+            tick_size,
+            min_order_size,
+            neg_risk: self.neg_risk,
+        })
+    }
+
+    /// Whether `hash` matches the hash recomputed from the book's contents
+    ///
+    /// The server hashes the book's JSON with `hash` blanked, in the key order of the `/book`
+    /// response, so a mismatch means the book was altered or truncated in transit.
+    pub fn verify_hash(&self) -> bool {
+        let mut recomputed = self.clone();
+        generate_orderbook_summary_hash(&mut recomputed) == self.hash
+    }
+
+    /// Highest bid price, or `None` if there are no bids
+    ///
+    /// Relies on the server's level order: bids ascending and asks descending by price, so
+    /// the best level of each side is the last one. Call [`Self::sanitize`] first on a book
+    /// that may be out of order.
+    pub fn best_bid(&self) -> Option<f64> {
+        best_price(&self.bids)
+    }
+
+    /// Lowest ask price, or `None` if there are no asks (same order assumption as `best_bid`)
+    pub fn best_ask(&self) -> Option<f64> {
+        best_price(&self.asks)
+    }
+
+    /// Midpoint of the best bid and ask, or `None` if either side is empty
+    pub fn midpoint(&self) -> Option<f64> {
+        Some((self.best_bid()? + self.best_ask()?) / 2.0)
+    }
+
+    /// Best ask minus best bid, or `None` if either side is empty
+    pub fn spread(&self) -> Option<f64> {
+        Some(self.best_ask()? - self.best_bid()?)
+    }
+
+    /// Repairs a bad snapshot in place: merges duplicate price levels (summing their sizes),
+    /// drops unparseable levels, restores level order (best level last) and, on a crossed
+    /// book, removes the fewest top levels from either side that uncross it. Price strings
+    /// are kept as the server sent them.
+    pub fn sanitize(&mut self) -> BookSanitizeReport {
+        let mut report = BookSanitizeReport::default();
+
+        let mut bids = merge_levels(
+            &self.bids,
+            &mut report.duplicates_merged,
+            &mut report.removed_bids,
+        );
+        let mut asks = merge_levels(
+            &self.asks,
+            &mut report.duplicates_merged,
+            &mut report.removed_asks,
+        );
+
+        // Bids ascending, asks descending, so the best level of each side is last
+        bids.sort_by(|a, b| a.0.total_cmp(&b.0));
+        asks.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        if let (Some(&(best_bid, _)), Some(&(best_ask, _))) = (bids.last(), asks.last()) {
+            if best_bid >= best_ask {
+                report.crossed = true;
+                let (drop_bids, drop_asks) = uncrossing_cut(&bids, &asks);
+                report
+                    .removed_bids
+                    .extend(bids.drain(bids.len() - drop_bids..).map(|(_, level)| level));
+                report
+                    .removed_asks
+                    .extend(asks.drain(asks.len() - drop_asks..).map(|(_, level)| level));
+            }
+        }
+
+        self.bids = bids.into_iter().map(|(_, level)| level).collect();
+        self.asks = asks.into_iter().map(|(_, level)| level).collect();
+        report
+    }
+}
+
+/// Changes made by [`OrderBookSummary::sanitize`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BookSanitizeReport {
+    /// Number of levels folded into another level at the same price
+    pub duplicates_merged: usize,
+    /// Whether the best bid was at or above the best ask
+    pub crossed: bool,
+    /// Bid levels dropped as crossed or unparseable
+    pub removed_bids: Vec<OrderSummary>,
+    /// Ask levels dropped as crossed or unparseable
+    pub removed_asks: Vec<OrderSummary>,
+}
+
+impl BookSanitizeReport {
+    /// Whether the book was already well-formed
+    pub fn is_clean(&self) -> bool {
+        self.duplicates_merged == 0
+            && !self.crossed
+            && self.removed_bids.is_empty()
+            && self.removed_asks.is_empty()
+    }
+}
+
+/// Price of the last (best) level; `None` if the side is empty or the price doesn't parse
+fn best_price(levels: &[OrderSummary]) -> Option<f64> {
+    levels.last()?.price.parse().ok()
+}
+
+/// Parses levels, summing sizes of levels at the same price into the first one seen
+fn merge_levels(
+    levels: &[OrderSummary],
+    duplicates_merged: &mut usize,
+    removed: &mut Vec<OrderSummary>,
+) -> Vec<(f64, OrderSummary)> {
+    let mut merged: Vec<(f64, f64, OrderSummary)> = Vec::with_capacity(levels.len());
+    for level in levels {
+        let (Ok(price), Ok(size)) = (level.price.parse::<f64>(), level.size.parse::<f64>()) else {
+            removed.push(level.clone());
+            continue;
+        };
+
+        match merged.iter_mut().find(|(p, _, _)| *p == price) {
+            Some((_, total, first)) => {
+                *total += size;
+                first.size = total.to_string();
+                *duplicates_merged += 1;
+            }
+            None => merged.push((price, size, level.clone())),
+        }
+    }
+    merged
+        .into_iter()
+        .map(|(price, _, level)| (price, level))
+        .collect()
+}
+
+/// Number of top bids and top asks to drop so the best bid ends up below the best ask,
+/// choosing the split that drops the fewest levels (fewer bids on a tie)
+fn uncrossing_cut(bids: &[(f64, OrderSummary)], asks: &[(f64, OrderSummary)]) -> (usize, usize) {
+    (0..=bids.len())
+        .map(|drop_bids| {
+            let drop_asks = match bids.len().checked_sub(drop_bids + 1) {
+                Some(i) => asks.iter().filter(|(price, _)| *price <= bids[i].0).count(),
+                None => 0,
+            };
+            (drop_bids, drop_asks)
+        })
+        .min_by_key(|(drop_bids, drop_asks)| drop_bids + drop_asks)
+        .unwrap_or((0, 0))
+}
+
+/// Result of checking a limit order against the current orderbook
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderBookCheck {
+    /// Whether the order crosses the spread and would execute immediately
+    /// (a buy at or above the best ask, or a sell at or below the best bid)
+    pub would_take: bool,
+    /// Best bid at the time of the check
+    pub best_bid: Option<f64>,
+    /// Best ask at the time of the check
+    pub best_ask: Option<f64>,
+    /// Size resting on the opposite side at prices the order would match
+    pub available_depth: f64,
+}
+
+/// Midpoint consistency of a binary market's two outcome tokens
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsistencyReport {
+    pub condition_id: String,
+    /// Outcome token ids, in market order (YES, NO)
+    pub token_ids: Vec<String>,
+    /// Midpoint of each token, in the same order as `token_ids`
+    pub midpoints: Vec<f64>,
+    /// Sum of the midpoints (≈ 1.0 for a consistent book)
+    pub sum: f64,
+    /// Absolute deviation of `sum` from 1.0
+    pub deviation: f64,
+    /// Whether `deviation` exceeds the tolerance (possible arbitrage or stale data)
+    pub flagged: bool,
+}
+
+/// Market order execution price together with the book it was computed from
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketPriceQuote {
+    /// Worst price the order needs to accept to fill `amount`
+    pub price: f64,
+    /// Whether the token belongs to a neg-risk market (orders go to the neg-risk exchange)
+    pub neg_risk: bool,
+}
+
+/// Expected fill of a market order against the current book
+///
+/// Amounts are in the order's own unit: USDC for buys, shares for sells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketOrderEstimate {
+    /// Volume-weighted price over the fillable part of the order
+    pub avg_price: f64,
+    /// Price of the deepest level the order reaches
+    pub worst_price: f64,
+    /// Part of the requested amount the book can fill
+    pub fillable_amount: f64,
+    /// Whether the book covers the whole amount
+    pub fully_filled: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceParams {
     pub token_id: String,
@@ -359,15 +702,41 @@ pub struct PriceParams {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
 pub struct Price {
     pub price: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
 pub struct Midpoint {
     pub mid: String,
 }
 
+/// Prices from `/prices` (and spreads from `/spreads`), keyed by token id then side
+pub type PricesResponse = HashMap<String, HashMap<Side, String>>;
+
+/// Midpoints from `/midpoints`, keyed by token id
+pub type MidpointsResponse = HashMap<String, String>;
+
+/// Full `/tick-size` response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TickSizeInfo {
+    pub minimum_tick_size: f64,
+    /// Fields beyond `minimum_tick_size` (e.g. `maximum`), kept as returned
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl TickSizeInfo {
+    /// Minimum tick size as a [`TickSize`]
+    pub fn tick_size(&self) -> ClobResult<TickSize> {
+        parse_tick_size(&self.minimum_tick_size.to_string()).ok_or_else(|| {
+            ClobError::Other(format!("Invalid tick size: {}", self.minimum_tick_size))
+        })
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PriceHistoryParams {
     pub token_id: String,
@@ -381,6 +750,7 @@ pub struct PriceHistoryParams {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
 pub struct HistoryPriceItem {
     /// Timestamp
     pub t: u64,
@@ -389,10 +759,28 @@ pub struct HistoryPriceItem {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
 pub struct HistoryPrice {
     pub history: Vec<HistoryPriceItem>,
 }
 
+impl HistoryPrice {
+    /// Orders the history by timestamp, oldest first (ties keep their original order)
+    pub fn sort(&mut self) {
+        self.history.sort_by_key(|item| item.t);
+    }
+
+    /// Oldest point, assuming the history is sorted
+    pub fn first(&self) -> Option<&HistoryPriceItem> {
+        self.history.first()
+    }
+
+    /// Most recent point, assuming the history is sorted
+    pub fn last(&self) -> Option<&HistoryPriceItem> {
+        self.history.last()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpreadsParams {
     pub token_id: String,
@@ -404,18 +792,41 @@ pub struct LastTradePriceParams {
     pub token_id: String,
 }
 
+/// Price and taker side of a token's most recent trade
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
+pub struct LastTradePrice {
+    pub price: String,
+    #[serde(default)]
+    pub side: Option<Side>,
+}
+
+impl LastTradePrice {
+    /// Parses `price`
+    pub fn price_f64(&self) -> ClobResult<f64> {
+        self.price
+            .parse()
+            .map_err(|_| ClobError::Other(format!("Invalid last trade price: {}", self.price)))
+    }
+}
+
 // ============================================================================
 // Trading Data
 // ============================================================================
 
 /// Order summary in orderbook
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
 pub struct OrderSummary {
     pub price: String,
     pub size: String,
 }
 /// Trade information
+///
+/// Also carried by user channel trade updates, which leave out the fee rate, bucket index,
+/// maker address, transaction hash and trader side; those take their defaults there.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
 pub struct Trade {
     pub id: String,
     pub taker_order_id: String,
@@ -423,18 +834,71 @@ pub struct Trade {
     pub asset_id: String,
     pub side: Side,
     pub size: String,
+    #[serde(default)]
     pub fee_rate_bps: String,
     pub price: String,
+    /// `MATCHED`, `MINED`, `CONFIRMED`, `RETRYING` or `FAILED`
     pub status: String,
+    /// Sent as `matchtime` on the user channel
+    #[serde(alias = "matchtime")]
     pub match_time: String,
     pub last_update: String,
     pub outcome: String,
+    #[serde(default)]
     pub bucket_index: u32,
     pub owner: String,
+    #[serde(default)]
     pub maker_address: String,
     pub maker_orders: Vec<MakerOrder>,
+    #[serde(default)]
     pub transaction_hash: String,
-    pub trader_side: TraderSide,
+    #[serde(default)]
+    pub trader_side: Option<TraderSide>,
+}
+
+/// Trade count and notional volume over a time window
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VolumeSummary {
+    pub trade_count: usize,
+    /// Sum of `size * price` over all trades, in USDC
+    pub volume: f64,
+    /// Trades where the user's order was resting on the book
+    pub maker: SideVolume,
+    /// Trades where the user's order crossed the spread
+    pub taker: SideVolume,
+}
+
+/// Trade count and notional volume for one trader side
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SideVolume {
+    pub trade_count: usize,
+    pub volume: f64,
+}
+
+impl VolumeSummary {
+    /// Adds a trade's notional (`size * price`) to the totals and, when known, to its
+    /// trader side
+    pub fn add(&mut self, trade: &Trade) -> ClobResult<()> {
+        let parse = |value: &str, field: &str| {
+            value.parse::<f64>().map_err(|_| {
+                ClobError::Other(format!("Invalid trade {} {}: {}", trade.id, field, value))
+            })
+        };
+        let notional = parse(&trade.size, "size")? * parse(&trade.price, "price")?;
+
+        self.trade_count += 1;
+        self.volume += notional;
+
+        let side = match trade.trader_side {
+            Some(TraderSide::Maker) => &mut self.maker,
+            Some(TraderSide::Taker) => &mut self.taker,
+            None => return Ok(()),
+        };
+        side.trade_count += 1;
+        side.volume += notional;
+
+        Ok(())
+    }
 }
 
 /// Trade parameters for filtering
@@ -456,6 +920,7 @@ pub struct TradeParams {
 
 /// Paginated trades response
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
 pub struct TradesPaginatedResponse {
     pub data: Vec<Trade>,
     pub next_cursor: String,
@@ -474,14 +939,21 @@ pub struct BalanceAllowanceParams {
 }
 
 /// Balance allowance response
+///
+/// Fields beyond `balance`/`allowance` (such as the per-contract `allowances` map) are kept
+/// in `extra`, so this type accepts them even with `strict-deserialization`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BalanceAllowanceResponse {
     pub balance: String,
+    #[serde(default)]
     pub allowance: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 /// Ban status response
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
 pub struct BanStatus {
     pub closed_only: bool,
 }
@@ -567,6 +1039,13 @@ pub struct BuilderTrade {
     pub updated_at: Option<String>,
 }
 
+impl BuilderTrade {
+    /// Parsed trade side
+    pub fn side(&self) -> ClobResult<Side> {
+        side_wire::from_wire(&serde_json::Value::from(self.side.as_str()))
+    }
+}
+
 /// Builder trades response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BuilderTradesResponse {
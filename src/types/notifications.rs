@@ -6,13 +6,28 @@ use serde::{Deserialize, Serialize};
 
 /// Notification
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
 pub struct Notification {
+    /// Server id, used to drop the notification once handled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
     #[serde(rename = "type")]
     pub notification_type: u32,
     pub owner: String,
     pub payload: serde_json::Value,
 }
 
+impl Notification {
+    /// Identity used to tell notifications apart across polls: the id when the server sends
+    /// one, else the full content
+    pub fn dedup_key(&self) -> String {
+        match self.id {
+            Some(id) => id.to_string(),
+            None => format!("{}:{}:{}", self.notification_type, self.owner, self.payload),
+        }
+    }
+}
+
 /// Drop notification parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DropNotificationParams {
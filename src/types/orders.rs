@@ -1,8 +1,12 @@
 use alloy_primitives::Address;
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::primitives::{OrderType, Side};
+use super::primitives::{OrderStatus, OrderType, Side};
+use crate::errors::{ClobError, ClobResult};
+use crate::utilities::side_wire;
 
 // ============================================================================
 // Order Types & Parameters
@@ -36,9 +40,39 @@ pub struct UserLimitOrder {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expiration: Option<u64>,
 
+    /// Seconds added to `expiration` before signing (default `SECONDS_DELAY`, `Some(0)` to
+    /// sign the expiration as given)
+    #[serde(default, skip_serializing)]
+    pub expiration_buffer: Option<u64>,
+
     /// Address of the order taker (zero address = public order)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub taker: Option<Address>,
+
+    /// Order type the order will be posted as; when set, an expiration is required for GTD
+    /// and rejected for the other types
+    #[serde(rename = "orderType", skip_serializing_if = "Option::is_none")]
+    pub order_type: Option<OrderType>,
+}
+
+impl UserLimitOrder {
+    /// Restricts the order to a single counterparty (private order)
+    ///
+    /// Returns an error for the zero address, which would silently make the order public.
+    pub fn private_to(mut self, taker: Address) -> ClobResult<Self> {
+        if taker == Address::ZERO {
+            return Err(ClobError::Other(
+                "Private order taker must be a non-zero address".to_string(),
+            ));
+        }
+        self.taker = Some(taker);
+        Ok(self)
+    }
+
+    /// Whether the order is restricted to a specific taker
+    pub fn is_private(&self) -> bool {
+        self.taker.is_some_and(|taker| taker != Address::ZERO)
+    }
 }
 
 /// Simplified market order for users
@@ -71,9 +105,117 @@ pub struct UserMarketOrder {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub taker: Option<Address>,
 
+    /// Timestamp after which the order is expired (GTD only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<u64>,
+
     /// Order type (FOK or FAK)
     #[serde(rename = "orderType", skip_serializing_if = "Option::is_none")]
     pub order_type: Option<OrderType>,
+
+    /// Highest market price to accept when `price` is computed from the book; a worse
+    /// price fails with `SlippageExceeded` instead of being signed
+    #[serde(default, skip_serializing)]
+    pub max_price: Option<f64>,
+
+    /// Lowest market price to accept when `price` is computed from the book
+    #[serde(default, skip_serializing)]
+    pub min_price: Option<f64>,
+}
+
+/// Limit order with decimal price and size, signed without going through `f64`
+/// (requires the `decimal` feature)
+#[cfg(feature = "decimal")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserOrderDecimal {
+    /// Token ID of the conditional token asset being traded
+    #[serde(rename = "tokenID")]
+    pub token_id: String,
+
+    /// Price used to create the order
+    pub price: Decimal,
+
+    /// Size in terms of the ConditionalToken
+    pub size: Decimal,
+
+    /// Side of the order
+    pub side: Side,
+
+    /// Fee rate, in basis points, charged to the order maker
+    #[serde(rename = "feeRateBps", skip_serializing_if = "Option::is_none")]
+    pub fee_rate_bps: Option<u32>,
+
+    /// Nonce used for onchain cancellations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u64>,
+
+    /// Timestamp after which the order is expired
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<u64>,
+
+    /// Seconds added to `expiration` before signing (default `SECONDS_DELAY`, `Some(0)` to
+    /// sign the expiration as given)
+    #[serde(default, skip_serializing)]
+    pub expiration_buffer: Option<u64>,
+
+    /// Address of the order taker (zero address = public order)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taker: Option<Address>,
+
+    /// Order type the order will be posted as; when set, an expiration is required for GTD
+    /// and rejected for the other types
+    #[serde(rename = "orderType", skip_serializing_if = "Option::is_none")]
+    pub order_type: Option<OrderType>,
+}
+
+/// Market order with decimal price and amount, signed without going through `f64`
+/// (requires the `decimal` feature)
+#[cfg(feature = "decimal")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserMarketOrderDecimal {
+    /// Token ID of the conditional token asset being traded
+    #[serde(rename = "tokenID")]
+    pub token_id: String,
+
+    /// Price (if not present, market price will be calculated)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<Decimal>,
+
+    /// BUY orders: $$$ Amount to buy
+    /// SELL orders: Shares to sell
+    pub amount: Decimal,
+
+    /// Side of the order
+    pub side: Side,
+
+    /// Fee rate, in basis points
+    #[serde(rename = "feeRateBps", skip_serializing_if = "Option::is_none")]
+    pub fee_rate_bps: Option<u32>,
+
+    /// Nonce used for onchain cancellations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u64>,
+
+    /// Address of the order taker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taker: Option<Address>,
+
+    /// Timestamp after which the order is expired (GTD only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<u64>,
+
+    /// Order type (FOK or FAK)
+    #[serde(rename = "orderType", skip_serializing_if = "Option::is_none")]
+    pub order_type: Option<OrderType>,
+
+    /// Highest market price to accept when `price` is computed from the book; a worse
+    /// price fails with `SlippageExceeded` instead of being signed
+    #[serde(default, skip_serializing)]
+    pub max_price: Option<Decimal>,
+
+    /// Lowest market price to accept when `price` is computed from the book
+    #[serde(default, skip_serializing)]
+    pub min_price: Option<Decimal>,
 }
 
 /// Order payload for cancellation
@@ -99,29 +241,118 @@ pub struct PostOrdersArgs {
     pub order_type: OrderType,
 }
 
+/// Result of posting a single order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PostOrderResponse {
+    /// `None` when the server leaves it out; see `is_success`
+    #[serde(default)]
+    pub success: Option<bool>,
+    #[serde(rename = "orderID", default)]
+    pub order_id: Option<String>,
+    #[serde(rename = "errorMsg", default)]
+    pub error_msg: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+impl PostOrderResponse {
+    /// Whether the order was accepted: anything but an explicit `success: false`
+    pub fn is_success(&self) -> bool {
+        self.success != Some(false)
+    }
+}
+
 /// Open order information
+///
+/// Also carried by user channel order updates, which leave out the status, maker address,
+/// creation time, expiration and order type; those take their defaults there.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
 pub struct OpenOrder {
     pub id: String,
-    pub status: String,
+    /// `Other("")` when absent
+    #[serde(default = "unknown_order_status")]
+    pub status: OrderStatus,
     pub owner: String,
+    #[serde(default)]
     pub maker_address: String,
     pub market: String,
     pub asset_id: String,
-    pub side: String,
+    /// `None` for a side this client doesn't know, rather than failing the whole response
+    #[serde(deserialize_with = "side_wire::deserialize_lenient")]
+    pub side: Option<Side>,
     pub original_size: String,
     pub size_matched: String,
     pub price: String,
+    /// Ids of the trades that matched this order; `null` on the wire before any match
+    #[serde(default, deserialize_with = "null_as_empty")]
     pub associate_trades: Vec<String>,
     pub outcome: String,
+    #[serde(default)]
     pub created_at: u64,
+    #[serde(default)]
     pub expiration: String,
-    pub order_type: String,
+    /// `Other` when absent
+    #[serde(default = "unknown_order_type")]
+    pub order_type: OrderType,
+}
+
+fn unknown_order_status() -> OrderStatus {
+    OrderStatus::Other(String::new())
+}
+
+fn unknown_order_type() -> OrderType {
+    OrderType::Other
+}
+
+fn null_as_empty<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Option::<Vec<String>>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+impl OpenOrder {
+    /// Whether the order is still resting on the book (live or partially filled)
+    pub fn is_open(&self) -> bool {
+        self.status.is_open()
+    }
+
+    /// Whether the order was cancelled (by the user or on market resolution)
+    pub fn is_cancelled(&self) -> bool {
+        self.status.is_cancelled()
+    }
+}
+
+/// Classifies a raw order status as open (resting on the book) or closed.
+///
+/// Accepts both the short form (`LIVE`) and the prefixed form (`ORDER_STATUS_LIVE`).
+/// Matched, cancelled and unknown statuses are treated as closed.
+pub fn is_open_order_status(status: &str) -> bool {
+    OrderStatus::from(status).is_open()
+}
+
+/// Whether a raw order status is a cancellation (`CANCELED`, `CANCELED_MARKET_RESOLVED`),
+/// in either the short or the `ORDER_STATUS_` prefixed form
+pub fn is_cancelled_order_status(status: &str) -> bool {
+    OrderStatus::from(status).is_cancelled()
 }
 
 /// Open orders response
 pub type OpenOrdersResponse = Vec<OpenOrder>;
 
+/// One page of open orders
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
+pub struct OpenOrdersPaginatedResponse {
+    pub data: Vec<OpenOrder>,
+    pub next_cursor: String,
+    #[serde(default)]
+    pub limit: Option<u32>,
+    #[serde(default)]
+    pub count: Option<u32>,
+}
+
 /// Open order parameters for filtering
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct OpenOrderParams {
@@ -134,17 +365,23 @@ pub struct OpenOrderParams {
 }
 
 /// Maker order information
+///
+/// User channel trade updates leave out the maker address, fee rate and side.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
 pub struct MakerOrder {
     pub order_id: String,
     pub owner: String,
+    #[serde(default)]
     pub maker_address: String,
     pub matched_amount: String,
     pub price: String,
+    #[serde(default)]
     pub fee_rate_bps: String,
     pub asset_id: String,
     pub outcome: String,
-    pub side: Side,
+    #[serde(default)]
+    pub side: Option<Side>,
 }
 
 // ============================================================================
@@ -159,6 +396,7 @@ pub struct OrderScoringParams {
 
 /// Order scoring response
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
 pub struct OrderScoring {
     pub scoring: bool,
 }
@@ -169,6 +407,26 @@ pub struct OrdersScoringParams {
     pub order_ids: Vec<String>,
 }
 
-/// Orders scoring response
-pub type OrdersScoring = HashMap<String, bool>;
+/// Kind of order lifecycle event recorded by the client
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderEventKind {
+    Post,
+    Cancel,
+}
+
+/// Order submission or cancellation recorded in the client's event log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderEvent {
+    /// Unix timestamp (milliseconds) when the response was received
+    pub timestamp: u64,
+    pub kind: OrderEventKind,
+    /// Order ids returned by the exchange, or the requested ids when none were returned
+    pub order_ids: Vec<String>,
+    pub success: bool,
+    pub error: Option<String>,
+}
 
+/// Orders scoring result, keyed by every requested order id
+///
+/// `None` means the server did not report on that id, so callers can retry only those.
+pub type OrdersScoring = HashMap<String, Option<bool>>;
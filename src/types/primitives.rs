@@ -1,12 +1,20 @@
-use serde::{Deserialize, Serialize};
+use crate::constants::{FEE_RATE_CACHE_TTL, TOKEN_PAIR_CACHE_TTL};
+use serde::{de, Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 
 // ============================================================================
 // Fundamental Enums
 // ============================================================================
 
 /// Blockchain network
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Non-exhaustive so that supporting a new network isn't a breaking change. Serializes as
+/// the chain id in a string (`"137"`) and deserializes from that or the bare number;
+/// `Display` prints the bare id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
 pub enum Chain {
     /// Polygon mainnet
     #[serde(rename = "137")]
@@ -20,10 +28,45 @@ impl Chain {
     pub fn chain_id(&self) -> u64 {
         *self as u64
     }
+
+    /// The supported chain with this id, if any
+    pub fn from_chain_id(chain_id: u64) -> Option<Chain> {
+        match chain_id {
+            137 => Some(Chain::Polygon),
+            80002 => Some(Chain::Amoy),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Chain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.chain_id())
+    }
+}
+
+impl<'de> Deserialize<'de> for Chain {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum ChainId {
+            Number(u64),
+            Text(String),
+        }
+
+        let chain_id = match ChainId::deserialize(deserializer)? {
+            ChainId::Number(chain_id) => chain_id,
+            ChainId::Text(text) => text
+                .parse()
+                .map_err(|_| de::Error::custom(format!("invalid chain id {:?}", text)))?,
+        };
+        Chain::from_chain_id(chain_id)
+            .ok_or_else(|| de::Error::custom(format!("unsupported chain id {}", chain_id)))
+    }
 }
 
 /// Order side (buy or sell)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Side {
     Buy,
@@ -52,6 +95,89 @@ pub enum OrderType {
     Gtd,
     /// Fill and Kill - partial fills allowed, cancel remainder
     Fak,
+    /// Order type not known to this client, as reported on open orders
+    #[serde(other)]
+    Other,
+}
+
+/// Status of an order on the CLOB
+///
+/// Parsed case-insensitively from the short (`LIVE`) or prefixed (`ORDER_STATUS_LIVE`) form;
+/// statuses this client doesn't know are kept as `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum OrderStatus {
+    /// Resting on the book
+    Live,
+    /// Fully matched
+    Matched,
+    /// Cancelled by the user or on market resolution
+    Cancelled,
+    /// Marketable order held back by the matching delay
+    Delayed,
+    /// Marketable order placed on the book after the delay without matching
+    Unmatched,
+    /// Status not known to this client, as sent by the server
+    Other(String),
+}
+
+impl OrderStatus {
+    /// Whether the order is still resting on the book (live, delayed or unmatched)
+    pub fn is_open(&self) -> bool {
+        matches!(
+            self,
+            OrderStatus::Live | OrderStatus::Delayed | OrderStatus::Unmatched
+        )
+    }
+
+    /// Whether the order was cancelled
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, OrderStatus::Cancelled)
+    }
+
+    /// Short uppercase form used by the API (`LIVE`, `CANCELED`, ...)
+    pub fn as_str(&self) -> &str {
+        match self {
+            OrderStatus::Live => "LIVE",
+            OrderStatus::Matched => "MATCHED",
+            OrderStatus::Cancelled => "CANCELED",
+            OrderStatus::Delayed => "DELAYED",
+            OrderStatus::Unmatched => "UNMATCHED",
+            OrderStatus::Other(status) => status,
+        }
+    }
+}
+
+impl From<&str> for OrderStatus {
+    fn from(status: &str) -> Self {
+        let upper = status.to_uppercase();
+        match upper.trim_start_matches("ORDER_STATUS_") {
+            "LIVE" => OrderStatus::Live,
+            "MATCHED" => OrderStatus::Matched,
+            "DELAYED" => OrderStatus::Delayed,
+            "UNMATCHED" => OrderStatus::Unmatched,
+            s if s.starts_with("CANCELED") || s.starts_with("CANCELLED") => OrderStatus::Cancelled,
+            _ => OrderStatus::Other(status.to_string()),
+        }
+    }
+}
+
+impl From<String> for OrderStatus {
+    fn from(status: String) -> Self {
+        OrderStatus::from(status.as_str())
+    }
+}
+
+impl From<OrderStatus> for String {
+    fn from(status: OrderStatus) -> Self {
+        status.as_str().to_string()
+    }
+}
+
+impl std::fmt::Display for OrderStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Asset type
@@ -103,11 +229,16 @@ impl TickSize {
     }
 }
 
-/// Price history interval
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+/// Price history interval, the window ending now covered by `/prices-history`
+///
+/// The CLOB's `1m` token is one month; there is no minute window (use `fidelity` for
+/// minute-resolution points instead).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PriceHistoryInterval {
+    #[serde(rename = "max")]
     Max,
+    #[serde(rename = "1m")]
+    OneMonth,
     #[serde(rename = "1w")]
     OneWeek,
     #[serde(rename = "1d")]
@@ -116,23 +247,28 @@ pub enum PriceHistoryInterval {
     SixHours,
     #[serde(rename = "1h")]
     OneHour,
-    #[serde(rename = "1m")]
-    OneMinute,
 }
 
 impl PriceHistoryInterval {
-    pub fn to_string(&self) -> String {
+    /// Token sent as the `interval` query parameter, same as the serde form
+    pub fn as_str(&self) -> &'static str {
         match self {
-            PriceHistoryInterval::Max => "max".to_string(),
-            PriceHistoryInterval::OneWeek => "1w".to_string(),
-            PriceHistoryInterval::OneDay => "1d".to_string(),
-            PriceHistoryInterval::SixHours => "6h".to_string(),
-            PriceHistoryInterval::OneHour => "1h".to_string(),
-            PriceHistoryInterval::OneMinute => "1m".to_string(),
+            PriceHistoryInterval::Max => "max",
+            PriceHistoryInterval::OneMonth => "1m",
+            PriceHistoryInterval::OneWeek => "1w",
+            PriceHistoryInterval::OneDay => "1d",
+            PriceHistoryInterval::SixHours => "6h",
+            PriceHistoryInterval::OneHour => "1h",
         }
     }
 }
 
+impl std::fmt::Display for PriceHistoryInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 // ============================================================================
 // Utility Types
 // ============================================================================
@@ -152,6 +288,85 @@ pub struct RoundConfig {
     pub amount: u32,
 }
 
+/// Produces the correlation id sent in the `X-Request-Id` header of each request
+pub type RequestIdGenerator = Arc<dyn Fn() -> String + Send + Sync>;
+
+/// Fallback from server time to local time when `/time` keeps failing
+///
+/// After `failure_threshold` consecutive failures, signatures use local time for
+/// `retry_after`, after which the server time is tried again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServerTimeFallback {
+    pub failure_threshold: u32,
+    pub retry_after: Duration,
+}
+
+impl Default for ServerTimeFallback {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 3,
+            retry_after: Duration::from_secs(30),
+        }
+    }
+}
+
+/// How long per-token market lookups stay cached (`None` keeps them until invalidated)
+///
+/// Tick sizes and neg-risk flags are fixed for a market's lifetime, so they never expire by
+/// default; fee rates can change and expire after `FEE_RATE_CACHE_TTL`. `token_pairs`
+/// covers condition id -> (YES, NO) token id lookups, kept for `TOKEN_PAIR_CACHE_TTL`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheTtlConfig {
+    pub tick_size: Option<Duration>,
+    pub neg_risk: Option<Duration>,
+    pub fee_rate: Option<Duration>,
+    pub token_pairs: Option<Duration>,
+}
+
+impl Default for CacheTtlConfig {
+    fn default() -> Self {
+        Self {
+            tick_size: None,
+            neg_risk: None,
+            fee_rate: Some(FEE_RATE_CACHE_TTL),
+            token_pairs: Some(TOKEN_PAIR_CACHE_TTL),
+        }
+    }
+}
+
+/// Retry policy for transient HTTP failures
+///
+/// GET requests are retried on 5xx and 429 responses; other methods only on 429.
+/// A `Retry-After` header on the response takes precedence over the computed backoff,
+/// but is still capped at `max_delay`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// Retries after the first attempt (0 disables retrying)
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled on each subsequent one
+    pub base_delay: Duration,
+    /// Upper bound on the delay between retries, including one asked for by `Retry-After`
+    pub max_delay: Duration,
+}
+
+impl RetryConfig {
+    /// Backoff before retry number `attempt` (0-based): `base_delay * 2^attempt`, capped at `max_delay`
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u32.checked_shl(attempt).unwrap_or(u32::MAX);
+        self.base_delay.saturating_mul(factor).min(self.max_delay)
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
 // ============================================================================
 // Cache Types
 // ============================================================================
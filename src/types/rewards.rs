@@ -7,6 +7,7 @@ use std::collections::HashMap;
 
 /// User earning
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
 pub struct UserEarning {
     pub date: String,
     pub condition_id: String,
@@ -18,6 +19,7 @@ pub struct UserEarning {
 
 /// Total user earning
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
 pub struct TotalUserEarning {
     pub date: String,
     pub asset_address: String,
@@ -43,6 +45,10 @@ pub struct RewardsConfig {
     // pub date: String,
     pub asset_address: String,
     pub rewards_daily_rate: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
 }
 
 /// Market reward
@@ -59,6 +65,57 @@ pub struct MarketReward {
     pub rewards_config: Vec<RewardsConfig>,
 }
 
+/// Current rewards configuration for a market, merged from its raw rewards entries
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketRewardConfig {
+    pub condition_id: String,
+    pub asset_address: String,
+    pub rewards_daily_rate: f64,
+    pub rewards_max_spread: f64,
+    pub rewards_min_size: f64,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+}
+
+impl MarketRewardConfig {
+    /// Collapses raw market rewards into the latest config (by `start_date`, then response order)
+    pub fn from_market_rewards(rewards: &[MarketReward]) -> Option<Self> {
+        rewards
+            .iter()
+            .flat_map(|reward| {
+                reward
+                    .rewards_config
+                    .iter()
+                    .map(move |config| (reward, config))
+            })
+            .max_by(|(_, a), (_, b)| a.start_date.cmp(&b.start_date))
+            .map(|(reward, config)| Self {
+                condition_id: reward.condition_id.clone(),
+                asset_address: config.asset_address.clone(),
+                rewards_daily_rate: config.rewards_daily_rate,
+                rewards_max_spread: reward.rewards_max_spread,
+                rewards_min_size: reward.rewards_min_size,
+                start_date: config.start_date.clone(),
+                end_date: config.end_date.clone(),
+            })
+    }
+}
+
+/// Query for `get_user_earnings_and_markets_config`
+///
+/// Empty `order_by`/`position` leave the server's default ordering, as in the JS client.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UserEarningsConfigParams {
+    /// Day to report, `YYYY-MM-DD`
+    pub date: String,
+    /// Field to sort markets by
+    pub order_by: String,
+    /// Sort direction
+    pub position: String,
+    /// Only include markets without reward competition
+    pub no_competition: bool,
+}
+
 /// User rewards earning
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserRewardsEarning {
@@ -1,6 +1,46 @@
-use crate::types::{OrderBookSummary, TickSize};
+use crate::constants::{POLYGON_PROXY_FACTORY, PROXY_INIT_CODE_HASH};
+use crate::errors::{ClobError, ClobResult};
+use crate::types::{Chain, OrderBookSummary, OrderSummary, TickSize};
+use alloy_primitives::{keccak256, Address, B256};
+use serde::Serialize;
 use sha1::{Digest, Sha1};
 
+/// Boxed future returned by the crate's traits (`ClobSigner`, the HTTP transport)
+///
+/// `Send` natively; on wasm32 the browser's futures are single-threaded, so it isn't.
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxFuture<'a, T> = futures::future::BoxFuture<'a, T>;
+
+/// Boxed future returned by the crate's traits (`ClobSigner`, the HTTP transport)
+///
+/// `Send` natively; on wasm32 the browser's futures are single-threaded, so it isn't.
+#[cfg(target_arch = "wasm32")]
+pub type BoxFuture<'a, T> = futures::future::LocalBoxFuture<'a, T>;
+
+/// Boxed stream counterpart of `BoxFuture`
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxStream<'a, T> = futures::stream::BoxStream<'a, T>;
+
+/// Boxed stream counterpart of `BoxFuture`
+#[cfg(target_arch = "wasm32")]
+pub type BoxStream<'a, T> = futures::stream::LocalBoxStream<'a, T>;
+
+/// `Send` natively and implemented by every type on wasm32, for bounds that follow
+/// `BoxFuture`
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send + ?Sized> MaybeSend for T {}
+
+/// `Send` natively and implemented by every type on wasm32, for bounds that follow
+/// `BoxFuture`
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> MaybeSend for T {}
+
 /// Round to nearest value with specified decimal places.
 /// Always applies rounding to avoid floating point precision issues.
 pub fn round_normal(num: f64, decimals: u32) -> f64 {
@@ -35,9 +75,34 @@ pub fn decimal_places(num: f64) -> u32 {
     }
 }
 
+/// `/book` response as the server hashes it: keys in its order (`hash` after `timestamp`),
+/// independent of how `OrderBookSummary` declares its fields
+#[derive(Serialize)]
+struct HashedOrderBook<'a> {
+    market: &'a str,
+    asset_id: &'a str,
+    timestamp: &'a str,
+    hash: &'a str,
+    bids: &'a [OrderSummary],
+    asks: &'a [OrderSummary],
+    min_order_size: &'a str,
+    tick_size: &'a str,
+    neg_risk: bool,
+}
+
 pub fn generate_orderbook_summary_hash(orderbook: &mut OrderBookSummary) -> String {
-    orderbook.hash = String::new();
-    let json = serde_json::to_string(orderbook).unwrap();
+    let hashed = HashedOrderBook {
+        market: &orderbook.market,
+        asset_id: &orderbook.asset_id,
+        timestamp: &orderbook.timestamp,
+        hash: "",
+        bids: &orderbook.bids,
+        asks: &orderbook.asks,
+        min_order_size: &orderbook.min_order_size,
+        tick_size: &orderbook.tick_size,
+        neg_risk: orderbook.neg_risk,
+    };
+    let json = serde_json::to_string(&hashed).unwrap();
     let mut hasher = Sha1::new();
     hasher.update(json.as_bytes());
     let result = hasher.finalize();
@@ -65,9 +130,147 @@ pub fn parse_tick_size(tick_size: &str) -> Option<TickSize> {
     }
 }
 
+/// Reads a batch endpoint value that may be returned as a string or a number
+pub(crate) fn value_to_string(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Drops items whose key was already seen, keeping the first occurrence and input order
+pub(crate) fn dedup_by_key<T, K, F>(items: Vec<T>, key: F) -> Vec<T>
+where
+    K: Eq + std::hash::Hash,
+    F: Fn(&T) -> K,
+{
+    let mut seen = std::collections::HashSet::new();
+    items
+        .into_iter()
+        .filter(|item| seen.insert(key(item)))
+        .collect()
+}
+
+/// Length in bytes of an ECDSA (r, s, v) signature
+const ECDSA_SIGNATURE_LENGTH: usize = 65;
+
+/// Normalizes an order signature to the 0x-prefixed lowercase hex the exchange expects.
+///
+/// EOA (0), Poly Proxy (1) and Gnosis Safe (2) orders are all signed by the EOA, so the
+/// signature must be a 65-byte ECDSA signature. Other types only need to be non-empty hex.
+pub fn normalize_order_signature(signature: &str, signature_type: u8) -> ClobResult<String> {
+    let hex_part = signature
+        .strip_prefix("0x")
+        .or_else(|| signature.strip_prefix("0X"))
+        .unwrap_or(signature);
+
+    let byte_len = match hex::decode(hex_part) {
+        Ok(bytes) if !bytes.is_empty() => bytes.len(),
+        _ => {
+            return Err(ClobError::SigningError(format!(
+                "Malformed order signature: {}",
+                signature
+            )))
+        }
+    };
+
+    if signature_type <= 2 && byte_len != ECDSA_SIGNATURE_LENGTH {
+        return Err(ClobError::SigningError(format!(
+            "Signature type {} expects a {}-byte signature, got {} bytes",
+            signature_type, ECDSA_SIGNATURE_LENGTH, byte_len
+        )));
+    }
+
+    Ok(format!("0x{}", hex_part.to_ascii_lowercase()))
+}
+
+/// Mapping between `Side` and its wire representations.
+///
+/// The exchange's order struct encodes side as an integer enum (`0` = BUY, `1` = SELL),
+/// which `SignedOrder` serializes as `"0"`/`"1"`, while the REST API speaks `"BUY"`/`"SELL"`.
+pub mod side_wire {
+    use crate::errors::{ClobError, ClobResult};
+    use crate::types::Side;
+    use serde_json::Value;
+
+    /// String form expected by the REST API
+    pub fn to_api_string(side: Side) -> &'static str {
+        match side {
+            Side::Buy => "BUY",
+            Side::Sell => "SELL",
+        }
+    }
+
+    /// Parses a side from `"BUY"`/`"SELL"` (any case), `"0"`/`"1"` or `0`/`1`
+    pub fn from_wire(value: &Value) -> ClobResult<Side> {
+        let side = match value {
+            Value::String(s) => match s.to_ascii_uppercase().as_str() {
+                "BUY" | "0" => Some(Side::Buy),
+                "SELL" | "1" => Some(Side::Sell),
+                _ => None,
+            },
+            Value::Number(n) => match n.as_u64() {
+                Some(0) => Some(Side::Buy),
+                Some(1) => Some(Side::Sell),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        side.ok_or_else(|| ClobError::Other(format!("Invalid order side: {}", value)))
+    }
+
+    /// Serde `deserialize_with` helper accepting any form `from_wire` does
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Side, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = <Value as serde::Deserialize>::deserialize(deserializer)?;
+        from_wire(&value).map_err(serde::de::Error::custom)
+    }
+
+    /// Like `deserialize`, but an unrecognised side becomes `None`
+    pub fn deserialize_lenient<'de, D>(deserializer: D) -> Result<Option<Side>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = <Value as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(from_wire(&value).ok())
+    }
+}
+
+/// Derives the Polymarket proxy wallet (signature type 1) that the proxy factory deploys
+/// for `eoa`, i.e. the funder address to use with that signature type.
+///
+/// CREATE2 from the factory with `salt = keccak256(eoa)` (packed, 20 bytes) and the proxy
+/// init code hash. Only Polygon mainnet has a proxy factory; other chains return
+/// `ConfigError`.
+pub fn derive_proxy_address(eoa: Address, chain: Chain) -> ClobResult<Address> {
+    let factory = match chain {
+        Chain::Polygon => POLYGON_PROXY_FACTORY,
+        Chain::Amoy => {
+            return Err(ClobError::ConfigError(
+                "No Polymarket proxy factory on Amoy".to_string(),
+            ))
+        }
+    };
+
+    let factory: Address = factory
+        .parse()
+        .map_err(|e| ClobError::ConfigError(format!("Invalid proxy factory address: {}", e)))?;
+    let init_code_hash: B256 = PROXY_INIT_CODE_HASH
+        .parse()
+        .map_err(|e| ClobError::ConfigError(format!("Invalid proxy init code hash: {}", e)))?;
+
+    Ok(factory.create2(keccak256(eoa), init_code_hash))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::Side;
+    use alloy_primitives::address;
 
     #[test]
     fn test_round_normal() {
@@ -122,10 +325,116 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_normalize_ecdsa_signatures() {
+        let raw = "AB".repeat(ECDSA_SIGNATURE_LENGTH);
+        let expected = format!("0x{}", "ab".repeat(ECDSA_SIGNATURE_LENGTH));
+
+        // EOA, Poly Proxy and Gnosis Safe all carry a 65-byte EOA signature
+        for signature_type in 0..=2 {
+            assert_eq!(
+                normalize_order_signature(&format!("0x{}", raw), signature_type).unwrap(),
+                expected
+            );
+            assert_eq!(
+                normalize_order_signature(&raw, signature_type).unwrap(),
+                expected
+            );
+            assert!(
+                normalize_order_signature(&format!("0x{}", "ab".repeat(64)), signature_type)
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize_other_signature_types() {
+        let long_signature = format!("0x{}", "cd".repeat(130));
+        assert_eq!(
+            normalize_order_signature(&long_signature, 3).unwrap(),
+            long_signature
+        );
+    }
+
+    #[test]
+    fn test_normalize_malformed_signatures() {
+        assert!(normalize_order_signature("", 0).is_err());
+        assert!(normalize_order_signature("0x", 0).is_err());
+        assert!(normalize_order_signature("0xabc", 3).is_err());
+        assert!(normalize_order_signature(&format!("0x{}", "zz".repeat(65)), 0).is_err());
+    }
+
+    #[test]
+    fn test_side_wire_from_wire() {
+        use serde_json::json;
+
+        for value in [json!("BUY"), json!("buy"), json!("0"), json!(0)] {
+            assert_eq!(side_wire::from_wire(&value).unwrap(), Side::Buy);
+        }
+        for value in [json!("SELL"), json!("Sell"), json!("1"), json!(1)] {
+            assert_eq!(side_wire::from_wire(&value).unwrap(), Side::Sell);
+        }
+        for value in [
+            json!("2"),
+            json!(2),
+            json!("HOLD"),
+            json!(null),
+            json!(true),
+        ] {
+            assert!(side_wire::from_wire(&value).is_err());
+        }
+    }
+
+    #[test]
+    fn test_side_wire_round_trip() {
+        for side in [Side::Buy, Side::Sell] {
+            let api = serde_json::Value::from(side_wire::to_api_string(side));
+            assert_eq!(side_wire::from_wire(&api).unwrap(), side);
+        }
+    }
+
+    #[test]
+    fn test_dedup_by_key() {
+        let items = vec![("a", 1), ("b", 2), ("a", 3), ("c", 4), ("b", 5)];
+        let deduped = dedup_by_key(items, |(key, _)| *key);
+        assert_eq!(deduped, vec![("a", 1), ("b", 2), ("c", 4)]);
+    }
+
     #[test]
     fn test_parse_tick_size() {
         assert_eq!(parse_tick_size("0.1"), Some(TickSize::ZeroPointOne));
         assert_eq!(parse_tick_size("0.01"), Some(TickSize::ZeroPointZeroOne));
         assert_eq!(parse_tick_size("invalid"), None);
     }
+
+    #[test]
+    fn test_derive_proxy_address_create2() {
+        let eoa = address!("6e0c80c90ea6c15917308F820Eac91Ce2724B5b5");
+        let derived = derive_proxy_address(eoa, Chain::Polygon).unwrap();
+
+        // 0xff ++ factory ++ keccak256(packed eoa) ++ init code hash, last 20 bytes
+        let factory: Address = POLYGON_PROXY_FACTORY.parse().unwrap();
+        let init_code_hash: B256 = PROXY_INIT_CODE_HASH.parse().unwrap();
+        let mut preimage = vec![0xff];
+        preimage.extend_from_slice(factory.as_slice());
+        preimage.extend_from_slice(keccak256(eoa.as_slice()).as_slice());
+        preimage.extend_from_slice(init_code_hash.as_slice());
+        let expected = Address::from_slice(&keccak256(&preimage)[12..]);
+
+        assert_eq!(derived, expected);
+        assert_ne!(derived, eoa);
+        assert_ne!(
+            derive_proxy_address(Address::repeat_byte(0x11), Chain::Polygon).unwrap(),
+            derived
+        );
+    }
+
+    #[test]
+    fn test_derive_proxy_address_requires_factory() {
+        let eoa = Address::repeat_byte(0x11);
+        assert!(matches!(
+            derive_proxy_address(eoa, Chain::Amoy),
+            Err(ClobError::ConfigError(_))
+        ));
+    }
 }
@@ -0,0 +1,136 @@
+use crate::constants::{WS_PING_INTERVAL, WS_RECONNECT_BASE_DELAY, WS_RECONNECT_MAX_DELAY};
+use crate::errors::ClobResult;
+use futures::stream::{self, Stream};
+use futures::{SinkExt, StreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::time::{interval_at, Instant, Interval};
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+/// Keep-alive frames exchanged with the server, never yielded
+const PING: &str = "PING";
+const PONG: &str = "PONG";
+
+struct Connection {
+    socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    ping: Interval,
+}
+
+struct State {
+    url: String,
+    subscribe: String,
+    connection: Option<Connection>,
+    // Consecutive connection failures since the last received message
+    failures: u32,
+}
+
+/// Text frames from a WebSocket channel, reconnecting as needed
+///
+/// Sends `subscribe` after every (re)connect and a `PING` every `WS_PING_INTERVAL`. Failed
+/// or dropped connections are logged and retried with a doubling delay; the stream never ends.
+pub(super) fn text_frames(url: String, subscribe: String) -> impl Stream<Item = String> + Send {
+    let state = State {
+        url,
+        subscribe,
+        connection: None,
+        failures: 0,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            let mut connection = match state.connection.take() {
+                Some(connection) => connection,
+                None => {
+                    if state.failures > 0 {
+                        tokio::time::sleep(reconnect_delay(state.failures)).await;
+                    }
+                    match connect(&state.url, &state.subscribe).await {
+                        Ok(connection) => connection,
+                        Err(e) => {
+                            state.failures += 1;
+                            tracing::warn!(url = %state.url, "websocket connect failed: {}", e);
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            tokio::select! {
+                message = connection.socket.next() => match message {
+                    Some(Ok(Message::Text(text))) => {
+                        state.failures = 0;
+                        state.connection = Some(connection);
+                        if text != PONG {
+                            return Some((text, state));
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | None => {
+                        state.failures += 1;
+                        tracing::warn!(url = %state.url, "websocket closed by server, reconnecting");
+                    }
+                    Some(Ok(_)) => state.connection = Some(connection),
+                    Some(Err(e)) => {
+                        state.failures += 1;
+                        tracing::warn!(url = %state.url, "websocket read failed: {}", e);
+                    }
+                },
+                _ = connection.ping.tick() => {
+                    match connection.socket.send(Message::Text(PING.to_string())).await {
+                        Ok(()) => state.connection = Some(connection),
+                        Err(e) => {
+                            state.failures += 1;
+                            tracing::warn!(url = %state.url, "websocket ping failed: {}", e);
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+async fn connect(url: &str, subscribe: &str) -> Result<Connection, tungstenite::Error> {
+    let (mut socket, _) = connect_async(url).await?;
+    socket.send(Message::Text(subscribe.to_string())).await?;
+
+    Ok(Connection {
+        socket,
+        ping: interval_at(Instant::now() + WS_PING_INTERVAL, WS_PING_INTERVAL),
+    })
+}
+
+fn reconnect_delay(failures: u32) -> Duration {
+    WS_RECONNECT_BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(failures.saturating_sub(1)))
+        .min(WS_RECONNECT_MAX_DELAY)
+}
+
+/// Parses a channel message, which carries one event or an array of them
+///
+/// Events whose `event_type` is not in `event_types` are skipped; a message or event that
+/// fails to parse is returned as an error.
+pub(super) fn parse_events<E: DeserializeOwned>(
+    text: &str,
+    event_types: &[&str],
+) -> Vec<ClobResult<E>> {
+    let events = match serde_json::from_str::<Value>(text) {
+        Ok(Value::Array(events)) => events,
+        Ok(event) => vec![event],
+        Err(e) => return vec![Err(e.into())],
+    };
+
+    events
+        .into_iter()
+        .filter(|event| {
+            let event_type = event.get("event_type").and_then(Value::as_str);
+            let known = event_type.is_some_and(|t| event_types.contains(&t));
+            if !known {
+                tracing::debug!(?event_type, "skipping channel event");
+            }
+            known
+        })
+        .map(|event| Ok(serde_json::from_value(event)?))
+        .collect()
+}
@@ -0,0 +1,143 @@
+use super::connection::{self, text_frames};
+use crate::constants::WS_MARKET_CHANNEL_URL;
+use crate::errors::ClobResult;
+use crate::types::{OrderBookSummary, Side};
+use futures::stream::{self, BoxStream, StreamExt};
+use serde::{Deserialize, Serialize};
+
+/// Full book snapshot, sent on subscribe and whenever a trade changes the book
+///
+/// The channel omits `min_order_size`, `tick_size` and `neg_risk`, which are left defaulted.
+pub type BookEvent = OrderBookSummary;
+
+/// Price level updates for one market, from new or cancelled orders
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
+pub struct PriceChangeEvent {
+    pub market: String,
+    pub price_changes: Vec<PriceChange>,
+    pub timestamp: String,
+}
+
+/// New aggregate size at one price level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
+pub struct PriceChange {
+    pub asset_id: String,
+    pub price: String,
+    /// Size now resting at `price`; `"0"` when the level was removed
+    pub size: String,
+    pub side: Side,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub best_bid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub best_ask: Option<String>,
+}
+
+/// Trade executed on an asset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-deserialization", serde(deny_unknown_fields))]
+pub struct LastTradePriceEvent {
+    pub asset_id: String,
+    pub market: String,
+    pub price: String,
+    pub side: Side,
+    pub size: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_rate_bps: Option<String>,
+    pub timestamp: String,
+}
+
+/// Event delivered on the market channel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event_type", rename_all = "snake_case")]
+pub enum MarketEvent {
+    Book(BookEvent),
+    PriceChange(PriceChangeEvent),
+    LastTradePrice(LastTradePriceEvent),
+}
+
+impl MarketEvent {
+    const EVENT_TYPES: [&'static str; 3] = ["book", "price_change", "last_trade_price"];
+}
+
+/// Subscriber for the public market channel
+///
+/// Streams book snapshots, price level changes and trades for a set of assets.
+#[derive(Debug, Clone)]
+pub struct MarketChannel {
+    url: String,
+    asset_ids: Vec<String>,
+}
+
+impl MarketChannel {
+    /// Market channel for `asset_ids` on the Polymarket WebSocket endpoint
+    pub fn new(asset_ids: Vec<String>) -> Self {
+        Self::with_url(WS_MARKET_CHANNEL_URL, asset_ids)
+    }
+
+    /// Market channel for `asset_ids` on a custom endpoint
+    pub fn with_url(url: impl Into<String>, asset_ids: Vec<String>) -> Self {
+        Self {
+            url: url.into(),
+            asset_ids,
+        }
+    }
+
+    pub fn asset_ids(&self) -> &[String] {
+        &self.asset_ids
+    }
+
+    /// Connects and streams events for the subscribed assets
+    ///
+    /// The connection is opened on first poll and re-established with backoff whenever it
+    /// drops, so the stream never ends. Events of other types are skipped; a message that
+    /// fails to parse is yielded as an error.
+    pub fn subscribe(&self) -> BoxStream<'static, ClobResult<MarketEvent>> {
+        let subscribe = serde_json::json!({
+            "type": "market",
+            "assets_ids": self.asset_ids,
+        })
+        .to_string();
+
+        text_frames(self.url.clone(), subscribe)
+            .flat_map(|text| stream::iter(parse_events(&text)))
+            .boxed()
+    }
+}
+
+fn parse_events(text: &str) -> Vec<ClobResult<MarketEvent>> {
+    connection::parse_events(text, &MarketEvent::EVENT_TYPES)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_events_accepts_arrays_and_skips_unknown_types() {
+        let events = parse_events(
+            r#"[{"event_type":"book","market":"0xabc","asset_id":"1","timestamp":"1",
+                 "bids":[{"price":"0.4","size":"10"}],"asks":[],"hash":"0x1"},
+                {"event_type":"tick_size_change","asset_id":"1","market":"0xabc",
+                 "old_tick_size":"0.01","new_tick_size":"0.001","timestamp":"2"}]"#,
+        );
+
+        assert_eq!(events.len(), 1);
+        match events[0].as_ref().unwrap() {
+            MarketEvent::Book(book) => {
+                assert_eq!(book.bids[0].price, "0.4");
+                assert_eq!(book.tick_size, "");
+            }
+            other => panic!("unexpected event {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_events_reports_malformed_messages() {
+        assert!(parse_events("not json")[0].is_err());
+        assert!(parse_events(r#"{"event_type":"last_trade_price"}"#)[0].is_err());
+    }
+}
@@ -0,0 +1,13 @@
+//! WebSocket channel subscribers
+//!
+//! Channels connect lazily when their stream is first polled, and reconnect (re-sending the
+//! subscription) whenever the connection drops.
+
+mod connection;
+pub mod market;
+pub mod user;
+
+pub use market::{
+    BookEvent, LastTradePriceEvent, MarketChannel, MarketEvent, PriceChange, PriceChangeEvent,
+};
+pub use user::{OrderUpdate, OrderUpdateType, TradeUpdate, UserChannel, UserEvent};
@@ -0,0 +1,114 @@
+use super::connection::{self, text_frames};
+use crate::constants::WS_USER_CHANNEL_URL;
+use crate::errors::ClobResult;
+use crate::types::{ApiKeyCreds, OpenOrder, Trade};
+use futures::stream::{self, BoxStream, StreamExt};
+use serde::{Deserialize, Serialize};
+
+/// What happened to an order in an [`OrderUpdate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OrderUpdateType {
+    Placement,
+    /// Partially matched
+    Update,
+    Cancellation,
+}
+
+/// Order placed, matched or cancelled for the authenticated user
+///
+/// The channel sends the [`OpenOrder`] fields without its status, maker address, creation
+/// time, expiration or order type, which are left at their defaults.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderUpdate {
+    #[serde(flatten)]
+    pub order: OpenOrder,
+    #[serde(rename = "type")]
+    pub update_type: OrderUpdateType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_owner: Option<String>,
+    pub timestamp: String,
+}
+
+/// Trade involving the authenticated user, sent on match and on each status change
+///
+/// The channel sends the [`Trade`] fields without fees, bucket index, maker address,
+/// transaction hash or trader side, which are left at their defaults.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeUpdate {
+    #[serde(flatten)]
+    pub trade: Trade,
+    /// Always `"TRADE"`
+    #[serde(rename = "type")]
+    pub update_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_owner: Option<String>,
+    pub timestamp: String,
+}
+
+/// Event delivered on the user channel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event_type", rename_all = "snake_case")]
+pub enum UserEvent {
+    Order(OrderUpdate),
+    Trade(TradeUpdate),
+}
+
+impl UserEvent {
+    const EVENT_TYPES: [&'static str; 2] = ["order", "trade"];
+}
+
+/// Subscriber for the authenticated user channel
+///
+/// Streams order and trade updates for the owner of the API key, so fills can be handled
+/// without polling `get_trades`.
+#[derive(Debug, Clone)]
+pub struct UserChannel {
+    url: String,
+    creds: ApiKeyCreds,
+    markets: Vec<String>,
+}
+
+impl UserChannel {
+    /// User channel on the Polymarket WebSocket endpoint
+    ///
+    /// `markets` are condition ids to receive updates for; leave it empty for every market.
+    pub fn new(creds: ApiKeyCreds, markets: Vec<String>) -> Self {
+        Self::with_url(WS_USER_CHANNEL_URL, creds, markets)
+    }
+
+    /// User channel on a custom endpoint
+    pub fn with_url(url: impl Into<String>, creds: ApiKeyCreds, markets: Vec<String>) -> Self {
+        Self {
+            url: url.into(),
+            creds,
+            markets,
+        }
+    }
+
+    pub fn markets(&self) -> &[String] {
+        &self.markets
+    }
+
+    /// Connects and streams updates for the authenticated user
+    ///
+    /// The connection is opened on first poll and re-established (re-authenticating) with
+    /// backoff whenever it drops, so the stream never ends. Events of other types are skipped;
+    /// a message that fails to parse is yielded as an error.
+    pub fn subscribe(&self) -> BoxStream<'static, ClobResult<UserEvent>> {
+        let subscribe = serde_json::json!({
+            "type": "user",
+            "auth": {
+                "apiKey": self.creds.key,
+                "secret": self.creds.secret,
+                "passphrase": self.creds.passphrase,
+            },
+            "markets": self.markets,
+        })
+        .to_string();
+
+        text_frames(self.url.clone(), subscribe)
+            .flat_map(|text| stream::iter(connection::parse_events(&text, &UserEvent::EVENT_TYPES)))
+            .boxed()
+    }
+}
@@ -0,0 +1,64 @@
+use rs_clob_client::{types::Chain, ClobClient, ClobError};
+
+const TOKEN_ID: &str = "1234";
+
+async fn midpoint_error(status: usize, body: &str) -> ClobError {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("GET", "/midpoint")
+        .match_query(mockito::Matcher::Any)
+        .with_status(status)
+        .with_body(body)
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+    client.get_midpoint(TOKEN_ID).await.unwrap_err()
+}
+
+#[tokio::test]
+async fn test_error_field_becomes_error_code() {
+    let body = r#"{"error":"not enough balance / allowance"}"#;
+    let err = midpoint_error(400, body).await;
+
+    assert_eq!(err.error_code(), Some("not enough balance / allowance"));
+    match err {
+        ClobError::ApiError {
+            message, status, ..
+        } => {
+            assert_eq!(status, 400);
+            assert_eq!(message, body);
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_error_msg_preferred_for_order_errors() {
+    let body = r#"{"error":"invalid order","errorMsg":"INVALID_ORDER_MIN_TICK_SIZE"}"#;
+    let err = midpoint_error(400, body).await;
+
+    assert_eq!(err.error_code(), Some("INVALID_ORDER_MIN_TICK_SIZE"));
+}
+
+#[tokio::test]
+async fn test_non_json_body_has_no_error_code() {
+    let err = midpoint_error(502, "Bad Gateway").await;
+
+    assert_eq!(err.error_code(), None);
+    assert!(matches!(
+        err,
+        ClobError::ApiError { status: 502, ref message, .. } if message == "Bad Gateway"
+    ));
+}
+
+#[test]
+fn test_api_error_ignores_empty_error_msg() {
+    let err = ClobError::api_error(
+        400,
+        r#"{"error":"order not found","errorMsg":""}"#.to_string(),
+    );
+
+    assert_eq!(err.error_code(), Some("order not found"));
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use alloy_signer_local::PrivateKeySigner;
+use common::{create_mock_client_with_api_key, create_mock_client_with_builder_api_key};
+use rs_clob_client::types::{AuthLevel, Chain};
+use rs_clob_client::{ClobClient, ClobError};
+
+const HOST: &str = "http://127.0.0.1:1";
+
+#[tokio::test]
+async fn test_public_client_has_no_address() {
+    let client =
+        ClobClient::new_public(HOST.to_string(), HOST.to_string(), Chain::Polygon).unwrap();
+
+    assert_eq!(client.address(), None);
+    assert_eq!(client.auth_level(), AuthLevel::Public);
+    assert!(matches!(
+        client.can_l1_auth(),
+        Err(ClobError::L1AuthUnavailable)
+    ));
+    assert!(matches!(
+        client.can_l2_auth(),
+        Err(ClobError::L1AuthUnavailable)
+    ));
+    assert!(!client.can_builder_auth());
+}
+
+#[tokio::test]
+async fn test_wallet_client_is_l1() {
+    let wallet = PrivateKeySigner::random();
+    let address = wallet.address();
+    let client = ClobClient::builder()
+        .host(HOST)
+        .gamma_host(HOST)
+        .wallet(wallet)
+        .build()
+        .unwrap();
+
+    assert_eq!(client.address(), Some(address));
+    assert_eq!(client.auth_level(), AuthLevel::L1);
+    assert!(client.can_l1_auth().is_ok());
+    assert!(matches!(
+        client.can_l2_auth(),
+        Err(ClobError::L2AuthNotAvailable)
+    ));
+}
+
+#[tokio::test]
+async fn test_api_key_client_is_l2() {
+    let client = create_mock_client_with_api_key(HOST.to_string());
+
+    assert!(client.address().is_some());
+    assert_eq!(client.auth_level(), AuthLevel::L2);
+    assert!(client.can_l2_auth().is_ok());
+    assert!(!client.can_builder_auth());
+}
+
+#[tokio::test]
+async fn test_builder_client_is_l2_builder() {
+    let client = create_mock_client_with_builder_api_key(HOST.to_string(), "builder-key");
+
+    assert!(client.address().is_some());
+    assert_eq!(client.auth_level(), AuthLevel::L2Builder);
+    assert!(client.can_builder_auth());
+}
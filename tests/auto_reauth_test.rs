@@ -0,0 +1,131 @@
+use alloy_signer_local::PrivateKeySigner;
+use mockito::{Matcher, Mock, ServerGuard};
+use rs_clob_client::types::ApiKeyCreds;
+use rs_clob_client::{ClobClient, ClobError};
+
+fn stale_creds() -> ApiKeyCreds {
+    ApiKeyCreds {
+        key: "stale-key".to_string(),
+        secret: "c3RhbGU=".to_string(),
+        passphrase: "stale-pass".to_string(),
+    }
+}
+
+fn client(server: &ServerGuard, auto_reauth: bool) -> ClobClient {
+    ClobClient::builder()
+        .host(server.url())
+        .gamma_host(server.url())
+        .wallet(PrivateKeySigner::random())
+        .creds(stale_creds())
+        .auto_reauth(auto_reauth)
+        .build()
+        .unwrap()
+}
+
+async fn api_keys_mock(
+    server: &mut ServerGuard,
+    api_key: &str,
+    status: usize,
+    hits: usize,
+) -> Mock {
+    let body = if status == 200 {
+        r#"{"apiKeys":["fresh-key"]}"#
+    } else {
+        r#"{"error":"Unauthorized/Invalid api key"}"#
+    };
+    server
+        .mock("GET", "/auth/api-keys")
+        .match_header("POLY_API_KEY", Matcher::Exact(api_key.to_string()))
+        .with_status(status)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+async fn derive_mock(server: &mut ServerGuard, hits: usize) -> Mock {
+    server
+        .mock("GET", "/auth/derive-api-key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"apiKey":"fresh-key","secret":"ZnJlc2g=","passphrase":"fresh-pass"}"#)
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_401_rederives_key_and_retries() {
+    let mut server = mockito::Server::new_async().await;
+    let stale = api_keys_mock(&mut server, "stale-key", 401, 1).await;
+    let fresh = api_keys_mock(&mut server, "fresh-key", 200, 2).await;
+    let derive = derive_mock(&mut server, 1).await;
+
+    let client = client(&server, true);
+    let keys = client.get_api_keys().await.unwrap();
+    assert_eq!(keys.api_keys, vec!["fresh-key".to_string()]);
+
+    // The re-derived key is kept for later requests
+    client.get_api_keys().await.unwrap();
+
+    stale.assert_async().await;
+    fresh.assert_async().await;
+    derive.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_401_is_returned_when_auto_reauth_is_off() {
+    let mut server = mockito::Server::new_async().await;
+    let stale = api_keys_mock(&mut server, "stale-key", 401, 1).await;
+    let derive = derive_mock(&mut server, 0).await;
+
+    let client = client(&server, false);
+    assert!(matches!(
+        client.get_api_keys().await,
+        Err(ClobError::ApiError { status: 401, .. })
+    ));
+
+    stale.assert_async().await;
+    derive.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_request_is_retried_only_once() {
+    let mut server = mockito::Server::new_async().await;
+    let stale = api_keys_mock(&mut server, "stale-key", 401, 1).await;
+    let fresh = api_keys_mock(&mut server, "fresh-key", 401, 1).await;
+    let derive = derive_mock(&mut server, 1).await;
+
+    let client = client(&server, true);
+    assert!(matches!(
+        client.get_api_keys().await,
+        Err(ClobError::ApiError { status: 401, .. })
+    ));
+
+    stale.assert_async().await;
+    fresh.assert_async().await;
+    derive.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_concurrent_401s_share_one_rederivation() {
+    let mut server = mockito::Server::new_async().await;
+    let stale = api_keys_mock(&mut server, "stale-key", 401, 3).await;
+    let fresh = api_keys_mock(&mut server, "fresh-key", 200, 3).await;
+    let derive = derive_mock(&mut server, 1).await;
+
+    let client = client(&server, true);
+    let (first, second, third) = tokio::join!(
+        client.get_api_keys(),
+        client.get_api_keys(),
+        client.get_api_keys()
+    );
+    first.unwrap();
+    second.unwrap();
+    third.unwrap();
+
+    stale.assert_async().await;
+    fresh.assert_async().await;
+    derive.assert_async().await;
+}
@@ -1,7 +1,7 @@
 mod common;
 
-use common::{create_test_client_with_api_key};
-use rs_clob_client::types::markets::BalanceAllowanceParams;
+use common::{create_mock_client_with_api_key, create_test_client_with_api_key};
+use rs_clob_client::types::markets::{BalanceAllowanceParams, BalanceAllowanceResponse};
 use rs_clob_client::types::primitives::AssetType;
 
 #[tokio::test]
@@ -21,3 +21,99 @@ async fn test_get_balance_allowance() {
         serde_json::to_string_pretty(&result).unwrap()
     );
 }
+
+#[tokio::test]
+async fn test_update_balance_allowance() {
+    let client = create_test_client_with_api_key(0);
+
+    let result = client
+        .update_balance_allowance(BalanceAllowanceParams {
+            asset_type: AssetType::Collateral,
+            token_id: None,
+        })
+        .await
+        .expect("Failed to update balance allowance");
+
+    println!(
+        "=== Update Balance Allowance ===\n{}",
+        serde_json::to_string_pretty(&result).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_update_balance_allowance_request() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/balance-allowance/update")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "asset_type".to_string(),
+            "COLLATERAL".to_string(),
+        ))
+        .match_header("POLY_API_KEY", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    client
+        .update_balance_allowance(BalanceAllowanceParams {
+            asset_type: AssetType::Collateral,
+            token_id: None,
+        })
+        .await
+        .expect("Failed to update balance allowance");
+
+    mock.assert_async().await;
+}
+
+const BALANCE_ALLOWANCE_BODY: &str = r#"{
+    "balance": "1500000",
+    "allowance": "0",
+    "allowances": {
+        "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E": "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+    }
+}"#;
+
+#[test]
+fn test_balance_allowance_response_keeps_extra_fields() {
+    let response: BalanceAllowanceResponse =
+        serde_json::from_str(BALANCE_ALLOWANCE_BODY).expect("Failed to parse");
+
+    assert_eq!(response.balance, "1500000");
+    assert_eq!(response.allowance, "0");
+    let allowances = response.extra.get("allowances").expect("allowances kept");
+    assert!(allowances
+        .get("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E")
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_get_balance_allowance_is_typed() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/balance-allowance")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "asset_type".to_string(),
+            "COLLATERAL".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"balance":"42","allowances":{}}"#)
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let response = client
+        .get_balance_allowance(BalanceAllowanceParams {
+            asset_type: AssetType::Collateral,
+            token_id: None,
+        })
+        .await
+        .expect("Failed to get balance allowance");
+
+    assert_eq!(response.balance, "42");
+    assert_eq!(response.allowance, "");
+    mock.assert_async().await;
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use common::create_mock_client_with_api_key;
+use mockito::{Matcher, Mock, ServerGuard};
+use rs_clob_client::types::OrdersScoringParams;
+
+fn ids(range: std::ops::Range<u32>) -> Vec<String> {
+    range.map(|i| format!("0x{}", i)).collect()
+}
+
+async fn cancel_mock(server: &mut ServerGuard, chunk: &[String], status: usize) -> Mock {
+    let body = if status == 200 {
+        // The last id of each chunk had already filled
+        let (filled, canceled) = chunk.split_last().unwrap();
+        serde_json::json!({
+            "canceled": canceled,
+            "not_canceled": { filled.as_str(): "order already matched" },
+        })
+        .to_string()
+    } else {
+        r#"{"error":"internal error"}"#.to_string()
+    };
+    server
+        .mock("DELETE", "/orders")
+        .match_body(Matcher::Json(serde_json::json!({ "order_ids": chunk })))
+        .with_status(status)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_cancel_orders_splits_and_merges_batches() {
+    let mut server = mockito::Server::new_async().await;
+    let order_ids = ids(0..7);
+    let mut mocks = Vec::new();
+    for chunk in order_ids.chunks(3) {
+        mocks.push(cancel_mock(&mut server, chunk, 200).await);
+    }
+
+    let mut client = create_mock_client_with_api_key(server.url());
+    client.set_max_batch(3);
+    let response = client.cancel_orders(order_ids).await.unwrap();
+
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+    let canceled: Vec<&str> = response["canceled"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|id| id.as_str().unwrap())
+        .collect();
+    assert_eq!(canceled, vec!["0x0", "0x1", "0x3", "0x4"]);
+    let not_canceled = response["not_canceled"].as_object().unwrap();
+    assert_eq!(not_canceled.len(), 3);
+    assert_eq!(not_canceled["0x2"], "order already matched");
+    assert_eq!(not_canceled["0x6"], "order already matched");
+}
+
+#[tokio::test]
+async fn test_cancel_orders_reports_failed_batch_ids() {
+    let mut server = mockito::Server::new_async().await;
+    let order_ids = ids(0..4);
+    let ok = cancel_mock(&mut server, &order_ids[..2], 200).await;
+    let failed = cancel_mock(&mut server, &order_ids[2..], 500).await;
+
+    let mut client = create_mock_client_with_api_key(server.url());
+    client.set_max_batch(2);
+    let response = client.cancel_orders(order_ids).await.unwrap();
+
+    ok.assert_async().await;
+    failed.assert_async().await;
+    assert_eq!(response["canceled"], serde_json::json!(["0x0"]));
+    let not_canceled = response["not_canceled"].as_object().unwrap();
+    assert_eq!(not_canceled["0x1"], "order already matched");
+    assert!(not_canceled["0x2"]
+        .as_str()
+        .unwrap()
+        .contains("internal error"));
+    assert!(not_canceled["0x3"]
+        .as_str()
+        .unwrap()
+        .contains("internal error"));
+}
+
+#[tokio::test]
+async fn test_are_orders_scoring_splits_and_merges_batches() {
+    let mut server = mockito::Server::new_async().await;
+    let order_ids = ids(0..5);
+    let mut mocks = Vec::new();
+    for chunk in order_ids.chunks(2) {
+        // Every other order scores; the server omits the last id
+        let body: serde_json::Map<String, serde_json::Value> = chunk
+            .iter()
+            .filter(|id| id.as_str() != "0x4")
+            .map(|id| {
+                (
+                    id.clone(),
+                    (id.as_str() == "0x0" || id.as_str() == "0x2").into(),
+                )
+            })
+            .collect();
+        let mock = server
+            .mock("GET", "/orders-scoring")
+            .match_query(Matcher::UrlEncoded("order_ids".into(), chunk.join(",")))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::Value::Object(body).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        mocks.push(mock);
+    }
+
+    let mut client = create_mock_client_with_api_key(server.url());
+    client.set_max_batch(2);
+    let scoring = client
+        .are_orders_scoring(OrdersScoringParams { order_ids })
+        .await
+        .unwrap();
+
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+    assert_eq!(scoring.len(), 5);
+    assert_eq!(scoring["0x0"], Some(true));
+    assert_eq!(scoring["0x1"], Some(false));
+    assert_eq!(scoring["0x2"], Some(true));
+    assert_eq!(scoring["0x3"], Some(false));
+    assert_eq!(scoring["0x4"], None);
+}
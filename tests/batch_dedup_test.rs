@@ -0,0 +1,124 @@
+use mockito::Matcher;
+use rs_clob_client::{
+    types::{Chain, OrderBookParams, PriceParams, Side},
+    ClobClient,
+};
+use serde_json::json;
+
+fn price(token_id: &str, side: Side) -> PriceParams {
+    PriceParams {
+        token_id: token_id.to_string(),
+        side,
+    }
+}
+
+#[tokio::test]
+async fn test_get_prices_dedups_by_token_and_side() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/prices")
+        .match_body(Matcher::Json(json!([
+            { "token_id": "1", "side": "BUY" },
+            { "token_id": "1", "side": "SELL" },
+            { "token_id": "2", "side": "BUY" }
+        ])))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"1":{"BUY":"0.5","SELL":"0.52"},"2":{"BUY":"0.3"}}"#)
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+
+    let prices = client
+        .get_prices(vec![
+            price("1", Side::Buy),
+            price("1", Side::Sell),
+            price("1", Side::Buy),
+            price("2", Side::Buy),
+            price("2", Side::Buy),
+        ])
+        .await
+        .expect("Failed to fetch prices");
+
+    mock.assert_async().await;
+    assert_eq!(prices["1"][&Side::Sell], "0.52");
+    assert_eq!(prices["2"][&Side::Buy], "0.3");
+}
+
+#[tokio::test]
+async fn test_get_order_books_dedups_tokens() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/books")
+        .match_body(Matcher::Json(json!([
+            { "token_id": "1", "side": null },
+            { "token_id": "2", "side": null }
+        ])))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+
+    let books = ["1", "2", "1"]
+        .iter()
+        .map(|token_id| OrderBookParams {
+            token_id: token_id.to_string(),
+            side: None,
+        })
+        .collect();
+
+    client
+        .get_order_books(books)
+        .await
+        .expect("Failed to fetch orderbooks");
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_midpoints_dedups_by_token_only() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/midpoints")
+        .match_body(Matcher::Json(json!([
+            { "token_id": "1", "side": "BUY" },
+            { "token_id": "2", "side": null }
+        ])))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"1":"0.5","2":"0.3"}"#)
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+
+    let params = vec![
+        OrderBookParams {
+            token_id: "1".to_string(),
+            side: Some(Side::Buy),
+        },
+        OrderBookParams {
+            token_id: "1".to_string(),
+            side: Some(Side::Sell),
+        },
+        OrderBookParams {
+            token_id: "2".to_string(),
+            side: None,
+        },
+    ];
+
+    let midpoints = client
+        .get_midpoints(params)
+        .await
+        .expect("Failed to fetch midpoints");
+
+    mock.assert_async().await;
+    assert_eq!(midpoints["1"], "0.5");
+}
@@ -0,0 +1,96 @@
+use mockito::Matcher;
+use rs_clob_client::types::{Chain, TickSize};
+use rs_clob_client::ClobClient;
+
+fn public_client(host: String) -> ClobClient {
+    ClobClient::new_public(host.clone(), host, Chain::Polygon)
+        .expect("Failed to create public client")
+}
+
+async fn mock_lookup(
+    server: &mut mockito::ServerGuard,
+    path: &str,
+    token_id: &str,
+    body: &str,
+) -> mockito::Mock {
+    server
+        .mock("GET", path)
+        .match_query(Matcher::UrlEncoded("token_id".into(), token_id.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_get_tick_sizes_skips_cached_tokens() {
+    let mut server = mockito::Server::new_async().await;
+    let mocks = vec![
+        mock_lookup(
+            &mut server,
+            "/tick-size",
+            "111",
+            r#"{"minimum_tick_size":0.1}"#,
+        )
+        .await,
+        mock_lookup(
+            &mut server,
+            "/tick-size",
+            "222",
+            r#"{"minimum_tick_size":0.01}"#,
+        )
+        .await,
+        mock_lookup(
+            &mut server,
+            "/tick-size",
+            "333",
+            r#"{"minimum_tick_size":0.001}"#,
+        )
+        .await,
+    ];
+
+    let client = public_client(server.url());
+    client.get_tick_size("111").await.unwrap();
+
+    let tick_sizes = client
+        .get_tick_sizes(&["111", "222", "333", "222"])
+        .await
+        .unwrap();
+
+    assert_eq!(tick_sizes.len(), 3);
+    assert_eq!(tick_sizes["111"], TickSize::ZeroPointOne);
+    assert_eq!(tick_sizes["222"], TickSize::ZeroPointZeroOne);
+    assert_eq!(tick_sizes["333"], TickSize::ZeroPointZeroZeroOne);
+    // One fetch per token overall: the pre-cached token isn't requested again
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_get_neg_risks_and_fee_rates_populate_caches() {
+    let mut server = mockito::Server::new_async().await;
+    let mocks = vec![
+        mock_lookup(&mut server, "/neg-risk", "111", r#"{"neg_risk":true}"#).await,
+        mock_lookup(&mut server, "/neg-risk", "222", r#"{"neg_risk":false}"#).await,
+        mock_lookup(&mut server, "/fee-rate", "111", r#"{"base_fee":0}"#).await,
+        mock_lookup(&mut server, "/fee-rate", "222", r#"{"base_fee":100}"#).await,
+    ];
+
+    let client = public_client(server.url());
+    let neg_risks = client.get_neg_risks(&["111", "222"]).await.unwrap();
+    let fee_rates = client.get_fee_rates(&["111", "222"]).await.unwrap();
+
+    assert!(neg_risks["111"]);
+    assert!(!neg_risks["222"]);
+    assert_eq!(fee_rates["222"], 100);
+
+    // Served from the caches filled above
+    assert!(client.get_neg_risk("111").await.unwrap());
+    assert_eq!(client.get_fee_rate_bps("222").await.unwrap(), 100);
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+}
@@ -0,0 +1,122 @@
+use rs_clob_client::types::{OrderBookSummary, OrderSummary};
+
+fn level(price: &str, size: &str) -> OrderSummary {
+    OrderSummary {
+        price: price.to_string(),
+        size: size.to_string(),
+    }
+}
+
+fn book(bids: Vec<OrderSummary>, asks: Vec<OrderSummary>) -> OrderBookSummary {
+    OrderBookSummary {
+        market: "0xabc".to_string(),
+        asset_id: "1234".to_string(),
+        timestamp: "1700000000000".to_string(),
+        bids,
+        asks,
+        min_order_size: "5".to_string(),
+        tick_size: "0.01".to_string(),
+        neg_risk: false,
+        hash: "0xhash".to_string(),
+    }
+}
+
+#[test]
+fn test_sanitize_clean_book_is_unchanged() {
+    let bids = vec![level("0.4", "10"), level("0.45", "20")];
+    let asks = vec![level("0.55", "15"), level("0.5", "5")];
+    let mut orderbook = book(bids.clone(), asks.clone());
+
+    let report = orderbook.sanitize();
+
+    assert!(report.is_clean());
+    assert_eq!(orderbook.bids, bids);
+    assert_eq!(orderbook.asks, asks);
+}
+
+#[test]
+fn test_sanitize_merges_duplicate_levels() {
+    let mut orderbook = book(
+        vec![level("0.45", "20"), level("0.4", "10"), level("0.45", "5")],
+        vec![level("0.5", "5"), level("0.55", "15"), level("0.50", "1")],
+    );
+
+    let report = orderbook.sanitize();
+
+    assert_eq!(report.duplicates_merged, 2);
+    assert!(!report.crossed);
+    assert_eq!(
+        orderbook.bids,
+        vec![level("0.4", "10"), level("0.45", "25")]
+    );
+    assert_eq!(orderbook.asks, vec![level("0.55", "15"), level("0.5", "6")]);
+}
+
+#[test]
+fn test_sanitize_removes_crossed_levels() {
+    let mut orderbook = book(
+        vec![level("0.4", "10"), level("0.5", "10"), level("0.6", "3")],
+        vec![level("0.7", "10"), level("0.55", "4"), level("0.52", "2")],
+    );
+
+    let report = orderbook.sanitize();
+
+    assert!(report.crossed);
+    assert_eq!(report.removed_bids, vec![level("0.6", "3")]);
+    assert!(report.removed_asks.is_empty());
+    assert_eq!(orderbook.bids, vec![level("0.4", "10"), level("0.5", "10")]);
+    assert_eq!(
+        orderbook.asks,
+        vec![level("0.7", "10"), level("0.55", "4"), level("0.52", "2")]
+    );
+}
+
+#[test]
+fn test_sanitize_drops_crossed_asks_when_fewer() {
+    let mut orderbook = book(
+        vec![level("0.4", "10"), level("0.5", "10"), level("0.6", "3")],
+        vec![level("0.7", "10"), level("0.58", "4")],
+    );
+
+    let report = orderbook.sanitize();
+
+    assert!(report.crossed);
+    assert!(report.removed_bids.is_empty());
+    assert_eq!(report.removed_asks, vec![level("0.58", "4")]);
+    assert_eq!(orderbook.best_bid(), Some(0.6));
+    assert_eq!(orderbook.best_ask(), Some(0.7));
+}
+
+#[test]
+fn test_sanitize_keeps_price_strings() {
+    let mut orderbook = book(
+        vec![level("0.450", "20"), level("0.40", "10")],
+        vec![level("0.50", "5"), level("0.550", "15")],
+    );
+
+    orderbook.sanitize();
+
+    assert_eq!(
+        orderbook.bids,
+        vec![level("0.40", "10"), level("0.450", "20")]
+    );
+    assert_eq!(
+        orderbook.asks,
+        vec![level("0.550", "15"), level("0.50", "5")]
+    );
+}
+
+#[test]
+fn test_sanitize_drops_unparseable_levels() {
+    let mut orderbook = book(
+        vec![level("0.4", "10"), level("n/a", "10")],
+        vec![level("0.6", "")],
+    );
+
+    let report = orderbook.sanitize();
+
+    assert_eq!(report.removed_bids, vec![level("n/a", "10")]);
+    assert_eq!(report.removed_asks, vec![level("0.6", "")]);
+    assert_eq!(orderbook.bids, vec![level("0.4", "10")]);
+    assert!(orderbook.asks.is_empty());
+}
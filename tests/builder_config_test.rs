@@ -0,0 +1,69 @@
+use alloy_signer_local::PrivateKeySigner;
+use rs_builder_signing_sdk::{BuilderApiKeyCreds, BuilderConfig};
+use rs_clob_client::{types::Chain, ClobClient, ClobError};
+
+fn client_with_builder_config(
+    builder_config: BuilderConfig,
+) -> rs_clob_client::ClobResult<ClobClient> {
+    ClobClient::new(
+        "http://127.0.0.1:1".to_string(),
+        "http://127.0.0.1:1".to_string(),
+        Chain::Polygon,
+        Some(PrivateKeySigner::random()),
+        None,
+        Some(0),
+        None,
+        None,
+        false,
+        Some(builder_config),
+        None,
+    )
+}
+
+#[test]
+fn test_valid_builder_config_is_accepted() {
+    let builder_config = BuilderConfig::new(
+        None,
+        Some(BuilderApiKeyCreds {
+            key: "builder-key".to_string(),
+            secret: "dGVzdF9idWlsZGVyX3NlY3JldA==".to_string(),
+            passphrase: "builder-passphrase".to_string(),
+        }),
+    )
+    .expect("Failed to create builder config");
+
+    assert!(client_with_builder_config(builder_config).is_ok());
+}
+
+#[tokio::test]
+async fn test_invalid_builder_creds_fail_before_any_builder_call() {
+    let mut server = mockito::Server::new_async().await;
+    let get = server
+        .mock("GET", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+    let post = server
+        .mock("POST", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    // Neither local credentials nor a remote signer. The SDK refuses such a config itself,
+    // so the `is_valid` check in `ClobClientBuilder::build` is only a backstop.
+    let result = BuilderConfig::new(None, None)
+        .map_err(|e| ClobError::ConfigError(e.to_string()))
+        .and_then(|builder_config| {
+            ClobClient::builder()
+                .host(server.url())
+                .gamma_host(server.url())
+                .chain(Chain::Polygon)
+                .wallet(PrivateKeySigner::random())
+                .builder_config(builder_config)
+                .build()
+        });
+
+    assert!(matches!(result, Err(ClobError::ConfigError(_))));
+    get.assert_async().await;
+    post.assert_async().await;
+}
@@ -0,0 +1,52 @@
+use mockito::Matcher;
+use rs_clob_client::{
+    types::{Chain, TickSize},
+    ClobClient,
+};
+use std::sync::Arc;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_client_is_send_sync() {
+    assert_send_sync::<ClobClient>();
+}
+
+#[tokio::test]
+async fn test_concurrent_get_tick_size() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/tick-size")
+        .match_query(Matcher::UrlEncoded("token_id".into(), "1234".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"minimum_tick_size": 0.01}"#)
+        .expect_at_least(1)
+        .expect_at_most(2)
+        .create_async()
+        .await;
+
+    let client = Arc::new(
+        ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+            .expect("Failed to create public client"),
+    );
+
+    let tasks: Vec<_> = (0..2)
+        .map(|_| {
+            let client = Arc::clone(&client);
+            tokio::spawn(async move { client.get_tick_size("1234").await })
+        })
+        .collect();
+
+    for task in tasks {
+        let tick_size = task.await.unwrap().expect("Failed to fetch tick size");
+        assert_eq!(tick_size, TickSize::ZeroPointZeroOne);
+    }
+
+    // Served from the cache without another request
+    assert_eq!(
+        client.get_tick_size("1234").await.unwrap(),
+        TickSize::ZeroPointZeroOne
+    );
+    mock.assert_async().await;
+}
@@ -0,0 +1,137 @@
+use mockito::Matcher;
+use rs_clob_client::types::{CacheTtlConfig, Chain};
+use rs_clob_client::ClobClient;
+use std::time::Duration;
+
+fn public_client(host: String) -> ClobClient {
+    ClobClient::new_public(host.clone(), host, Chain::Polygon)
+        .expect("Failed to create public client")
+}
+
+async fn mock_lookup(
+    server: &mut mockito::ServerGuard,
+    path: &str,
+    body: &str,
+    hits: usize,
+) -> mockito::Mock {
+    server
+        .mock("GET", path)
+        .match_query(Matcher::UrlEncoded("token_id".into(), "1234".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_fee_rate_refetched_after_ttl() {
+    let mut server = mockito::Server::new_async().await;
+    let fee_mock = mock_lookup(&mut server, "/fee-rate", r#"{"base_fee":100}"#, 2).await;
+    let tick_mock = mock_lookup(
+        &mut server,
+        "/tick-size",
+        r#"{"minimum_tick_size":0.01}"#,
+        1,
+    )
+    .await;
+
+    let mut client = public_client(server.url());
+    client.set_cache_ttl(CacheTtlConfig {
+        fee_rate: Some(Duration::from_millis(50)),
+        ..CacheTtlConfig::default()
+    });
+
+    assert_eq!(client.get_fee_rate_bps("1234").await.unwrap(), 100);
+    assert_eq!(client.get_fee_rate_bps("1234").await.unwrap(), 100);
+    client.get_tick_size("1234").await.unwrap();
+
+    tokio::time::sleep(Duration::from_millis(80)).await;
+
+    // Fee rate is stale now; the tick size never expires by default
+    assert_eq!(client.get_fee_rate_bps("1234").await.unwrap(), 100);
+    client.get_tick_size("1234").await.unwrap();
+
+    fee_mock.assert_async().await;
+    tick_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_token_pair_refetched_after_ttl() {
+    let mut server = mockito::Server::new_async().await;
+    let market_mock = server
+        .mock("GET", "/markets/0xabc")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"tokens":[{"token_id":"111","outcome":"Yes"},{"token_id":"222","outcome":"No"}]}"#,
+        )
+        .expect(2)
+        .create_async()
+        .await;
+
+    let mut client = public_client(server.url());
+    client.set_cache_ttl(CacheTtlConfig {
+        token_pairs: Some(Duration::from_millis(50)),
+        ..CacheTtlConfig::default()
+    });
+
+    client.token_pair_for_condition("0xabc").await.unwrap();
+    client.token_pair_for_condition("0xabc").await.unwrap();
+
+    tokio::time::sleep(Duration::from_millis(80)).await;
+    client.token_pair_for_condition("0xabc").await.unwrap();
+
+    market_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_invalidate_token_refetches_lookups() {
+    let mut server = mockito::Server::new_async().await;
+    let tick_mock = mock_lookup(
+        &mut server,
+        "/tick-size",
+        r#"{"minimum_tick_size":0.01}"#,
+        2,
+    )
+    .await;
+    let neg_risk_mock = mock_lookup(&mut server, "/neg-risk", r#"{"neg_risk":true}"#, 2).await;
+    let fee_mock = mock_lookup(&mut server, "/fee-rate", r#"{"base_fee":0}"#, 2).await;
+
+    let client = public_client(server.url());
+    for _ in 0..2 {
+        client.get_tick_size("1234").await.unwrap();
+        assert!(client.get_neg_risk("1234").await.unwrap());
+        client.get_fee_rate_bps("1234").await.unwrap();
+    }
+
+    client.invalidate_token("1234");
+    client.get_tick_size("1234").await.unwrap();
+    client.get_neg_risk("1234").await.unwrap();
+    client.get_fee_rate_bps("1234").await.unwrap();
+
+    tick_mock.assert_async().await;
+    neg_risk_mock.assert_async().await;
+    fee_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_clear_caches_refetches_lookups() {
+    let mut server = mockito::Server::new_async().await;
+    let tick_mock = mock_lookup(
+        &mut server,
+        "/tick-size",
+        r#"{"minimum_tick_size":0.001}"#,
+        2,
+    )
+    .await;
+
+    let client = public_client(server.url());
+    client.get_tick_size("1234").await.unwrap();
+    client.get_tick_size("1234").await.unwrap();
+    client.clear_caches();
+    client.get_tick_size("1234").await.unwrap();
+
+    tick_mock.assert_async().await;
+}
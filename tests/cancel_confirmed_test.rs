@@ -0,0 +1,53 @@
+mod common;
+
+use common::{create_mock_client_with_api_key, open_order_json};
+
+const CANCELLED_ID: &str = "0xaaa";
+const FILLED_ID: &str = "0xbbb";
+const UNKNOWN_ID: &str = "0xccc";
+
+#[tokio::test]
+async fn test_cancel_orders_confirmed_excludes_filled_orders() {
+    let mut server = mockito::Server::new_async().await;
+    let cancel = server
+        .mock("DELETE", "/orders")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"canceled":["{CANCELLED_ID}","{FILLED_ID}","{UNKNOWN_ID}"],"not_canceled":{{}}}}"#
+        ))
+        .create_async()
+        .await;
+    server
+        .mock("GET", format!("/data/order/{CANCELLED_ID}").as_str())
+        .with_status(200)
+        .with_body(open_order_json(CANCELLED_ID, "CANCELED", "0").to_string())
+        .create_async()
+        .await;
+    // Filled before the cancel reached the book
+    server
+        .mock("GET", format!("/data/order/{FILLED_ID}").as_str())
+        .with_status(200)
+        .with_body(open_order_json(FILLED_ID, "MATCHED", "10").to_string())
+        .create_async()
+        .await;
+    server
+        .mock("GET", format!("/data/order/{UNKNOWN_ID}").as_str())
+        .with_status(404)
+        .with_body(r#"{"error":"order not found"}"#)
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let confirmed = client
+        .cancel_orders_confirmed(vec![
+            CANCELLED_ID.to_string(),
+            FILLED_ID.to_string(),
+            UNKNOWN_ID.to_string(),
+        ])
+        .await
+        .expect("Failed to cancel orders");
+
+    cancel.assert_async().await;
+    assert_eq!(confirmed, vec![CANCELLED_ID, UNKNOWN_ID]);
+}
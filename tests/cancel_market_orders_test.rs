@@ -0,0 +1,52 @@
+mod common;
+
+use common::create_mock_client_with_api_key;
+use mockito::Matcher;
+use rs_clob_client::types::OrderMarketCancelParams;
+use rs_clob_client::ClobError;
+
+#[tokio::test]
+async fn test_cancel_without_market_or_asset_is_rejected() {
+    let mut server = mockito::Server::new_async().await;
+    let cancel = server
+        .mock("DELETE", "/cancel-market-orders")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let params = OrderMarketCancelParams {
+        market: None,
+        asset_id: None,
+    };
+    assert!(matches!(
+        client.cancel_market_orders(params).await,
+        Err(ClobError::MissingCancelTarget)
+    ));
+
+    cancel.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_cancel_by_market_only() {
+    let mut server = mockito::Server::new_async().await;
+    let cancel = server
+        .mock("DELETE", "/cancel-market-orders")
+        .match_body(Matcher::Json(serde_json::json!({ "market": "0xabc" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"canceled":["0x1"],"not_canceled":{}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let params = OrderMarketCancelParams {
+        market: Some("0xabc".to_string()),
+        asset_id: None,
+    };
+    let result = client.cancel_market_orders(params).await.unwrap();
+    assert_eq!(result["canceled"][0], "0x1");
+
+    cancel.assert_async().await;
+}
@@ -0,0 +1,56 @@
+use rs_clob_client::constants::{get_contract_config, AMOY_CONTRACTS, MATIC_CONTRACTS};
+use rs_clob_client::types::Chain;
+use rs_clob_client::ClobError;
+
+#[test]
+fn test_from_chain_id_polygon() {
+    assert_eq!(Chain::from_chain_id(137), Some(Chain::Polygon));
+    let config = get_contract_config(137).unwrap();
+    assert_eq!(config.exchange, MATIC_CONTRACTS.exchange);
+}
+
+#[test]
+fn test_from_chain_id_amoy() {
+    assert_eq!(Chain::from_chain_id(80002), Some(Chain::Amoy));
+    let config = get_contract_config(80002).unwrap();
+    assert_eq!(config.exchange, AMOY_CONTRACTS.exchange);
+}
+
+#[test]
+fn test_unknown_chain_id() {
+    assert_eq!(Chain::from_chain_id(1), None);
+    assert!(matches!(
+        get_contract_config(1),
+        Err(ClobError::ConfigError(message)) if message.contains("chain ID 1")
+    ));
+}
+
+#[test]
+fn test_display_is_numeric_chain_id() {
+    assert_eq!(Chain::Polygon.to_string(), "137");
+    assert_eq!(Chain::Amoy.to_string(), "80002");
+    assert_eq!(
+        Chain::Polygon.to_string(),
+        Chain::Polygon.chain_id().to_string()
+    );
+}
+
+#[test]
+fn test_serde_round_trip() {
+    for chain in [Chain::Polygon, Chain::Amoy] {
+        let json = serde_json::to_string(&chain).unwrap();
+        assert_eq!(json, format!("\"{}\"", chain));
+        assert_eq!(serde_json::from_str::<Chain>(&json).unwrap(), chain);
+    }
+}
+
+#[test]
+fn test_deserialize_from_number() {
+    assert_eq!(
+        serde_json::from_str::<Chain>("137").unwrap(),
+        Chain::Polygon
+    );
+    assert_eq!(serde_json::from_str::<Chain>("80002").unwrap(), Chain::Amoy);
+    assert!(serde_json::from_str::<Chain>("1").is_err());
+    assert!(serde_json::from_str::<Chain>("\"polygon\"").is_err());
+}
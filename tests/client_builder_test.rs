@@ -0,0 +1,87 @@
+use alloy_primitives::address;
+use alloy_signer_local::PrivateKeySigner;
+use rs_clob_client::{types::Chain, ClobClient, ClobError};
+
+const FUNDER: &str = "0x1111111111111111111111111111111111111111";
+
+fn builder() -> rs_clob_client::ClobClientBuilder {
+    ClobClient::builder()
+        .host("http://127.0.0.1:1/")
+        .gamma_host("http://127.0.0.1:2")
+}
+
+#[test]
+fn test_builder_defaults() {
+    assert!(builder().build().is_ok());
+}
+
+#[test]
+fn test_builder_requires_hosts() {
+    let result = ClobClient::builder().gamma_host("http://127.0.0.1:2").build();
+    assert!(matches!(result, Err(ClobError::ConfigError(_))));
+
+    let result = ClobClient::builder().host("http://127.0.0.1:1").build();
+    assert!(matches!(result, Err(ClobError::ConfigError(_))));
+}
+
+#[test]
+fn test_builder_with_wallet_and_funder() {
+    let result = builder()
+        .chain(Chain::Amoy)
+        .wallet(PrivateKeySigner::random())
+        .signature_type(2)
+        .funder_address(FUNDER)
+        .use_server_time(true)
+        .build();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_builder_rejects_proxy_signature_without_funder() {
+    for signature_type in [1, 2] {
+        let result = builder()
+            .wallet(PrivateKeySigner::random())
+            .signature_type(signature_type)
+            .build();
+        assert!(matches!(result, Err(ClobError::ConfigError(_))));
+    }
+}
+
+#[test]
+fn test_builder_rejects_invalid_config() {
+    let result = builder().signature_type(3).build();
+    assert!(matches!(result, Err(ClobError::ConfigError(_))));
+
+    let result = builder()
+        .signature_type(1)
+        .funder_address("not-an-address")
+        .build();
+    assert!(matches!(result, Err(ClobError::ConfigError(_))));
+}
+
+#[tokio::test]
+async fn test_builder_wallet_from_mnemonic() {
+    // Well-known development mnemonic (Hardhat/Anvil default accounts)
+    let phrase = "test test test test test test test test test test test junk";
+
+    let client = builder().wallet_from_mnemonic(phrase, 0).build().unwrap();
+    assert_eq!(
+        client.address(),
+        Some(address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266"))
+    );
+
+    let client = builder().wallet_from_mnemonic(phrase, 1).build().unwrap();
+    assert_eq!(
+        client.address(),
+        Some(address!("70997970C51812dc3A010C7d01b50e0d17dc79C8"))
+    );
+}
+
+#[test]
+fn test_builder_rejects_invalid_mnemonic() {
+    let result = builder()
+        .wallet_from_mnemonic("not a valid mnemonic", 0)
+        .build();
+    assert!(matches!(result, Err(ClobError::WalletError(_))));
+}
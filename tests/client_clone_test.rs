@@ -0,0 +1,71 @@
+mod common;
+
+use common::create_mock_client_with_api_key;
+use mockito::Matcher;
+use rs_clob_client::types::TickSize;
+use rs_clob_client::ClobClient;
+use std::sync::Arc;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_client_is_send_and_sync() {
+    assert_send_sync::<ClobClient>();
+    assert_send_sync::<Arc<ClobClient>>();
+}
+
+#[tokio::test]
+async fn test_clones_run_concurrently_and_share_caches() {
+    let mut server = mockito::Server::new_async().await;
+    let tick_mock = server
+        .mock("GET", "/tick-size")
+        .match_query(Matcher::UrlEncoded("token_id".into(), "111".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"minimum_tick_size":0.01}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let time_mock = server
+        .mock("GET", "/time")
+        .with_status(200)
+        .with_body("1700000000")
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let clone = client.clone();
+
+    let first = tokio::spawn(async move {
+        let tick_size = client.get_tick_size("111").await.unwrap();
+        (client, tick_size)
+    });
+    let second = tokio::spawn(async move {
+        let time = clone.get_server_time().await.unwrap();
+        (clone, time)
+    });
+    let (client, tick_size) = first.await.unwrap();
+    let (clone, time) = second.await.unwrap();
+    assert_eq!(tick_size, TickSize::ZeroPointZeroOne);
+    assert_eq!(time, 1700000000);
+
+    // The tick size fetched through the original is cached for the clone made before it
+    let (from_client, from_clone) =
+        tokio::join!(client.get_server_time(), clone.get_tick_size("111"));
+    assert_eq!(from_client.unwrap(), 1700000000);
+    assert_eq!(from_clone.unwrap(), TickSize::ZeroPointZeroOne);
+
+    tick_mock.assert_async().await;
+    time_mock.assert_async().await;
+}
+
+#[test]
+fn test_clone_settings_are_independent() {
+    let client = create_mock_client_with_api_key("http://127.0.0.1:1".to_string());
+    let mut clone = client.clone();
+
+    clone.clear_creds();
+    assert!(client.can_l2_auth().is_ok());
+    assert!(clone.can_l2_auth().is_err());
+}
@@ -0,0 +1,120 @@
+use alloy_primitives::{Address, Signature, B256};
+use alloy_signer_local::PrivateKeySigner;
+use futures::future::BoxFuture;
+use mockito::Matcher;
+use rs_clob_client::errors::ClobResult;
+use rs_clob_client::signing::eip712::order_hash;
+use rs_clob_client::signing::ClobSigner;
+use rs_clob_client::types::{CreateOrderOptions, Side, TickSize, UserLimitOrder};
+use rs_clob_client::ClobClient;
+use serde_json::Value;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+const HOST: &str = "http://127.0.0.1:1";
+const EXCHANGE: &str = "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E";
+
+/// Stand-in for a remote signer: delegates to a local key and counts signing requests
+struct DelegatingSigner {
+    inner: PrivateKeySigner,
+    signed: AtomicUsize,
+}
+
+impl ClobSigner for DelegatingSigner {
+    fn address(&self) -> Address {
+        ClobSigner::address(&self.inner)
+    }
+
+    fn sign_hash<'a>(&'a self, hash: &'a B256) -> BoxFuture<'a, ClobResult<Signature>> {
+        self.signed.fetch_add(1, Ordering::SeqCst);
+        ClobSigner::sign_hash(&self.inner, hash)
+    }
+}
+
+fn delegating_signer() -> (Arc<DelegatingSigner>, Address) {
+    let inner = PrivateKeySigner::random();
+    let address = inner.address();
+    let signer = Arc::new(DelegatingSigner {
+        inner,
+        signed: AtomicUsize::new(0),
+    });
+    (signer, address)
+}
+
+fn order() -> UserLimitOrder {
+    UserLimitOrder {
+        token_id: "1234".to_string(),
+        price: 0.37,
+        size: 25.0,
+        side: Side::Buy,
+        fee_rate_bps: None,
+        nonce: None,
+        expiration: None,
+        expiration_buffer: None,
+        taker: None,
+        order_type: None,
+    }
+}
+
+fn recovered_signer(order: &Value) -> Address {
+    let hash = order_hash(order, 137, Address::from_str(EXCHANGE).unwrap()).unwrap();
+    let signature = Signature::from_str(order["signature"].as_str().unwrap()).unwrap();
+    signature.recover_address_from_prehash(&hash).unwrap()
+}
+
+#[tokio::test]
+async fn test_orders_are_signed_by_custom_signer() {
+    let (signer, address) = delegating_signer();
+    let client = ClobClient::builder()
+        .host(HOST)
+        .gamma_host(HOST)
+        .signer(signer.clone())
+        .build()
+        .unwrap();
+
+    let options = CreateOrderOptions {
+        tick_size: TickSize::ZeroPointZeroOne,
+        neg_risk: Some(false),
+    };
+    let signed = client
+        .create_order_offline(&order(), options, 0)
+        .await
+        .unwrap();
+
+    assert_eq!(client.address(), Some(address));
+    assert_eq!(signer.signed.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        Address::from_str(signed["signer"].as_str().unwrap()).unwrap(),
+        address
+    );
+    assert_eq!(recovered_signer(&signed), address);
+}
+
+#[tokio::test]
+async fn test_l1_headers_are_signed_by_custom_signer() {
+    let (signer, address) = delegating_signer();
+    let address = format!("{:#x}", address);
+
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/auth/derive-api-key")
+        .match_header("POLY_ADDRESS", Matcher::Exact(address))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"apiKey":"key","secret":"c2VjcmV0","passphrase":"pass"}"#)
+        .create_async()
+        .await;
+
+    let client = ClobClient::builder()
+        .host(server.url())
+        .gamma_host(server.url())
+        .signer(signer.clone())
+        .build()
+        .unwrap();
+
+    let creds = client.derive_api_key(None).await.unwrap();
+    assert_eq!(creds.key, "key");
+    assert_eq!(signer.signed.load(Ordering::SeqCst), 1);
+    mock.assert_async().await;
+}
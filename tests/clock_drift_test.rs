@@ -0,0 +1,81 @@
+mod common;
+
+use common::create_mock_client_with_api_key;
+use mockito::{Mock, ServerGuard};
+use rs_clob_client::ClobError;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+async fn unauthorized_mock(server: &mut ServerGuard, hits: usize) -> Mock {
+    server
+        .mock("GET", "/auth/api-keys")
+        .with_status(401)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error":"Unauthorized/Invalid api key"}"#)
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+async fn time_mock(server: &mut ServerGuard, server_time: i64) -> Mock {
+    server
+        .mock("GET", "/time")
+        .with_status(200)
+        .with_body(server_time.to_string())
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_large_skew_is_reported_as_clock_drift() {
+    let mut server = mockito::Server::new_async().await;
+    let api_keys = unauthorized_mock(&mut server, 2).await;
+    // The server is an hour ahead of the local clock
+    let time = time_mock(&mut server, now() + 3600).await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    assert_eq!(client.server_time_offset(), None);
+
+    for _ in 0..2 {
+        match client.get_api_keys().await {
+            Err(ClobError::ClockDrift {
+                local,
+                server,
+                skew,
+            }) => {
+                assert!((3595..=3605).contains(&skew), "skew {}", skew);
+                assert_eq!(server as i64 - local as i64, skew);
+            }
+            other => panic!("expected ClockDrift, got {:?}", other),
+        }
+    }
+
+    // Measured once, then reused
+    let offset = client.server_time_offset().unwrap();
+    assert!((3595..=3605).contains(&offset), "offset {}", offset);
+    api_keys.assert_async().await;
+    time.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_small_skew_keeps_the_api_error() {
+    let mut server = mockito::Server::new_async().await;
+    let api_keys = unauthorized_mock(&mut server, 1).await;
+    let time = time_mock(&mut server, now() - 2).await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    assert!(matches!(
+        client.get_api_keys().await,
+        Err(ClobError::ApiError { status: 401, .. })
+    ));
+
+    api_keys.assert_async().await;
+    time.assert_async().await;
+}
@@ -1,7 +1,7 @@
 #![allow(unused)]
 
 use alloy_signer_local::PrivateKeySigner;
-use rs_builder_signing_sdk::{BuilderApiKeyCreds, BuilderConfig};
+use rs_builder_signing_sdk::{BuilderApiKeyCreds, BuilderConfig, RemoteBuilderConfig};
 use rs_clob_client::{
     client::ClobClient,
     types::{ApiKeyCreds, Chain},
@@ -17,20 +17,7 @@ pub fn create_test_client() -> ClobClient {
     let gamma_host = env::var("CLOB_GAMMA_API_URL").expect("CLOB_GAMMA_API_URL must be set");
 
     // Create client without authentication (public endpoint)
-    ClobClient::new(
-        host,
-        gamma_host,
-        Chain::Polygon,
-        None,  // No wallet needed for public endpoints
-        None,  // No API credentials needed
-        None,  // No signature type
-        None,  // No funder address
-        None,  // No geo block token
-        false, // Don't use server time
-        None,  // No builder config
-        None,  // No proxy URL
-    )
-    .expect("Failed to create ClobClient")
+    ClobClient::new_public(host, gamma_host, Chain::Polygon).expect("Failed to create ClobClient")
 }
 
 pub fn create_test_client_with_wallet() -> ClobClient {
@@ -43,11 +30,7 @@ pub fn create_test_client_with_wallet() -> ClobClient {
 
     // Parse chain ID
     let chain_id_str: String = env::var("CHAIN_ID").unwrap_or_else(|_| "80002".to_string());
-    let chain_id: Chain = match chain_id_str.parse::<u64>().unwrap() {
-        137 => Chain::Polygon,
-        80002 => Chain::Amoy,
-        _ => Chain::Amoy,
-    };
+    let chain_id = Chain::from_chain_id(chain_id_str.parse().unwrap()).unwrap_or(Chain::Amoy);
 
     let address = wallet.address();
     println!("Address: {}, chainId: {}", address, chain_id_str);
@@ -83,11 +66,7 @@ pub fn create_test_client_with_api_key(signature_type: u8) -> ClobClient {
 
     // Parse chain ID
     let chain_id_str: String = env::var("CHAIN_ID").unwrap_or_else(|_| "80002".to_string());
-    let chain_id: Chain = match chain_id_str.parse::<u64>().unwrap() {
-        137 => Chain::Polygon,
-        80002 => Chain::Amoy,
-        _ => Chain::Amoy,
-    };
+    let chain_id = Chain::from_chain_id(chain_id_str.parse().unwrap()).unwrap_or(Chain::Amoy);
 
     let address = wallet.address();
     println!("Address: {}, chainId: {}", address, chain_id_str);
@@ -137,11 +116,7 @@ pub fn create_test_client_with_builder_api_key(signature_type: u8) -> ClobClient
 
     // Parse chain ID
     let chain_id_str: String = env::var("CHAIN_ID").unwrap_or_else(|_| "80002".to_string());
-    let chain_id: Chain = match chain_id_str.parse::<u64>().unwrap() {
-        137 => Chain::Polygon,
-        80002 => Chain::Amoy,
-        _ => Chain::Amoy,
-    };
+    let chain_id = Chain::from_chain_id(chain_id_str.parse().unwrap()).unwrap_or(Chain::Amoy);
 
     let address = wallet.address();
     println!("Address: {}, chainId: {}", address, chain_id_str);
@@ -192,3 +167,169 @@ pub fn create_test_client_with_builder_api_key(signature_type: u8) -> ClobClient
     )
     .expect("Failed to create ClobClient")
 }
+
+/// Creates an L2 client with a throwaway wallet and dummy credentials, pointed at a mock server
+pub fn create_mock_client_with_api_key(host: String) -> ClobClient {
+    let wallet = PrivateKeySigner::random();
+
+    let creds = ApiKeyCreds {
+        key: "test-api-key".to_string(),
+        secret: "dGVzdF9zZWNyZXRfa2V5".to_string(), // base64("test_secret_key")
+        passphrase: "test-passphrase".to_string(),
+    };
+
+    ClobClient::new(
+        host.clone(),
+        host,
+        Chain::Polygon,
+        Some(wallet),
+        Some(creds),
+        Some(0),
+        None,
+        None,
+        false,
+        None,
+        None,
+    )
+    .expect("Failed to create ClobClient")
+}
+
+/// Same as `create_mock_client_with_api_key`, plus local builder credentials using `builder_key`
+pub fn create_mock_client_with_builder_api_key(host: String, builder_key: &str) -> ClobClient {
+    let wallet = PrivateKeySigner::random();
+
+    let creds = ApiKeyCreds {
+        key: "test-api-key".to_string(),
+        secret: "dGVzdF9zZWNyZXRfa2V5".to_string(),
+        passphrase: "test-passphrase".to_string(),
+    };
+
+    let builder_config = BuilderConfig::new(
+        None,
+        Some(BuilderApiKeyCreds {
+            key: builder_key.to_string(),
+            secret: "dGVzdF9idWlsZGVyX3NlY3JldA==".to_string(), // base64("test_builder_secret")
+            passphrase: "test-builder-passphrase".to_string(),
+        }),
+    )
+    .expect("Failed to create builder config");
+
+    ClobClient::new(
+        host.clone(),
+        host,
+        Chain::Polygon,
+        Some(wallet),
+        Some(creds),
+        Some(0),
+        None,
+        None,
+        false,
+        Some(builder_config),
+        None,
+    )
+    .expect("Failed to create ClobClient")
+}
+
+/// Same as `create_mock_client_with_api_key`, plus a remote builder signer at `signer_url`
+pub fn create_mock_client_with_remote_builder(host: String, signer_url: String) -> ClobClient {
+    let creds = ApiKeyCreds {
+        key: "test-api-key".to_string(),
+        secret: "dGVzdF9zZWNyZXRfa2V5".to_string(),
+        passphrase: "test-passphrase".to_string(),
+    };
+
+    let builder_config = BuilderConfig::new(
+        Some(RemoteBuilderConfig {
+            url: signer_url,
+            token: None,
+        }),
+        None,
+    )
+    .expect("Failed to create builder config");
+
+    ClobClient::new(
+        host.clone(),
+        host,
+        Chain::Polygon,
+        Some(PrivateKeySigner::random()),
+        Some(creds),
+        Some(0),
+        None,
+        None,
+        false,
+        Some(builder_config),
+        None,
+    )
+    .expect("Failed to create ClobClient")
+}
+
+/// An `OpenOrder` as returned by `/data/order/{id}` and `/data/orders`, owned by the mock client
+pub fn open_order_json(id: &str, status: &str, size_matched: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "status": status,
+        "owner": "test-api-key",
+        "maker_address": "0x0000000000000000000000000000000000000001",
+        "market": "0xabc",
+        "asset_id": "1234",
+        "side": "BUY",
+        "original_size": "10",
+        "size_matched": size_matched,
+        "price": "0.5",
+        "associate_trades": [],
+        "outcome": "Yes",
+        "created_at": 1700000000,
+        "expiration": "0",
+        "order_type": "GTC"
+    })
+}
+
+/// A confirmed `Trade` as returned by `/data/trades`, owned by the mock client
+pub fn trade_json(
+    id: &str,
+    size: &str,
+    price: &str,
+    match_time: u64,
+    trader_side: &str,
+) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "taker_order_id": "0xorder",
+        "market": "0xabc",
+        "asset_id": "1234",
+        "side": "BUY",
+        "size": size,
+        "fee_rate_bps": "0",
+        "price": price,
+        "status": "CONFIRMED",
+        "match_time": match_time.to_string(),
+        "last_update": match_time.to_string(),
+        "outcome": "Yes",
+        "bucket_index": 0,
+        "owner": "test-api-key",
+        "maker_address": "0x0000000000000000000000000000000000000001",
+        "maker_orders": [],
+        "transaction_hash": "0xhash",
+        "trader_side": trader_side
+    })
+}
+
+/// A `POST /order` response for an accepted order
+pub fn accepted_order_response_json(order_id: &str, status: &str) -> serde_json::Value {
+    serde_json::json!({
+        "success": true,
+        "errorMsg": "",
+        "orderID": order_id,
+        "transactionsHashes": [],
+        "status": status
+    })
+}
+
+/// A `POST /order` response for an order the server refused with `error_msg`
+pub fn rejected_order_response_json(error_msg: &str) -> serde_json::Value {
+    serde_json::json!({
+        "success": false,
+        "errorMsg": error_msg,
+        "orderID": ""
+    })
+}
@@ -0,0 +1,109 @@
+use alloy_signer_local::PrivateKeySigner;
+use mockito::Matcher;
+use rs_clob_client::types::{Side, UserLimitOrder};
+use rs_clob_client::{ClobClient, ClobError};
+
+const BOOK_BODY: &str = r#"{"market":"0xabc","asset_id":"1234","timestamp":"0","bids":[],"asks":[],"min_order_size":"5","tick_size":"0.01","neg_risk":false,"hash":""}"#;
+
+fn order(token_id: &str, price: f64) -> UserLimitOrder {
+    UserLimitOrder {
+        token_id: token_id.to_string(),
+        price,
+        size: 10.0,
+        side: Side::Buy,
+        fee_rate_bps: None,
+        nonce: None,
+        expiration: None,
+        expiration_buffer: None,
+        taker: None,
+        order_type: None,
+    }
+}
+
+fn client(host: String) -> ClobClient {
+    ClobClient::builder()
+        .host(host.clone())
+        .gamma_host(host)
+        .wallet(PrivateKeySigner::random())
+        .build()
+        .expect("Failed to create client")
+}
+
+async fn mock_lookup(
+    server: &mut mockito::ServerGuard,
+    path: &str,
+    body: &str,
+    hits: usize,
+) -> mockito::Mock {
+    server
+        .mock("GET", path)
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_create_orders_looks_up_each_token_once() {
+    let mut server = mockito::Server::new_async().await;
+    let tick_mock = mock_lookup(
+        &mut server,
+        "/tick-size",
+        r#"{"minimum_tick_size":0.01}"#,
+        2,
+    )
+    .await;
+    let neg_risk_mock = mock_lookup(&mut server, "/neg-risk", r#"{"neg_risk":false}"#, 2).await;
+    let fee_mock = mock_lookup(&mut server, "/fee-rate", r#"{"base_fee":0}"#, 2).await;
+    let book_mock = mock_lookup(&mut server, "/book", BOOK_BODY, 2).await;
+
+    let orders = [order("111", 0.4), order("222", 0.6), order("111", 0.45)];
+    let signed = client(server.url())
+        .create_orders(&orders, None)
+        .await
+        .expect("Failed to create orders");
+
+    let token_ids: Vec<&str> = signed
+        .iter()
+        .map(|order| order["tokenId"].as_str().unwrap())
+        .collect();
+    assert_eq!(token_ids, vec!["111", "222", "111"]);
+
+    tick_mock.assert_async().await;
+    neg_risk_mock.assert_async().await;
+    fee_mock.assert_async().await;
+    book_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_orders_rejects_mismatched_fee_rate() {
+    let mut server = mockito::Server::new_async().await;
+    let _tick_mock = mock_lookup(
+        &mut server,
+        "/tick-size",
+        r#"{"minimum_tick_size":0.01}"#,
+        1,
+    )
+    .await;
+    let _neg_risk_mock = mock_lookup(&mut server, "/neg-risk", r#"{"neg_risk":false}"#, 1).await;
+    let _fee_mock = mock_lookup(&mut server, "/fee-rate", r#"{"base_fee":100}"#, 1).await;
+    let _book_mock = mock_lookup(&mut server, "/book", BOOK_BODY, 1).await;
+
+    let mut mismatched = order("111", 0.4);
+    mismatched.fee_rate_bps = Some(50);
+    let err = client(server.url())
+        .create_orders(&[order("111", 0.5), mismatched], None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        ClobError::InvalidFeeRate {
+            user_fee_rate: 50,
+            market_fee_rate: 100
+        }
+    ));
+}
@@ -0,0 +1,29 @@
+use rs_clob_client::types::{ApiKeyCreds, ApiKeyRaw};
+
+#[test]
+fn test_creds_debug_masks_secrets() {
+    let creds = ApiKeyCreds {
+        key: "test-key".to_string(),
+        secret: "c2VjcmV0LXZhbHVl".to_string(),
+        passphrase: "test-passphrase".to_string(),
+    };
+
+    let output = format!("{:?}", creds);
+    assert!(output.contains("test-key"));
+    assert!(!output.contains("c2VjcmV0LXZhbHVl"));
+    assert!(!output.contains("test-passphrase"));
+    assert!(output.contains("***"));
+}
+
+#[test]
+fn test_raw_creds_debug_masks_secrets() {
+    let raw = ApiKeyRaw {
+        api_key: "test-key".to_string(),
+        secret: "c2VjcmV0LXZhbHVl".to_string(),
+        passphrase: "test-passphrase".to_string(),
+    };
+
+    let output = format!("{:#?}", raw);
+    assert!(!output.contains("c2VjcmV0LXZhbHVl"));
+    assert!(!output.contains("test-passphrase"));
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use common::{accepted_order_response_json, create_mock_client_with_api_key};
+use mockito::Matcher;
+use rs_clob_client::types::{OrderType, PostOrdersArgs};
+
+#[tokio::test]
+async fn test_post_order_sends_defer_exec() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/order")
+        .match_body(Matcher::PartialJson(serde_json::json!({ "deferExec": true })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(accepted_order_response_json("0xabc", "delayed").to_string())
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    client
+        .post_order(serde_json::json!({ "salt": "1" }), OrderType::Gtc, true)
+        .await
+        .expect("Failed to post order");
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_post_orders_sends_defer_exec_per_order() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/orders")
+        .match_body(Matcher::Regex(
+            r#"^\[\{.*"deferExec":true.*\},\{.*"deferExec":true.*\}\]$"#.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let orders = vec![
+        PostOrdersArgs {
+            order: serde_json::json!({ "salt": "1" }),
+            order_type: OrderType::Gtc,
+        },
+        PostOrdersArgs {
+            order: serde_json::json!({ "salt": "2" }),
+            order_type: OrderType::Gtc,
+        },
+    ];
+    let client = create_mock_client_with_api_key(server.url());
+    client
+        .post_orders(orders, true)
+        .await
+        .expect("Failed to post orders");
+
+    mock.assert_async().await;
+}
@@ -27,7 +27,10 @@ async fn test_create_market_buy_order() {
                 fee_rate_bps: None,
                 nonce: None,
                 taker: None,
+                expiration: None,
                 order_type: Some(OrderType::Fok), // or FAK
+                max_price: None,
+                min_price: None,
             },
             None,
             OrderType::Fok, // or FAK
@@ -37,8 +40,8 @@ async fn test_create_market_buy_order() {
 
     // Assertions
     assert!(
-        response.is_object(),
-        "Create and post response should be a valid JSON object"
+        response.order_id.is_some(),
+        "Create and post response should carry the order ID"
     );
 
     println!("Create and Post Response: {:#?}", response);
@@ -64,7 +67,10 @@ async fn test_create_market_sell_order() {
                 fee_rate_bps: None,
                 nonce: None,
                 taker: None,
+                expiration: None,
                 order_type: None,
+                max_price: None,
+                min_price: None,
             },
             None,
             OrderType::Fok, // or FAK
@@ -74,8 +80,8 @@ async fn test_create_market_sell_order() {
 
     // Assertions
     assert!(
-        response.is_object(),
-        "Create and post response should be a valid JSON object"
+        response.order_id.is_some(),
+        "Create and post response should carry the order ID"
     );
 
     println!("Create and Post Response: {:#?}", response);
@@ -101,7 +107,9 @@ async fn test_create_limit_buy_order() {
                 fee_rate_bps: None,
                 nonce: None,
                 expiration: None,
+                expiration_buffer: None,
                 taker: None,
+                order_type: None,
             },
             None,
             OrderType::Gtc,
@@ -111,8 +119,8 @@ async fn test_create_limit_buy_order() {
 
     // Assertions
     assert!(
-        response.is_object(),
-        "Create and post response should be a valid JSON object"
+        response.order_id.is_some(),
+        "Create and post response should carry the order ID"
     );
 
     println!("Create and Post Response: {:#?}", response); // 0xf58d1851dbd249d6d26f60f64f30a5cfa58e80950a4a24e14398348a91f6cbf6
@@ -138,7 +146,9 @@ async fn test_create_limit_sell_order() {
                 fee_rate_bps: None,
                 nonce: None,
                 expiration: None,
+                expiration_buffer: None,
                 taker: None,
+                order_type: None,
             },
             None,
             OrderType::Gtc,
@@ -148,8 +158,8 @@ async fn test_create_limit_sell_order() {
 
     // Assertions
     assert!(
-        response.is_object(),
-        "Create and post response should be a valid JSON object"
+        response.order_id.is_some(),
+        "Create and post response should carry the order ID"
     );
 
     println!("Create and Post Response: {:#?}", response);
@@ -0,0 +1,107 @@
+use alloy_signer_local::PrivateKeySigner;
+use mockito::Matcher;
+use rs_clob_client::{
+    types::{Chain, CreateOrderOptions, Side, TickSize, UserLimitOrder},
+    ClobClient, ClobError,
+};
+
+const FUNDER: &str = "0x1111111111111111111111111111111111111111";
+
+fn client_with(
+    host: String,
+    signature_type: u8,
+    funder: Option<&str>,
+) -> Result<ClobClient, ClobError> {
+    ClobClient::new(
+        host.clone(),
+        host,
+        Chain::Polygon,
+        Some(PrivateKeySigner::random()),
+        None,
+        Some(signature_type),
+        funder.map(str::to_string),
+        None,
+        false,
+        None,
+        None,
+    )
+}
+
+#[test]
+fn test_proxy_signature_types_require_funder() {
+    for signature_type in [1, 2] {
+        let result = client_with("http://127.0.0.1:1".to_string(), signature_type, None);
+        assert!(
+            matches!(result, Err(ClobError::ConfigError(_))),
+            "signature type {} without funder",
+            signature_type
+        );
+    }
+}
+
+#[test]
+fn test_eoa_does_not_require_funder() {
+    assert!(client_with("http://127.0.0.1:1".to_string(), 0, None).is_ok());
+}
+
+#[tokio::test]
+async fn test_funder_is_order_maker() {
+    let mut server = mockito::Server::new_async().await;
+    let _fee_mock = server
+        .mock("GET", "/fee-rate")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"base_fee":0}"#)
+        .create_async()
+        .await;
+    let _book_mock = server
+        .mock("GET", "/book")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"market":"0xabc","asset_id":"1234","timestamp":"0","bids":[],"asks":[],"min_order_size":"5","tick_size":"0.01","neg_risk":false,"hash":""}"#)
+        .create_async()
+        .await;
+
+    let wallet = PrivateKeySigner::random();
+    let client = ClobClient::builder()
+        .host(server.url())
+        .gamma_host(server.url())
+        .wallet(wallet.clone())
+        .signature_type(2)
+        .funder_address(FUNDER)
+        .build()
+        .expect("Failed to create client with funder");
+
+    let order = UserLimitOrder {
+        token_id: "1234".to_string(),
+        price: 0.5,
+        size: 10.0,
+        side: Side::Buy,
+        fee_rate_bps: None,
+        nonce: None,
+        expiration: None,
+        expiration_buffer: None,
+        taker: None,
+        order_type: None,
+    };
+    let options = CreateOrderOptions {
+        tick_size: TickSize::ZeroPointZeroOne,
+        neg_risk: Some(false),
+    };
+
+    let signed = client
+        .create_limit_order(&order, Some(options))
+        .await
+        .expect("Failed to create order");
+
+    assert!(signed["maker"]
+        .as_str()
+        .unwrap()
+        .eq_ignore_ascii_case(FUNDER));
+    assert!(signed["signer"]
+        .as_str()
+        .unwrap()
+        .eq_ignore_ascii_case(&wallet.address().to_string()));
+}
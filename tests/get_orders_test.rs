@@ -0,0 +1,79 @@
+mod common;
+
+use common::{create_mock_client_with_api_key, open_order_json};
+use mockito::Matcher;
+use rs_clob_client::types::OrderStatus;
+
+#[tokio::test]
+async fn test_get_orders_omits_missing_ids() {
+    let mut server = mockito::Server::new_async().await;
+
+    let ids = vec![
+        "0xaaa".to_string(),
+        "0xmissing".to_string(),
+        "0xccc".to_string(),
+    ];
+
+    let mut mocks = Vec::new();
+    for id in ["0xaaa", "0xccc"] {
+        mocks.push(
+            server
+                .mock("GET", format!("/data/order/{}", id).as_str())
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(open_order_json(id, "LIVE", "0").to_string())
+                .create_async()
+                .await,
+        );
+    }
+    mocks.push(
+        server
+            .mock("GET", "/data/order/0xmissing")
+            .with_status(404)
+            .with_body(r#"{"error":"order not found"}"#)
+            .create_async()
+            .await,
+    );
+
+    let client = create_mock_client_with_api_key(server.url());
+    let orders = client
+        .get_orders(&ids)
+        .await
+        .expect("Failed to fetch orders");
+
+    for mock in &mocks {
+        mock.assert_async().await;
+    }
+
+    let returned_ids: Vec<&str> = orders.iter().map(|o| o.id.as_str()).collect();
+    assert_eq!(returned_ids, vec!["0xaaa", "0xccc"]);
+}
+
+#[tokio::test]
+async fn test_get_open_order_sends_l2_headers() {
+    let mut server = mockito::Server::new_async().await;
+    let client = create_mock_client_with_api_key(server.url());
+    let address = format!("{:#x}", client.address().unwrap());
+
+    let mock = server
+        .mock("GET", "/data/order/0xaaa")
+        .match_header("POLY_ADDRESS", Matcher::Exact(address))
+        .match_header("POLY_API_KEY", "test-api-key")
+        .match_header("POLY_PASSPHRASE", "test-passphrase")
+        .match_header("POLY_SIGNATURE", Matcher::Regex(".+".to_string()))
+        .match_header("POLY_TIMESTAMP", Matcher::Regex("^[0-9]+$".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(open_order_json("0xaaa", "LIVE", "0").to_string())
+        .create_async()
+        .await;
+
+    let order = client
+        .get_open_order("0xaaa")
+        .await
+        .expect("Failed to fetch order");
+
+    mock.assert_async().await;
+    assert_eq!(order.id, "0xaaa");
+    assert_eq!(order.status, OrderStatus::Live);
+}
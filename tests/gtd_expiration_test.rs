@@ -0,0 +1,99 @@
+mod common;
+
+use common::create_mock_client_with_api_key;
+use rs_clob_client::types::{
+    CreateOrderOptions, OrderType, Side, TickSize, UserLimitOrder, UserMarketOrder,
+};
+use rs_clob_client::ClobError;
+
+fn order(expiration: Option<u64>) -> UserLimitOrder {
+    UserLimitOrder {
+        token_id: "1234".to_string(),
+        price: 0.5,
+        size: 10.0,
+        side: Side::Buy,
+        fee_rate_bps: Some(0),
+        nonce: None,
+        expiration,
+        expiration_buffer: None,
+        taker: None,
+        order_type: None,
+    }
+}
+
+fn options() -> Option<CreateOrderOptions> {
+    Some(CreateOrderOptions {
+        tick_size: TickSize::ZeroPointZeroOne,
+        neg_risk: Some(false),
+    })
+}
+
+#[tokio::test]
+async fn test_gtd_order_without_expiration_is_rejected() {
+    // No mocks: validation must fail before any request is sent
+    let server = mockito::Server::new_async().await;
+    let client = create_mock_client_with_api_key(server.url());
+
+    let err = client
+        .create_and_post_limit_order(&order(None), options(), OrderType::Gtd)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ClobError::MissingExpiration));
+}
+
+#[tokio::test]
+async fn test_expiration_rejected_for_non_gtd_order() {
+    let server = mockito::Server::new_async().await;
+    let client = create_mock_client_with_api_key(server.url());
+
+    let err = client
+        .create_and_post_limit_order(&order(Some(1_900_000_000)), options(), OrderType::Gtc)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ClobError::Other(_)));
+}
+
+#[tokio::test]
+async fn test_create_limit_order_checks_order_type() {
+    let server = mockito::Server::new_async().await;
+    let client = create_mock_client_with_api_key(server.url());
+
+    let gtd = UserLimitOrder {
+        order_type: Some(OrderType::Gtd),
+        ..order(None)
+    };
+    let err = client
+        .create_limit_order(&gtd, options())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ClobError::MissingExpiration));
+}
+
+#[tokio::test]
+async fn test_create_market_order_rejects_expiration_for_fok() {
+    let server = mockito::Server::new_async().await;
+    let client = create_mock_client_with_api_key(server.url());
+
+    let order = UserMarketOrder {
+        token_id: "1234".to_string(),
+        price: Some(0.5),
+        amount: 10.0,
+        side: Side::Buy,
+        fee_rate_bps: Some(0),
+        nonce: None,
+        taker: None,
+        expiration: Some(1_900_000_000),
+        order_type: Some(OrderType::Fok),
+        max_price: None,
+        min_price: None,
+    };
+    let err = client
+        .create_market_order(&order, options())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ClobError::Other(_)));
+}
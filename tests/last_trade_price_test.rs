@@ -0,0 +1,35 @@
+use mockito::Matcher;
+use rs_clob_client::types::{Chain, LastTradePrice, Side};
+use rs_clob_client::ClobClient;
+
+#[test]
+fn test_last_trade_price_deserializes() {
+    let last: LastTradePrice = serde_json::from_str(r#"{"price":"0.53","side":"BUY"}"#).unwrap();
+
+    assert_eq!(last.price, "0.53");
+    assert_eq!(last.side, Some(Side::Buy));
+    assert_eq!(last.price_f64().unwrap(), 0.53);
+}
+
+#[tokio::test]
+async fn test_get_last_trade_price_f64() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/last-trade-price")
+        .match_query(Matcher::UrlEncoded("token_id".into(), "1234".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"price":"0.47","side":"SELL"}"#)
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+    let price = client
+        .get_last_trade_price_f64("1234")
+        .await
+        .expect("Failed to fetch last trade price");
+
+    mock.assert_async().await;
+    assert_eq!(price, 0.47);
+}
@@ -0,0 +1,42 @@
+use mockito::Matcher;
+use rs_clob_client::{types::Chain, ClobClient};
+
+const CONDITION_ID: &str = "0x5eed579ff6763914d78a966c83473ba2485ac8910d0a0914eef6d9fe2b3c48a6";
+
+#[tokio::test]
+async fn test_check_market_consistency_flags_deviation() {
+    let mut server = mockito::Server::new_async().await;
+    let market_mock = server
+        .mock("GET", "/markets")
+        .match_query(Matcher::UrlEncoded("condition_id".into(), CONDITION_ID.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"[{{ "id": "1", "conditionId": "{CONDITION_ID}", "clobTokenIds": "[\"111\", \"222\"]" }}]"#
+        ))
+        .create_async()
+        .await;
+    let midpoints_mock = server
+        .mock("POST", "/midpoints")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"111": "0.55", "222": "0.43"}"#)
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+
+    let report = client
+        .check_market_consistency(CONDITION_ID)
+        .await
+        .expect("Failed to check market consistency");
+
+    market_mock.assert_async().await;
+    midpoints_mock.assert_async().await;
+    assert_eq!(report.token_ids, vec!["111", "222"]);
+    assert_eq!(report.midpoints, vec![0.55, 0.43]);
+    assert!((report.sum - 0.98).abs() < 1e-9);
+    assert!((report.deviation - 0.02).abs() < 1e-9);
+    assert!(report.flagged);
+}
@@ -0,0 +1,71 @@
+use mockito::Matcher;
+use rs_clob_client::types::{Chain, MarketConstraints, OrderBookSummary, TickSize};
+use rs_clob_client::ClobClient;
+
+const TOKEN_ID: &str = "1234567890";
+
+const BOOK_BODY: &str = r#"{
+    "market": "0xabc",
+    "asset_id": "1234567890",
+    "timestamp": "1700000000000",
+    "bids": [{ "price": "0.480", "size": "50" }],
+    "asks": [{ "price": "0.520", "size": "40" }],
+    "min_order_size": "5",
+    "tick_size": "0.001",
+    "neg_risk": true,
+    "hash": "deadbeef"
+}"#;
+
+#[test]
+fn test_constraints_from_sample_book() {
+    let book: OrderBookSummary = serde_json::from_str(BOOK_BODY).expect("Failed to parse book");
+
+    assert_eq!(
+        book.constraints().unwrap(),
+        MarketConstraints {
+            tick_size: TickSize::ZeroPointZeroZeroOne,
+            min_order_size: 5.0,
+            neg_risk: true,
+        }
+    );
+}
+
+#[test]
+fn test_constraints_reject_unknown_tick_size() {
+    let mut book: OrderBookSummary = serde_json::from_str(BOOK_BODY).expect("Failed to parse book");
+    book.tick_size = "0.05".to_string();
+    assert!(book.constraints().is_err());
+
+    book.tick_size = "0.010".to_string();
+    assert_eq!(
+        book.constraints().unwrap().tick_size,
+        TickSize::ZeroPointZeroOne
+    );
+}
+
+#[tokio::test]
+async fn test_get_market_constraints_uses_one_book_fetch() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/book")
+        .match_query(Matcher::UrlEncoded("token_id".into(), TOKEN_ID.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(BOOK_BODY)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+
+    let constraints = client
+        .get_market_constraints(TOKEN_ID)
+        .await
+        .expect("Failed to fetch constraints");
+
+    mock.assert_async().await;
+    assert_eq!(constraints.tick_size, TickSize::ZeroPointZeroZeroOne);
+    assert_eq!(constraints.min_order_size, 5.0);
+    assert!(constraints.neg_risk);
+}
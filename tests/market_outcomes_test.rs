@@ -0,0 +1,66 @@
+use rs_clob_client::types::Market;
+
+const MARKET_BODY: &str = r#"{
+    "id": "253591",
+    "question": "Will the Fed cut rates in December?",
+    "conditionId": "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1",
+    "slug": "will-the-fed-cut-rates-in-december",
+    "outcomes": "[\"Yes\", \"No\"]",
+    "outcomePrices": "[\"0.0045\", \"0.9955\"]",
+    "volume": "1284532.418",
+    "active": true,
+    "closed": false,
+    "marketMakerAddress": "",
+    "startDateIso": "2024-10-01",
+    "endDateIso": "2024-12-18",
+    "clobTokenIds": "[\"71321045679252212594626385532706912750332728571942532289631379312455583992563\", \"52114319501245915516055106046884209969926127482827954674443846427813813222426\"]",
+    "acceptingOrders": true,
+    "enableOrderBook": true,
+    "negRisk": false
+}"#;
+
+fn market(body: &str) -> Market {
+    serde_json::from_str(body).expect("Failed to parse market")
+}
+
+#[test]
+fn test_market_parses_encoded_lists() {
+    let market = market(MARKET_BODY);
+
+    assert_eq!(market.outcomes_parsed().unwrap(), vec!["Yes", "No"]);
+    assert_eq!(
+        market.outcome_prices_parsed().unwrap(),
+        vec![0.0045, 0.9955]
+    );
+    assert_eq!(
+        market.clob_token_ids_parsed().unwrap(),
+        vec![
+            "71321045679252212594626385532706912750332728571942532289631379312455583992563",
+            "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+        ]
+    );
+}
+
+#[test]
+fn test_market_missing_lists_are_empty() {
+    let market = market(r#"{ "id": "1" }"#);
+
+    assert!(market.outcomes_parsed().unwrap().is_empty());
+    assert!(market.outcome_prices_parsed().unwrap().is_empty());
+    assert!(market.clob_token_ids_parsed().unwrap().is_empty());
+}
+
+#[test]
+fn test_market_outcome_prices_accept_numbers() {
+    let market = market(r#"{ "id": "1", "outcomePrices": "[0.25, 0.75]" }"#);
+
+    assert_eq!(market.outcome_prices_parsed().unwrap(), vec![0.25, 0.75]);
+}
+
+#[test]
+fn test_market_malformed_lists_error() {
+    let market = market(r#"{ "id": "1", "outcomes": "Yes,No", "outcomePrices": "[\"cheap\"]" }"#);
+
+    assert!(market.outcomes_parsed().is_err());
+    assert!(market.outcome_prices_parsed().is_err());
+}
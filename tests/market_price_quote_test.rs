@@ -0,0 +1,237 @@
+use mockito::Matcher;
+use rs_clob_client::{
+    types::{Chain, CreateOrderOptions, OrderType, Side, TickSize, UserMarketOrder},
+    ClobClient, ClobError,
+};
+
+mod common;
+
+const TOKEN_ID: &str = "1234567890";
+
+// Neg-risk book: asks sorted descending (best ask last), as returned by the API
+const NEG_RISK_BOOK_BODY: &str = r#"{
+    "market": "0xabc",
+    "asset_id": "1234567890",
+    "timestamp": "1700000000000",
+    "bids": [{ "price": "0.30", "size": "100" }, { "price": "0.32", "size": "100" }],
+    "asks": [{ "price": "0.40", "size": "100" }, { "price": "0.36", "size": "100" }, { "price": "0.35", "size": "20" }],
+    "min_order_size": "5",
+    "tick_size": "0.01",
+    "neg_risk": true,
+    "hash": "deadbeef"
+}"#;
+
+#[tokio::test]
+async fn test_quote_market_price_neg_risk_book() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/book")
+        .match_query(Matcher::UrlEncoded("token_id".into(), TOKEN_ID.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(NEG_RISK_BOOK_BODY)
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+
+    // 20 @ 0.35 = 7 USDC, then 100 @ 0.36 = 36 USDC: 30 USDC fills at 0.36
+    let quote = client
+        .quote_market_price(TOKEN_ID, Side::Buy, 30.0, OrderType::Fok)
+        .await
+        .expect("Failed to quote market price");
+
+    mock.assert_async().await;
+    assert_eq!(quote.price, 0.36);
+    assert!(quote.neg_risk);
+}
+
+#[tokio::test]
+async fn test_market_order_on_empty_book_is_not_signed() {
+    let mut server = mockito::Server::new_async().await;
+    let _fee_mock = server
+        .mock("GET", "/fee-rate")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"base_fee":0}"#)
+        .create_async()
+        .await;
+    let _book_mock = server
+        .mock("GET", "/book")
+        .match_query(Matcher::UrlEncoded("token_id".into(), TOKEN_ID.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"market":"0xabc","asset_id":"1234567890","timestamp":"1700000000000",
+            "bids":[],"asks":[],"min_order_size":"5","tick_size":"0.01",
+            "neg_risk":false,"hash":""}"#,
+        )
+        .create_async()
+        .await;
+
+    let client = common::create_mock_client_with_api_key(server.url());
+    let order = UserMarketOrder {
+        token_id: TOKEN_ID.to_string(),
+        price: None,
+        amount: 10.0,
+        side: Side::Buy,
+        fee_rate_bps: None,
+        nonce: None,
+        taker: None,
+        expiration: None,
+        order_type: Some(OrderType::Fok),
+        max_price: None,
+        min_price: None,
+    };
+    let options = CreateOrderOptions {
+        tick_size: TickSize::ZeroPointZeroOne,
+        neg_risk: Some(false),
+    };
+
+    // An empty book must fail rather than sign at a placeholder price
+    let result = client.create_market_order(&order, Some(options)).await;
+    assert!(matches!(result, Err(ClobError::NoOrderbook)));
+}
+
+#[tokio::test]
+async fn test_fok_quote_without_enough_depth() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/book")
+        .match_query(Matcher::UrlEncoded("token_id".into(), TOKEN_ID.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(NEG_RISK_BOOK_BODY)
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+
+    // Asks hold 7 + 36 + 40 = 83 USDC
+    let result = client
+        .calculate_market_price(TOKEN_ID, Side::Buy, 100.0, OrderType::Fok)
+        .await;
+    assert!(matches!(
+        result,
+        Err(ClobError::InsufficientLiquidity { requested, .. }) if requested == 100.0
+    ));
+}
+
+#[tokio::test]
+async fn test_preview_market_order_reports_partial_fak_fill() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/book")
+        .match_query(Matcher::UrlEncoded("token_id".into(), TOKEN_ID.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(NEG_RISK_BOOK_BODY)
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+
+    // Bids hold 100 @ 0.32 and 100 @ 0.30
+    let estimate = client
+        .preview_market_order(TOKEN_ID, Side::Sell, 250.0, OrderType::Fak)
+        .await
+        .expect("Failed to preview market order");
+
+    assert!(!estimate.fully_filled);
+    assert_eq!(estimate.fillable_amount, 200.0);
+    assert_eq!(estimate.worst_price, 0.30);
+    assert!((estimate.avg_price - 0.31).abs() < 1e-9);
+}
+
+async fn bounded_market_order_client(server: &mut mockito::ServerGuard) -> ClobClient {
+    server
+        .mock("GET", "/fee-rate")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"base_fee":0}"#)
+        .create_async()
+        .await;
+    server
+        .mock("GET", "/book")
+        .match_query(Matcher::UrlEncoded("token_id".into(), TOKEN_ID.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(NEG_RISK_BOOK_BODY)
+        .create_async()
+        .await;
+    common::create_mock_client_with_api_key(server.url())
+}
+
+fn bounded_market_order(
+    side: Side,
+    amount: f64,
+    max_price: Option<f64>,
+    min_price: Option<f64>,
+) -> UserMarketOrder {
+    UserMarketOrder {
+        token_id: TOKEN_ID.to_string(),
+        price: None,
+        amount,
+        side,
+        fee_rate_bps: None,
+        nonce: None,
+        taker: None,
+        expiration: None,
+        order_type: Some(OrderType::Fok),
+        max_price,
+        min_price,
+    }
+}
+
+fn neg_risk_options() -> Option<CreateOrderOptions> {
+    Some(CreateOrderOptions {
+        tick_size: TickSize::ZeroPointZeroOne,
+        neg_risk: Some(true),
+    })
+}
+
+#[tokio::test]
+async fn test_market_order_within_price_bound_is_signed() {
+    let mut server = mockito::Server::new_async().await;
+    let client = bounded_market_order_client(&mut server).await;
+
+    // 30 USDC fills at 0.36
+    let order = bounded_market_order(Side::Buy, 30.0, Some(0.37), None);
+    let signed = client
+        .create_market_order(&order, neg_risk_options())
+        .await
+        .expect("Failed to create market order");
+    assert_eq!(signed["tokenId"], TOKEN_ID);
+
+    // 150 shares fill down to 0.30
+    let order = bounded_market_order(Side::Sell, 150.0, None, Some(0.30));
+    client
+        .create_market_order(&order, neg_risk_options())
+        .await
+        .expect("Failed to create market order");
+}
+
+#[tokio::test]
+async fn test_market_order_beyond_price_bound_is_rejected() {
+    let mut server = mockito::Server::new_async().await;
+    let client = bounded_market_order_client(&mut server).await;
+
+    let order = bounded_market_order(Side::Buy, 30.0, Some(0.35), None);
+    let result = client.create_market_order(&order, neg_risk_options()).await;
+    assert!(matches!(
+        result,
+        Err(ClobError::SlippageExceeded { limit, computed }) if limit == 0.35 && computed == 0.36
+    ));
+
+    let order = bounded_market_order(Side::Sell, 150.0, None, Some(0.31));
+    let result = client.create_market_order(&order, neg_risk_options()).await;
+    assert!(matches!(
+        result,
+        Err(ClobError::SlippageExceeded { limit, computed }) if limit == 0.31 && computed == 0.30
+    ));
+}
@@ -0,0 +1,73 @@
+use mockito::Matcher;
+use rs_clob_client::{types::Chain, ClobClient};
+
+const CONDITION_ID: &str = "0x5eed579ff6763914d78a966c83473ba2485ac8910d0a0914eef6d9fe2b3c48a6";
+
+fn market_reward(configs: &str) -> String {
+    format!(
+        r#"{{
+            "condition_id": "{CONDITION_ID}",
+            "question": "Will it rain?",
+            "market_slug": "will-it-rain",
+            "event_slug": "rain",
+            "image": "",
+            "rewards_max_spread": 3.5,
+            "rewards_min_size": 50,
+            "tokens": [],
+            "rewards_config": [{configs}]
+        }}"#
+    )
+}
+
+#[tokio::test]
+async fn test_get_market_reward_config_selects_latest() {
+    let mut server = mockito::Server::new_async().await;
+    let endpoint = format!("/rewards/markets/{}", CONDITION_ID);
+
+    let first_page = format!(
+        r#"{{ "data": [{}], "next_cursor": "MTAw" }}"#,
+        market_reward(
+            r#"{ "asset_address": "0xusdc", "rewards_daily_rate": 10, "start_date": "2024-05-01", "end_date": "2024-05-31" },
+               { "asset_address": "0xusdc", "rewards_daily_rate": 25, "start_date": "2024-07-01", "end_date": "2500-12-31" }"#
+        )
+    );
+    let second_page = format!(
+        r#"{{ "data": [{}], "next_cursor": "LTE=" }}"#,
+        market_reward(
+            r#"{ "asset_address": "0xusdc", "rewards_daily_rate": 15, "start_date": "2024-06-01", "end_date": "2024-06-30" }"#
+        )
+    );
+
+    let first = server
+        .mock("GET", endpoint.as_str())
+        .match_query(Matcher::UrlEncoded("next_cursor".into(), "MA==".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(first_page)
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", endpoint.as_str())
+        .match_query(Matcher::UrlEncoded("next_cursor".into(), "MTAw".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(second_page)
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+
+    let config = client
+        .get_market_reward_config(CONDITION_ID)
+        .await
+        .expect("Failed to fetch market reward config");
+
+    first.assert_async().await;
+    second.assert_async().await;
+    assert_eq!(config.condition_id, CONDITION_ID);
+    assert_eq!(config.rewards_daily_rate, 25.0);
+    assert_eq!(config.start_date.as_deref(), Some("2024-07-01"));
+    assert_eq!(config.rewards_max_spread, 3.5);
+    assert_eq!(config.rewards_min_size, 50.0);
+}
@@ -14,6 +14,7 @@ async fn test_get_markets() {
         ascending: Some(false),
         condition_id: None,
         closed: Some(false),
+        tag_id: None,
     };
 
     // Get first page of markets
@@ -0,0 +1,160 @@
+use alloy_signer_local::PrivateKeySigner;
+use mockito::Matcher;
+use rs_clob_client::types::{
+    CreateOrderOptions, OrderType, Side, TickSize, UserLimitOrder, UserMarketOrder,
+};
+use rs_clob_client::{ClobClient, ClobError};
+
+const TOKEN_ID: &str = "1234";
+
+const BOOK_BODY: &str = r#"{
+    "market": "0xabc",
+    "asset_id": "1234",
+    "timestamp": "1700000000000",
+    "bids": [{ "price": "0.40", "size": "100" }],
+    "asks": [{ "price": "0.50", "size": "100" }],
+    "min_order_size": "5",
+    "tick_size": "0.01",
+    "neg_risk": false,
+    "hash": "deadbeef"
+}"#;
+
+fn options() -> Option<CreateOrderOptions> {
+    Some(CreateOrderOptions {
+        tick_size: TickSize::ZeroPointZeroOne,
+        neg_risk: Some(false),
+    })
+}
+
+fn limit_order(size: f64) -> UserLimitOrder {
+    UserLimitOrder {
+        token_id: TOKEN_ID.to_string(),
+        price: 0.45,
+        size,
+        side: Side::Buy,
+        fee_rate_bps: None,
+        nonce: None,
+        expiration: None,
+        expiration_buffer: None,
+        taker: None,
+        order_type: None,
+    }
+}
+
+fn market_order(side: Side, amount: f64) -> UserMarketOrder {
+    UserMarketOrder {
+        token_id: TOKEN_ID.to_string(),
+        price: None,
+        amount,
+        side,
+        fee_rate_bps: None,
+        nonce: None,
+        taker: None,
+        expiration: None,
+        order_type: Some(OrderType::Fok),
+        max_price: None,
+        min_price: None,
+    }
+}
+
+/// Client against a server serving the book (min size 5, fetched `book_hits` times) and a
+/// zero fee rate
+async fn client(
+    server: &mut mockito::ServerGuard,
+    book_hits: usize,
+) -> (ClobClient, mockito::Mock) {
+    server
+        .mock("GET", "/fee-rate")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"base_fee":0}"#)
+        .create_async()
+        .await;
+    let book_mock = server
+        .mock("GET", "/book")
+        .match_query(Matcher::UrlEncoded("token_id".into(), TOKEN_ID.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(BOOK_BODY)
+        .expect(book_hits)
+        .create_async()
+        .await;
+
+    let client = ClobClient::builder()
+        .host(server.url())
+        .gamma_host(server.url())
+        .wallet(PrivateKeySigner::random())
+        .build()
+        .expect("Failed to create client");
+    (client, book_mock)
+}
+
+#[tokio::test]
+async fn test_limit_order_at_and_above_minimum_is_signed() {
+    let mut server = mockito::Server::new_async().await;
+    let (client, _book_mock) = client(&mut server, 1).await;
+
+    for size in [5.0, 5.01] {
+        client
+            .create_limit_order(&limit_order(size), options())
+            .await
+            .expect("Failed to create order");
+    }
+}
+
+#[tokio::test]
+async fn test_limit_order_below_minimum_is_rejected() {
+    let mut server = mockito::Server::new_async().await;
+    let (client, _book_mock) = client(&mut server, 1).await;
+
+    let result = client
+        .create_limit_order(&limit_order(4.99), options())
+        .await;
+    assert!(matches!(
+        result,
+        Err(ClobError::OrderSizeTooSmall { size, min_size }) if size == 4.99 && min_size == 5.0
+    ));
+}
+
+#[tokio::test]
+async fn test_min_order_size_is_cached() {
+    let mut server = mockito::Server::new_async().await;
+    let (client, book_mock) = client(&mut server, 1).await;
+
+    assert_eq!(client.get_min_order_size(TOKEN_ID).await.unwrap(), 5.0);
+    client
+        .create_limit_order(&limit_order(5.0), options())
+        .await
+        .expect("Failed to create order");
+
+    book_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_market_order_size_is_checked_in_shares() {
+    let mut server = mockito::Server::new_async().await;
+    // Each market order prices off the book; the minimum size is fetched once
+    let (client, book_mock) = client(&mut server, 4).await;
+
+    // Buys spend USDC: 2.5 USDC at the 0.50 ask is exactly 5 shares
+    client
+        .create_market_order(&market_order(Side::Buy, 2.5), options())
+        .await
+        .expect("Failed to create market order");
+    let result = client
+        .create_market_order(&market_order(Side::Buy, 2.0), options())
+        .await;
+    assert!(matches!(
+        result,
+        Err(ClobError::OrderSizeTooSmall { size, .. }) if size == 4.0
+    ));
+
+    // Sells are sized in shares
+    let result = client
+        .create_market_order(&market_order(Side::Sell, 4.0), options())
+        .await;
+    assert!(matches!(result, Err(ClobError::OrderSizeTooSmall { .. })));
+
+    book_mock.assert_async().await;
+}
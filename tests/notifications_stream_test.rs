@@ -0,0 +1,84 @@
+use futures::StreamExt;
+use mockito::Matcher;
+use std::time::Duration;
+
+mod common;
+
+fn notification(id: u64) -> String {
+    format!(
+        r#"{{"id":{},"type":1,"owner":"test-api-key","payload":{{"order_id":"0x{}"}}}}"#,
+        id, id
+    )
+}
+
+#[tokio::test]
+async fn test_notifications_stream_dedups_across_polls() {
+    let mut server = mockito::Server::new_async().await;
+    let first_poll = server
+        .mock("GET", "/notifications")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!("[{},{}]", notification(1), notification(2)))
+        .expect(1)
+        .create_async()
+        .await;
+    let second_poll = server
+        .mock("GET", "/notifications")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            "[{},{},{}]",
+            notification(1),
+            notification(2),
+            notification(3)
+        ))
+        .create_async()
+        .await;
+
+    let client = common::create_mock_client_with_api_key(server.url());
+    let ids: Vec<Option<u64>> = client
+        .notifications_stream(Duration::from_millis(10), false)
+        .take(3)
+        .map(|notification| notification.id)
+        .collect()
+        .await;
+
+    first_poll.assert_async().await;
+    second_poll.assert_async().await;
+    assert_eq!(ids, vec![Some(1), Some(2), Some(3)]);
+}
+
+#[tokio::test]
+async fn test_notifications_stream_auto_drops_and_recovers_from_errors() {
+    let mut server = mockito::Server::new_async().await;
+    let failing_poll = server
+        .mock("GET", "/notifications")
+        .with_status(500)
+        .expect(1)
+        .create_async()
+        .await;
+    let _poll = server
+        .mock("GET", "/notifications")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!("[{}]", notification(7)))
+        .create_async()
+        .await;
+    let drop = server
+        .mock("DELETE", "/notifications")
+        .match_query(Matcher::UrlEncoded("ids".into(), "7".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = common::create_mock_client_with_api_key(server.url());
+    let mut stream = Box::pin(client.notifications_stream(Duration::from_millis(10), true));
+    let notification = stream.next().await.unwrap();
+
+    failing_poll.assert_async().await;
+    drop.assert_async().await;
+    assert_eq!(notification.id, Some(7));
+}
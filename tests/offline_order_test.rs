@@ -0,0 +1,86 @@
+use alloy_signer_local::PrivateKeySigner;
+use mockito::Matcher;
+use rs_clob_client::types::{CreateOrderOptions, Side, TickSize, UserLimitOrder};
+use rs_clob_client::ClobClient;
+use serde_json::Value;
+
+fn order() -> UserLimitOrder {
+    UserLimitOrder {
+        token_id: "1234".to_string(),
+        price: 0.37,
+        size: 25.0,
+        side: Side::Sell,
+        fee_rate_bps: None,
+        nonce: Some(7),
+        expiration: None,
+        expiration_buffer: None,
+        taker: None,
+        order_type: None,
+    }
+}
+
+fn client(host: String, wallet: PrivateKeySigner) -> ClobClient {
+    ClobClient::builder()
+        .host(host.clone())
+        .gamma_host(host)
+        .wallet(wallet)
+        .build()
+        .expect("Failed to create client")
+}
+
+/// The salt is drawn at random for every order, and the signature covers it
+fn without_salt(mut signed: Value) -> String {
+    let object = signed.as_object_mut().unwrap();
+    object.remove("salt");
+    object.remove("signature");
+    signed.to_string()
+}
+
+async fn mock_lookup(server: &mut mockito::ServerGuard, path: &str, body: &str) -> mockito::Mock {
+    server
+        .mock("GET", path)
+        .match_query(Matcher::UrlEncoded("token_id".into(), "1234".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_offline_order_matches_online_order() {
+    let wallet = PrivateKeySigner::random();
+
+    let mut server = mockito::Server::new_async().await;
+    let tick_mock = mock_lookup(&mut server, "/tick-size", r#"{"minimum_tick_size":0.01}"#).await;
+    let neg_risk_mock = mock_lookup(&mut server, "/neg-risk", r#"{"neg_risk":true}"#).await;
+    let fee_mock = mock_lookup(&mut server, "/fee-rate", r#"{"base_fee":100}"#).await;
+    let book_mock = mock_lookup(&mut server, "/book", r#"{"market":"0xabc","asset_id":"1234","timestamp":"0","bids":[],"asks":[],"min_order_size":"5","tick_size":"0.01","neg_risk":false,"hash":""}"#).await;
+
+    let online = client(server.url(), wallet.clone())
+        .create_limit_order(&order(), None)
+        .await
+        .expect("Failed to create online order");
+
+    tick_mock.assert_async().await;
+    neg_risk_mock.assert_async().await;
+    fee_mock.assert_async().await;
+    book_mock.assert_async().await;
+
+    // Nothing listens here: any lookup would fail the offline path
+    let offline = client("http://127.0.0.1:1".to_string(), wallet)
+        .create_order_offline(
+            &order(),
+            CreateOrderOptions {
+                tick_size: TickSize::ZeroPointZeroOne,
+                neg_risk: Some(true),
+            },
+            100,
+        )
+        .await
+        .expect("Failed to create offline order");
+
+    assert_eq!(offline["feeRateBps"], "100");
+    assert_eq!(without_salt(offline), without_salt(online));
+}
@@ -0,0 +1,79 @@
+mod common;
+
+use common::{create_mock_client_with_api_key, open_order_json};
+use mockito::Matcher;
+use rs_clob_client::constants::{END_CURSOR, INITIAL_CURSOR};
+use rs_clob_client::types::OpenOrderParams;
+
+async fn mock_page(
+    server: &mut mockito::ServerGuard,
+    cursor: &str,
+    order_ids: &[&str],
+    next_cursor: &str,
+) -> mockito::Mock {
+    let data: Vec<_> = order_ids
+        .iter()
+        .map(|id| open_order_json(id, "LIVE", "0"))
+        .collect();
+    server
+        .mock("GET", "/data/orders")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("next_cursor".into(), cursor.into()),
+            Matcher::UrlEncoded("market".into(), "0xabc".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "data": data,
+                "next_cursor": next_cursor,
+                "limit": 2,
+                "count": order_ids.len(),
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await
+}
+
+fn market_filter() -> Option<OpenOrderParams> {
+    Some(OpenOrderParams {
+        market: Some("0xabc".to_string()),
+        ..Default::default()
+    })
+}
+
+#[tokio::test]
+async fn test_get_open_orders_follows_cursor() {
+    let mut server = mockito::Server::new_async().await;
+    let first = mock_page(&mut server, INITIAL_CURSOR, &["0x1", "0x2"], "Mg==").await;
+    let second = mock_page(&mut server, "Mg==", &["0x3"], END_CURSOR).await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let orders = client
+        .get_open_orders(market_filter())
+        .await
+        .expect("Failed to get open orders");
+
+    let ids: Vec<&str> = orders.iter().map(|order| order.id.as_str()).collect();
+    assert_eq!(ids, vec!["0x1", "0x2", "0x3"]);
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_open_orders_paginated_returns_one_page() {
+    let mut server = mockito::Server::new_async().await;
+    let first = mock_page(&mut server, INITIAL_CURSOR, &["0x1", "0x2"], "Mg==").await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let page = client
+        .get_open_orders_paginated(market_filter(), None)
+        .await
+        .expect("Failed to get open orders page");
+
+    assert_eq!(page.data.len(), 2);
+    assert_eq!(page.next_cursor, "Mg==");
+    first.assert_async().await;
+}
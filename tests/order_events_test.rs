@@ -0,0 +1,61 @@
+mod common;
+
+use common::{accepted_order_response_json, create_mock_client_with_api_key};
+use rs_clob_client::types::{OrderEventKind, OrderType};
+
+const ORDER_ID: &str = "0x2601867f24395c00f981dabedfd92ab86cec62a018b68eec6908f01ebbdd812c";
+
+#[tokio::test]
+async fn test_post_order_appends_event() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/order")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(accepted_order_response_json(ORDER_ID, "live").to_string())
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    assert!(client.recent_order_events().is_empty());
+
+    client
+        .post_order(serde_json::json!({ "salt": "1" }), OrderType::Gtc, false)
+        .await
+        .expect("Failed to post order");
+
+    mock.assert_async().await;
+    let events = client.recent_order_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, OrderEventKind::Post);
+    assert_eq!(events[0].order_ids, vec![ORDER_ID.to_string()]);
+    assert!(events[0].success);
+    assert!(events[0].error.is_none());
+}
+
+#[tokio::test]
+async fn test_order_events_capacity() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("DELETE", "/order")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"canceled":[],"not_canceled":{}}"#)
+        .expect(3)
+        .create_async()
+        .await;
+
+    let mut client = create_mock_client_with_api_key(server.url());
+    client.set_order_event_capacity(2);
+
+    for id in ["a", "b", "c"] {
+        client.cancel_order(id).await.expect("Failed to cancel order");
+    }
+
+    let ids: Vec<_> = client
+        .recent_order_events()
+        .into_iter()
+        .flat_map(|event| event.order_ids)
+        .collect();
+    assert_eq!(ids, vec!["b".to_string(), "c".to_string()]);
+}
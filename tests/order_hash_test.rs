@@ -0,0 +1,124 @@
+use alloy_primitives::{Address, U256};
+use alloy_signer_local::PrivateKeySigner;
+use alloy_sol_types::{eip712_domain, sol, SolStruct};
+use mockito::Matcher;
+use rs_clob_client::signing::eip712::order_hash;
+use rs_clob_client::types::{Side, UserLimitOrder};
+use rs_clob_client::ClobClient;
+use std::str::FromStr;
+
+const EXCHANGE: &str = "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E";
+
+fn order() -> UserLimitOrder {
+    UserLimitOrder {
+        token_id: "1234".to_string(),
+        price: 0.37,
+        size: 25.0,
+        side: Side::Sell,
+        fee_rate_bps: None,
+        nonce: None,
+        expiration: None,
+        expiration_buffer: None,
+        taker: None,
+        order_type: None,
+    }
+}
+
+async fn mock_lookup(server: &mut mockito::ServerGuard, path: &str, body: &str) -> mockito::Mock {
+    server
+        .mock("GET", path)
+        .match_query(Matcher::UrlEncoded("token_id".into(), "1234".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .create_async()
+        .await
+}
+
+sol! {
+    // `Order` as declared by the CTF exchange contract
+    struct Order {
+        uint256 salt;
+        address maker;
+        address signer;
+        address taker;
+        uint256 tokenId;
+        uint256 makerAmount;
+        uint256 takerAmount;
+        uint256 expiration;
+        uint256 nonce;
+        uint256 feeRateBps;
+        uint8 side;
+        uint8 signatureType;
+    }
+}
+
+#[test]
+fn test_order_hash_of_reference_order() {
+    // Typed-data hash of the same order built by alloy from the contract's struct and domain,
+    // independent of this crate's hand-rolled encoding and domain constants
+    let maker = Address::from_str("0x6e0c80c90ea6c15917308F820Eac91Ce2724B5b5").unwrap();
+    let exchange = Address::from_str(EXCHANGE).unwrap();
+    let token_id = "71321045679252212594626385532706912750332728571942532289631379312455583992563";
+    let order = serde_json::json!({
+        "salt": 479249096354_u64,
+        "maker": maker.to_string(),
+        "signer": maker.to_string(),
+        "taker": "0x0000000000000000000000000000000000000000",
+        "tokenId": token_id,
+        "makerAmount": "10000000",
+        "takerAmount": "5000000",
+        "expiration": "0",
+        "nonce": "0",
+        "feeRateBps": "0",
+        "side": "BUY",
+        "signatureType": 0
+    });
+
+    let expected = Order {
+        salt: U256::from(479249096354_u64),
+        maker,
+        signer: maker,
+        taker: Address::ZERO,
+        tokenId: U256::from_str(token_id).unwrap(),
+        makerAmount: U256::from(10_000_000),
+        takerAmount: U256::from(5_000_000),
+        expiration: U256::ZERO,
+        nonce: U256::ZERO,
+        feeRateBps: U256::ZERO,
+        side: 0,
+        signatureType: 0,
+    }
+    .eip712_signing_hash(&eip712_domain! {
+        name: "Polymarket CTF Exchange",
+        version: "1",
+        chain_id: 137,
+        verifying_contract: exchange,
+    });
+
+    assert_eq!(order_hash(&order, 137, exchange).unwrap(), expected);
+}
+
+#[tokio::test]
+async fn test_create_order_with_hash_returns_order_id() {
+    let mut server = mockito::Server::new_async().await;
+    let _tick_mock = mock_lookup(&mut server, "/tick-size", r#"{"minimum_tick_size":0.01}"#).await;
+    let _neg_risk_mock = mock_lookup(&mut server, "/neg-risk", r#"{"neg_risk":true}"#).await;
+    let _fee_mock = mock_lookup(&mut server, "/fee-rate", r#"{"base_fee":0}"#).await;
+    let _book_mock = mock_lookup(&mut server, "/book", r#"{"market":"0xabc","asset_id":"1234","timestamp":"0","bids":[],"asks":[],"min_order_size":"5","tick_size":"0.01","neg_risk":false,"hash":""}"#).await;
+
+    let client = ClobClient::builder()
+        .host(server.url())
+        .gamma_host(server.url())
+        .wallet(PrivateKeySigner::random())
+        .build()
+        .expect("Failed to create client");
+
+    let (signed, hash) = client.create_order_with_hash(&order(), None).await.unwrap();
+
+    assert!(hash.starts_with("0x"));
+    assert_eq!(hash.len(), 66);
+    // Neg-risk orders are hashed against the neg-risk exchange
+    assert_eq!(hash, client.order_hash(&signed, Some(true)).unwrap());
+    assert_ne!(hash, client.order_hash(&signed, Some(false)).unwrap());
+}
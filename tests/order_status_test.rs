@@ -0,0 +1,104 @@
+mod common;
+
+use common::{create_mock_client_with_api_key, open_order_json};
+
+const ORDER_ID: &str = "0x2601867f24395c00f981dabedfd92ab86cec62a018b68eec6908f01ebbdd812c";
+
+#[tokio::test]
+async fn test_is_order_open_by_status() {
+    let cases = [
+        ("LIVE", "0", true),
+        ("LIVE", "4", true), // partially filled
+        ("ORDER_STATUS_LIVE", "0", true),
+        ("MATCHED", "10", false),
+        ("CANCELED", "0", false),
+        ("ORDER_STATUS_CANCELED_MARKET_RESOLVED", "0", false),
+    ];
+
+    for (status, size_matched, expected) in cases {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", format!("/data/order/{}", ORDER_ID).as_str())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(open_order_json(ORDER_ID, status, size_matched).to_string())
+            .create_async()
+            .await;
+
+        let client = create_mock_client_with_api_key(server.url());
+        let is_open = client
+            .is_order_open(ORDER_ID)
+            .await
+            .expect("Failed to check order status");
+
+        mock.assert_async().await;
+        assert_eq!(is_open, expected, "status {}", status);
+    }
+}
+
+#[tokio::test]
+async fn test_is_order_open_not_found() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("GET", format!("/data/order/{}", ORDER_ID).as_str())
+        .with_status(404)
+        .with_body(r#"{"error":"order not found"}"#)
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let is_open = client
+        .is_order_open(ORDER_ID)
+        .await
+        .expect("404 should map to a closed order");
+
+    assert!(!is_open);
+}
+
+#[test]
+fn test_open_order_typed_fields() {
+    use rs_clob_client::types::{OpenOrder, OrderStatus, OrderType, Side};
+
+    let order: OpenOrder =
+        serde_json::from_value(open_order_json(ORDER_ID, "ORDER_STATUS_LIVE", "0")).unwrap();
+    assert_eq!(order.status, OrderStatus::Live);
+    assert_eq!(order.side, Some(Side::Buy));
+    assert_eq!(order.order_type, OrderType::Gtc);
+
+    // Unknown statuses are kept rather than failing deserialization
+    let order: OpenOrder =
+        serde_json::from_value(open_order_json(ORDER_ID, "PAUSED", "0")).unwrap();
+    assert_eq!(order.status, OrderStatus::Other("PAUSED".to_string()));
+    assert!(!order.is_open());
+
+    let mut body = open_order_json(ORDER_ID, "CANCELED_MARKET_RESOLVED", "0");
+    body["side"] = "sell".into();
+    let order: OpenOrder = serde_json::from_value(body).unwrap();
+    assert!(order.is_cancelled());
+    assert_eq!(order.side, Some(Side::Sell));
+
+    // Unknown sides and order types don't fail deserialization either
+    let mut body = open_order_json(ORDER_ID, "LIVE", "0");
+    body["side"] = "SHORT".into();
+    body["order_type"] = "GTX".into();
+    let order: OpenOrder = serde_json::from_value(body).unwrap();
+    assert_eq!(order.side, None);
+    assert_eq!(order.order_type, OrderType::Other);
+}
+
+#[test]
+fn test_order_status_round_trip() {
+    use rs_clob_client::types::OrderStatus;
+
+    for status in [
+        "LIVE",
+        "MATCHED",
+        "CANCELED",
+        "DELAYED",
+        "UNMATCHED",
+        "NEW_STATUS",
+    ] {
+        let parsed: OrderStatus = serde_json::from_value(status.into()).unwrap();
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), status);
+    }
+}
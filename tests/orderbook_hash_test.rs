@@ -0,0 +1,80 @@
+use mockito::Matcher;
+use rs_clob_client::types::{Chain, OrderBookSummary};
+use rs_clob_client::{ClobClient, ClobError};
+use sha1::{Digest, Sha1};
+
+// `/book` body in the server's key order (`hash` after `timestamp`), with `hash` blanked
+const BOOK_BODY: &str = r#"{"market":"0xabc","asset_id":"1234","timestamp":"1700000000000","hash":"","bids":[{"price":"0.4","size":"10"},{"price":"0.45","size":"20"}],"asks":[{"price":"0.55","size":"15"},{"price":"0.5","size":"5"}],"min_order_size":"5","tick_size":"0.01","neg_risk":false}"#;
+
+/// SHA-1 of the body bytes, as the server computes it
+fn book_hash() -> String {
+    hex::encode(Sha1::digest(BOOK_BODY.as_bytes()))
+}
+
+fn book() -> OrderBookSummary {
+    let mut book: OrderBookSummary = serde_json::from_str(BOOK_BODY).unwrap();
+    book.hash = book_hash();
+    book
+}
+
+#[test]
+fn test_verify_hash_matches_hash_of_response_bytes() {
+    assert!(book().verify_hash());
+}
+
+#[test]
+fn test_verify_hash_rejects_tampered_book() {
+    let mut truncated = book();
+    truncated.asks.pop();
+    assert!(!truncated.verify_hash());
+
+    let mut resized = book();
+    resized.bids[0].size = "11".to_string();
+    assert!(!resized.verify_hash());
+}
+
+#[tokio::test]
+async fn test_get_verified_order_book_reports_mismatch() {
+    let mut server = mockito::Server::new_async().await;
+    let mut tampered = book();
+    tampered.bids.remove(0);
+    let _mock = server
+        .mock("GET", "/book")
+        .match_query(Matcher::UrlEncoded("token_id".into(), "1234".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&tampered).unwrap())
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+    let err = client.get_verified_order_book("1234").await.unwrap_err();
+
+    match err {
+        ClobError::OrderbookHashMismatch { expected, actual } => {
+            assert_eq!(expected, book_hash());
+            assert_ne!(actual, book_hash());
+        }
+        other => panic!("Expected OrderbookHashMismatch, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_get_verified_order_book_returns_intact_book() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/book")
+        .match_query(Matcher::UrlEncoded("token_id".into(), "1234".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&book()).unwrap())
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+    let orderbook = client.get_verified_order_book("1234").await.unwrap();
+
+    assert_eq!(orderbook.hash, book_hash());
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use common::create_mock_client_with_api_key;
+use mockito::Matcher;
+use rs_clob_client::types::OrdersScoringParams;
+
+#[tokio::test]
+async fn test_are_orders_scoring_marks_missing_ids_unknown() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/orders-scoring")
+        .match_query(Matcher::UrlEncoded(
+            "order_ids".into(),
+            "0xaaa,0xbbb,0xccc".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"0xaaa": true, "0xbbb": false}"#)
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let scoring = client
+        .are_orders_scoring(OrdersScoringParams {
+            order_ids: vec!["0xaaa".into(), "0xbbb".into(), "0xccc".into()],
+        })
+        .await
+        .expect("Failed to check orders scoring");
+
+    mock.assert_async().await;
+    assert_eq!(scoring.len(), 3);
+    assert_eq!(scoring["0xaaa"], Some(true));
+    assert_eq!(scoring["0xbbb"], Some(false));
+    assert_eq!(scoring["0xccc"], None);
+}
@@ -0,0 +1,32 @@
+use rs_clob_client::types::{Market, PaginationPayload};
+
+const PAGE_BODY: &str = r#"{
+    "limit": 2,
+    "count": 2,
+    "next_cursor": "Mg==",
+    "data": [
+        { "id": "716407", "question": "Will it rain?", "conditionId": "0xaaa", "active": true },
+        { "id": "716408", "slug": "will-it-snow", "closed": true }
+    ]
+}"#;
+
+#[test]
+fn test_page_deserializes_into_typed_markets() {
+    let page: PaginationPayload<Market> =
+        serde_json::from_str(PAGE_BODY).expect("Failed to parse page");
+
+    assert_eq!(page.limit, 2);
+    assert_eq!(page.count, 2);
+    assert_eq!(page.next_cursor, "Mg==");
+    assert_eq!(page.data[0].id, "716407");
+    assert_eq!(page.data[0].condition_id.as_deref(), Some("0xaaa"));
+    assert_eq!(page.data[1].slug.as_deref(), Some("will-it-snow"));
+}
+
+#[test]
+fn test_page_defaults_to_json_values() {
+    let page: PaginationPayload = serde_json::from_str(PAGE_BODY).expect("Failed to parse page");
+
+    assert_eq!(page.data.len(), 2);
+    assert_eq!(page.data[1]["slug"], "will-it-snow");
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use common::{
+    accepted_order_response_json, create_mock_client_with_api_key, rejected_order_response_json,
+};
+use rs_clob_client::types::OrderType;
+use rs_clob_client::ClobError;
+
+#[tokio::test]
+async fn test_post_order_returns_typed_response() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/order")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(accepted_order_response_json("0xabc", "matched").to_string())
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let response = client
+        .post_order(serde_json::json!({ "salt": "1" }), OrderType::Gtc, false)
+        .await
+        .expect("Failed to post order");
+
+    assert!(response.is_success());
+    assert_eq!(response.order_id.as_deref(), Some("0xabc"));
+    assert_eq!(response.status.as_deref(), Some("matched"));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_post_order_unsuccessful_is_api_error() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("POST", "/order")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(rejected_order_response_json("not enough balance / allowance").to_string())
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let err = client
+        .post_order(serde_json::json!({ "salt": "1" }), OrderType::Gtc, false)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ClobError::ApiError { status: 200, .. }));
+    assert_eq!(err.error_code(), Some("not enough balance / allowance"));
+
+    let events = client.recent_order_events();
+    assert_eq!(events.len(), 1);
+    assert!(!events[0].success);
+}
+
+#[tokio::test]
+async fn test_post_order_without_success_flag_is_accepted() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("POST", "/order")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"orderID":"0xabc","status":"live"}"#)
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let response = client
+        .post_order(serde_json::json!({ "salt": "1" }), OrderType::Gtc, false)
+        .await
+        .expect("A response without `success` should not be an error");
+
+    assert_eq!(response.success, None);
+    assert!(response.is_success());
+    assert_eq!(response.order_id.as_deref(), Some("0xabc"));
+}
@@ -0,0 +1,108 @@
+use rs_clob_client::types::{Chain, PriceHistoryInterval, PriceHistoryParams};
+use rs_clob_client::{ClobClient, ClobError};
+
+#[tokio::test]
+async fn test_get_prices_history_sorts_by_timestamp() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/prices-history")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"history":[
+                {"t":1700000120,"p":0.52},
+                {"t":1700000000,"p":0.5},
+                {"t":1700000180,"p":0.53},
+                {"t":1700000060,"p":0.51}
+            ]}"#,
+        )
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+    let history = client
+        .get_prices_history(PriceHistoryParams {
+            token_id: "1234".to_string(),
+            fidelity: 1,
+            interval: Some(PriceHistoryInterval::OneHour),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to fetch price history");
+
+    mock.assert_async().await;
+    let timestamps: Vec<u64> = history.history.iter().map(|item| item.t).collect();
+    assert_eq!(
+        timestamps,
+        vec![1700000000, 1700000060, 1700000120, 1700000180]
+    );
+    assert_eq!(history.first().unwrap().p, 0.5);
+    assert_eq!(history.last().unwrap().p, 0.53);
+}
+
+#[test]
+fn test_price_history_interval_tokens() {
+    let cases = [
+        (PriceHistoryInterval::Max, "max"),
+        (PriceHistoryInterval::OneMonth, "1m"),
+        (PriceHistoryInterval::OneWeek, "1w"),
+        (PriceHistoryInterval::OneDay, "1d"),
+        (PriceHistoryInterval::SixHours, "6h"),
+        (PriceHistoryInterval::OneHour, "1h"),
+    ];
+
+    for (interval, token) in cases {
+        assert_eq!(interval.as_str(), token);
+        assert_eq!(interval.to_string(), token);
+        assert_eq!(serde_json::to_value(interval).unwrap(), token);
+        let parsed: PriceHistoryInterval = serde_json::from_value(token.into()).unwrap();
+        assert_eq!(parsed, interval);
+    }
+}
+
+fn offline_client() -> ClobClient {
+    // Validation fails before any request is made
+    ClobClient::new_public(
+        "http://127.0.0.1:1".to_string(),
+        "http://127.0.0.1:1".to_string(),
+        Chain::Polygon,
+    )
+    .expect("Failed to create public client")
+}
+
+#[tokio::test]
+async fn test_get_prices_history_rejects_swapped_range() {
+    let result = offline_client()
+        .get_prices_history(PriceHistoryParams {
+            token_id: "1234".to_string(),
+            fidelity: 60,
+            start_ts: Some(1700003600),
+            end_ts: Some(1700000000),
+            interval: None,
+        })
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ClobError::Other(message)) if message.contains("must be before end_ts")
+    ));
+}
+
+#[tokio::test]
+async fn test_get_prices_history_rejects_zero_fidelity() {
+    let result = offline_client()
+        .get_prices_history(PriceHistoryParams {
+            token_id: "1234".to_string(),
+            fidelity: 0,
+            interval: Some(PriceHistoryInterval::OneDay),
+            ..Default::default()
+        })
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ClobError::Other(message)) if message.contains("fidelity")
+    ));
+}
@@ -53,8 +53,7 @@ async fn test_get_prices() {
         .expect("Failed to fetch prices");
 
     // Response is a map: { token_id: { side: price } }
-    assert!(!prices.is_null(), "Prices should not be null");
-    assert!(prices.is_object(), "Prices should be an object");
+    assert!(!prices.is_empty(), "Prices should not be empty");
 
     println!(
         "=== Prices ===\n{}",
@@ -99,7 +98,7 @@ async fn test_get_midpoints() {
         .await
         .expect("Failed to fetch midpoints");
 
-    assert!(!midpoints.is_null(), "Midpoints should not be null");
+    assert!(!midpoints.is_empty(), "Midpoints should not be empty");
 
     println!(
         "=== Midpoints ===\n{}",
@@ -156,8 +155,7 @@ async fn test_get_spreads() {
         .expect("Failed to fetch spreads");
 
     // Response is a map: { token_id: { side: price } }
-    assert!(!spreads.is_null(), "Spreads should not be null");
-    assert!(spreads.is_object(), "Spreads should be an object");
+    assert!(!spreads.is_empty(), "Spreads should not be empty");
 
     println!(
         "=== Spreads ===\n{}",
@@ -174,7 +172,7 @@ async fn test_get_last_trades_price() {
         .await
         .expect("Failed to fetch price");
 
-    assert!(!result.is_null(), "Result should not be null");
+    assert!(result.price_f64().is_ok(), "Price should be numeric");
 
     println!(
         "=== Last Trade Price ===\n{}",
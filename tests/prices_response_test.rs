@@ -0,0 +1,88 @@
+use rs_clob_client::{
+    types::{Chain, OrderBookParams, PriceParams, Side, SpreadsParams},
+    ClobClient,
+};
+
+fn public_client(host: String) -> ClobClient {
+    ClobClient::new_public(host.clone(), host, Chain::Polygon)
+        .expect("Failed to create public client")
+}
+
+#[tokio::test]
+async fn test_prices_are_typed_by_token_and_side() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("POST", "/prices")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        // Numeric prices are accepted as well
+        .with_body(r#"{"1":{"BUY":"0.53","SELL":0.55}}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = public_client(server.url());
+    let params = vec![
+        PriceParams {
+            token_id: "1".to_string(),
+            side: Side::Buy,
+        },
+        PriceParams {
+            token_id: "1".to_string(),
+            side: Side::Sell,
+        },
+    ];
+
+    let prices = client.get_prices(params.clone()).await.unwrap();
+    assert_eq!(prices["1"][&Side::Buy], "0.53");
+    assert_eq!(prices["1"][&Side::Sell], "0.55");
+
+    let raw = client.get_prices_raw(params).await.unwrap();
+    assert_eq!(raw["1"]["SELL"], serde_json::json!(0.55));
+}
+
+#[tokio::test]
+async fn test_spreads_and_midpoints_are_typed() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("POST", "/spreads")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"1":{"BUY":"0.02"}}"#)
+        .create_async()
+        .await;
+    server
+        .mock("POST", "/midpoints")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"1":"0.52","2":0.48}"#)
+        .create_async()
+        .await;
+
+    let client = public_client(server.url());
+
+    let spreads = client
+        .get_spreads(vec![SpreadsParams {
+            token_id: "1".to_string(),
+            side: Some(Side::Buy),
+        }])
+        .await
+        .unwrap();
+    assert_eq!(spreads["1"][&Side::Buy], "0.02");
+
+    let midpoints = client
+        .get_midpoints(vec![
+            OrderBookParams {
+                token_id: "1".to_string(),
+                side: None,
+            },
+            OrderBookParams {
+                token_id: "2".to_string(),
+                side: None,
+            },
+        ])
+        .await
+        .unwrap();
+    assert_eq!(midpoints["1"], "0.52");
+    assert_eq!(midpoints["2"], "0.48");
+}
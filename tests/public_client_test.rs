@@ -0,0 +1,56 @@
+use mockito::Matcher;
+use rs_clob_client::{errors::ClobError, types::Chain, ClobClient};
+
+const TOKEN_ID: &str = "1234567890";
+
+const BOOK_BODY: &str = r#"{
+    "market": "0xabc",
+    "asset_id": "1234567890",
+    "timestamp": "1700000000000",
+    "bids": [{ "price": "0.48", "size": "100" }],
+    "asks": [{ "price": "0.52", "size": "50" }],
+    "min_order_size": "5",
+    "tick_size": "0.01",
+    "neg_risk": false,
+    "hash": "deadbeef"
+}"#;
+
+#[tokio::test]
+async fn test_public_client_get_order_book() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/book")
+        .match_query(Matcher::UrlEncoded("token_id".into(), TOKEN_ID.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(BOOK_BODY)
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+
+    let orderbook = client
+        .get_order_book(TOKEN_ID)
+        .await
+        .expect("Failed to fetch orderbook");
+
+    mock.assert_async().await;
+    assert_eq!(orderbook.asset_id, TOKEN_ID);
+    assert_eq!(orderbook.bids.len(), 1);
+    assert_eq!(orderbook.asks.len(), 1);
+}
+
+#[tokio::test]
+async fn test_public_client_rejects_l2_method() {
+    let client = ClobClient::new_public(
+        "http://127.0.0.1:1".to_string(),
+        "http://127.0.0.1:1".to_string(),
+        Chain::Polygon,
+    )
+    .expect("Failed to create public client");
+
+    let result = client.get_api_keys().await;
+
+    assert!(matches!(result, Err(ClobError::L1AuthUnavailable)));
+}
@@ -0,0 +1,118 @@
+use mockito::Matcher;
+use rs_clob_client::{
+    types::{Chain, PriceParams, Side},
+    ClobClient,
+};
+use serde_json::json;
+use std::time::Duration;
+
+fn book(asset_id: &str) -> serde_json::Value {
+    json!({
+        "market": "0xabc",
+        "asset_id": asset_id,
+        "timestamp": "1700000000000",
+        "bids": [{ "price": "0.48", "size": "100" }],
+        "asks": [{ "price": "0.52", "size": "50" }],
+        "min_order_size": "5",
+        "tick_size": "0.01",
+        "neg_risk": false,
+        "hash": "deadbeef"
+    })
+}
+
+fn batching_client(url: String) -> ClobClient {
+    let mut client =
+        ClobClient::new_public(url.clone(), url, Chain::Polygon).expect("Failed to create client");
+    client.enable_read_batching(
+        vec!["1".to_string(), "2".to_string()],
+        Duration::from_millis(50),
+    );
+    client
+}
+
+#[tokio::test]
+async fn test_concurrent_book_reads_coalesce() {
+    let mut server = mockito::Server::new_async().await;
+    let batch = server
+        .mock("POST", "/books")
+        .match_body(Matcher::Json(json!([
+            { "token_id": "1", "side": null },
+            { "token_id": "2", "side": null }
+        ])))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([book("1"), book("2")]).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let single = server
+        .mock("GET", "/book")
+        .match_query(Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = batching_client(server.url());
+    let (a, b, c) = tokio::join!(
+        client.get_order_book("1"),
+        client.get_order_book("2"),
+        client.get_order_book("1"),
+    );
+
+    batch.assert_async().await;
+    single.assert_async().await;
+    assert_eq!(a.unwrap().asset_id, "1");
+    assert_eq!(b.unwrap().asset_id, "2");
+    assert_eq!(c.unwrap().asset_id, "1");
+}
+
+#[tokio::test]
+async fn test_concurrent_price_reads_coalesce() {
+    let mut server = mockito::Server::new_async().await;
+    let batch = server
+        .mock("POST", "/prices")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"1":{"BUY":"0.48","SELL":"0.52"},"2":{"BUY":"0.3"}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = batching_client(server.url());
+    let price = |token_id: &str, side| PriceParams {
+        token_id: token_id.to_string(),
+        side,
+    };
+    let (a, b, c) = tokio::join!(
+        client.get_price(price("1", Side::Buy)),
+        client.get_price(price("1", Side::Sell)),
+        client.get_price(price("2", Side::Buy)),
+    );
+
+    batch.assert_async().await;
+    assert_eq!(a.unwrap().price, "0.48");
+    assert_eq!(b.unwrap().price, "0.52");
+    assert_eq!(c.unwrap().price, "0.3");
+}
+
+#[tokio::test]
+async fn test_unbatched_token_uses_single_endpoint() {
+    let mut server = mockito::Server::new_async().await;
+    let single = server
+        .mock("GET", "/book")
+        .match_query(Matcher::UrlEncoded("token_id".into(), "3".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(book("3").to_string())
+        .create_async()
+        .await;
+
+    let client = batching_client(server.url());
+    let book = client
+        .get_order_book("3")
+        .await
+        .expect("Failed to fetch book");
+
+    single.assert_async().await;
+    assert_eq!(book.asset_id, "3");
+}
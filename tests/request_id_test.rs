@@ -0,0 +1,77 @@
+use rs_clob_client::{constants::REQUEST_ID_HEADER, types::Chain, ClobClient};
+use tracing_test::traced_test;
+
+fn public_client(url: String) -> ClobClient {
+    ClobClient::new_public(url.clone(), url, Chain::Polygon)
+        .expect("Failed to create public client")
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_custom_request_id_is_sent_and_logged() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/time")
+        .match_header(REQUEST_ID_HEADER, "req-42")
+        .with_status(200)
+        .with_body("1700000000")
+        .create_async()
+        .await;
+
+    let mut client = public_client(server.url());
+    client.set_request_id_generator(|| "req-42".to_string());
+    client
+        .get_server_time()
+        .await
+        .expect("Failed to fetch server time");
+
+    mock.assert_async().await;
+    assert!(logs_contain("request_id=\"req-42\""));
+}
+
+#[cfg(feature = "request-id")]
+#[tokio::test]
+async fn test_default_request_id_is_uuid_v4() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/time")
+        .match_header(
+            REQUEST_ID_HEADER,
+            mockito::Matcher::Regex(
+                "^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$".to_string(),
+            ),
+        )
+        .with_status(200)
+        .with_body("1700000000")
+        .create_async()
+        .await;
+
+    let client = public_client(server.url());
+    client
+        .get_server_time()
+        .await
+        .expect("Failed to fetch server time");
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_request_ids_can_be_disabled() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/time")
+        .match_header(REQUEST_ID_HEADER, mockito::Matcher::Missing)
+        .with_status(200)
+        .with_body("1700000000")
+        .create_async()
+        .await;
+
+    let mut client = public_client(server.url());
+    client.disable_request_ids();
+    client
+        .get_server_time()
+        .await
+        .expect("Failed to fetch server time");
+
+    mock.assert_async().await;
+}
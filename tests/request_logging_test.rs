@@ -0,0 +1,24 @@
+use rs_clob_client::{types::Chain, ClobClient};
+use tracing_test::traced_test;
+
+#[tokio::test]
+#[traced_test]
+async fn test_error_status_emits_error_event() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/time")
+        .with_status(503)
+        .with_body("maintenance")
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+    let result = client.get_server_time().await;
+
+    assert!(result.is_err());
+    assert!(logs_contain("ERROR"));
+    assert!(logs_contain("request failed"));
+    assert!(logs_contain("status=503"));
+    assert!(logs_contain("endpoint=\"/time\""));
+}
@@ -0,0 +1,189 @@
+use rs_clob_client::{
+    types::{Chain, OrderBookParams, RetryConfig},
+    ClobClient, ClobError,
+};
+use std::time::Duration;
+
+const TOKEN_ID: &str = "1234";
+
+fn fast_retries(max_retries: u32) -> RetryConfig {
+    RetryConfig {
+        max_retries,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(5),
+    }
+}
+
+fn retrying_client(url: String, max_retries: u32) -> ClobClient {
+    let mut client =
+        ClobClient::new_public(url.clone(), url, Chain::Polygon).expect("Failed to create client");
+    client.set_retry_config(Some(fast_retries(max_retries)));
+    client
+}
+
+#[tokio::test]
+async fn test_get_retries_server_errors_then_succeeds() {
+    let mut server = mockito::Server::new_async().await;
+    let unavailable = server
+        .mock("GET", "/midpoint")
+        .match_query(mockito::Matcher::Any)
+        .with_status(503)
+        .expect(2)
+        .create_async()
+        .await;
+    let ok = server
+        .mock("GET", "/midpoint")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"mid":"0.55"}"#)
+        .create_async()
+        .await;
+
+    let client = retrying_client(server.url(), 3);
+    let midpoint = client
+        .get_midpoint(TOKEN_ID)
+        .await
+        .expect("Request should succeed after retries");
+
+    unavailable.assert_async().await;
+    ok.assert_async().await;
+    assert_eq!(midpoint.mid, "0.55");
+}
+
+#[tokio::test]
+async fn test_get_gives_up_after_max_retries() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/midpoint")
+        .match_query(mockito::Matcher::Any)
+        .with_status(502)
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = retrying_client(server.url(), 2);
+    let err = client.get_midpoint(TOKEN_ID).await.unwrap_err();
+
+    mock.assert_async().await;
+    assert!(matches!(err, ClobError::ApiError { status: 502, .. }));
+}
+
+#[tokio::test]
+async fn test_post_not_retried_on_server_error() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/midpoints")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = retrying_client(server.url(), 3);
+    let params = vec![OrderBookParams {
+        token_id: TOKEN_ID.to_string(),
+        side: None,
+    }];
+    let err = client.get_midpoints(params).await.unwrap_err();
+
+    mock.assert_async().await;
+    assert!(matches!(err, ClobError::ApiError { status: 503, .. }));
+}
+
+#[tokio::test]
+async fn test_post_retried_on_rate_limit() {
+    let mut server = mockito::Server::new_async().await;
+    let limited = server
+        .mock("POST", "/midpoints")
+        .with_status(429)
+        .with_header("Retry-After", "0")
+        .expect(1)
+        .create_async()
+        .await;
+    let ok = server
+        .mock("POST", "/midpoints")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"1234":"0.55"}"#)
+        .create_async()
+        .await;
+
+    let client = retrying_client(server.url(), 3);
+    let params = vec![OrderBookParams {
+        token_id: TOKEN_ID.to_string(),
+        side: None,
+    }];
+    let midpoints = client
+        .get_midpoints(params)
+        .await
+        .expect("Request should succeed after rate limit");
+
+    limited.assert_async().await;
+    ok.assert_async().await;
+    assert_eq!(midpoints[TOKEN_ID], "0.55");
+}
+
+#[tokio::test]
+async fn test_retry_after_capped_at_max_delay() {
+    let mut server = mockito::Server::new_async().await;
+    let limited = server
+        .mock("GET", "/midpoint")
+        .match_query(mockito::Matcher::Any)
+        .with_status(429)
+        .with_header("Retry-After", "3600")
+        .expect(1)
+        .create_async()
+        .await;
+    let ok = server
+        .mock("GET", "/midpoint")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"mid":"0.55"}"#)
+        .create_async()
+        .await;
+
+    let client = retrying_client(server.url(), 3);
+    let midpoint = tokio::time::timeout(Duration::from_secs(5), client.get_midpoint(TOKEN_ID))
+        .await
+        .expect("Retry-After should be capped at max_delay")
+        .expect("Request should succeed after rate limit");
+
+    limited.assert_async().await;
+    ok.assert_async().await;
+    assert_eq!(midpoint.mid, "0.55");
+}
+
+#[tokio::test]
+async fn test_no_retries_by_default() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/midpoint")
+        .match_query(mockito::Matcher::Any)
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create client");
+    let err = client.get_midpoint(TOKEN_ID).await.unwrap_err();
+
+    mock.assert_async().await;
+    assert!(matches!(err, ClobError::ApiError { status: 503, .. }));
+}
+
+#[test]
+fn test_backoff_doubles_and_caps() {
+    let config = RetryConfig {
+        max_retries: 10,
+        base_delay: Duration::from_millis(100),
+        max_delay: Duration::from_millis(500),
+    };
+
+    assert_eq!(config.backoff(0), Duration::from_millis(100));
+    assert_eq!(config.backoff(1), Duration::from_millis(200));
+    assert_eq!(config.backoff(2), Duration::from_millis(400));
+    assert_eq!(config.backoff(3), Duration::from_millis(500));
+    assert_eq!(config.backoff(40), Duration::from_millis(500));
+}
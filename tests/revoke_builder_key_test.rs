@@ -0,0 +1,80 @@
+mod common;
+
+use common::{create_mock_client_with_builder_api_key, create_mock_client_with_remote_builder};
+use mockito::Matcher;
+
+const ACTIVE_KEY: &str = "019894b9-cb40-79c4-b2bd-6aecb6f8c6c5";
+const STALE_KEY: &str = "01988a1e-4d7b-7a2c-9f3e-2b1c0d9e8f7a";
+
+#[tokio::test]
+async fn test_revoke_builder_api_key_by_targets_key() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("DELETE", "/auth/builder-api-key")
+        .match_query(Matcher::UrlEncoded("key".into(), STALE_KEY.into()))
+        .match_header("POLY_API_KEY", "test-api-key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_builder_api_key(server.url(), ACTIVE_KEY);
+    client
+        .revoke_builder_api_key_by(STALE_KEY, false)
+        .await
+        .expect("Failed to revoke builder API key");
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_revoke_builder_api_key_by_refuses_active_key() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("DELETE", "/auth/builder-api-key")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_body("{}")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_builder_api_key(server.url(), ACTIVE_KEY);
+
+    assert!(client
+        .revoke_builder_api_key_by(ACTIVE_KEY, false)
+        .await
+        .is_err());
+
+    client
+        .revoke_builder_api_key_by(ACTIVE_KEY, true)
+        .await
+        .expect("Forced revoke should be sent");
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_revoke_builder_api_key_by_does_not_call_remote_signer() {
+    let mut server = mockito::Server::new_async().await;
+    let signer = server.mock("POST", "/sign").expect(0).create_async().await;
+    let mock = server
+        .mock("DELETE", "/auth/builder-api-key")
+        .match_query(Matcher::UrlEncoded("key".into(), STALE_KEY.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .create_async()
+        .await;
+
+    let client =
+        create_mock_client_with_remote_builder(server.url(), format!("{}/sign", server.url()));
+    client
+        .revoke_builder_api_key_by(STALE_KEY, false)
+        .await
+        .expect("Failed to revoke builder API key");
+
+    signer.assert_async().await;
+    mock.assert_async().await;
+}
@@ -0,0 +1,27 @@
+mod common;
+
+use common::create_mock_client_with_api_key;
+
+#[tokio::test]
+async fn test_get_liquidity_reward_percentages() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/rewards/user/percentages")
+        .match_header("POLY_API_KEY", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"0xabc": 12.5, "0xdef": 0.75}"#)
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let percentages = client
+        .get_liquidity_reward_percentages()
+        .await
+        .expect("Failed to fetch liquidity reward percentages");
+
+    mock.assert_async().await;
+    assert_eq!(percentages.len(), 2);
+    assert_eq!(percentages["0xabc"], 12.5);
+    assert_eq!(percentages["0xdef"], 0.75);
+}
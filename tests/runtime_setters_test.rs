@@ -0,0 +1,201 @@
+use alloy_primitives::{Address, Signature};
+use alloy_signer_local::PrivateKeySigner;
+use mockito::Matcher;
+use rs_clob_client::signing::eip712::order_hash;
+use rs_clob_client::types::{
+    ApiKeyCreds, AuthLevel, CreateOrderOptions, Side, TickSize, UserLimitOrder,
+};
+use rs_clob_client::{ClobClient, ClobError};
+use serde_json::Value;
+use std::str::FromStr;
+
+const HOST: &str = "http://127.0.0.1:1";
+const EXCHANGE: &str = "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E";
+const FUNDER: &str = "0x6e0c80c90ea6c15917308F820Eac91Ce2724B5b5";
+
+fn order() -> UserLimitOrder {
+    UserLimitOrder {
+        token_id: "1234".to_string(),
+        price: 0.37,
+        size: 25.0,
+        side: Side::Buy,
+        fee_rate_bps: None,
+        nonce: None,
+        expiration: None,
+        expiration_buffer: None,
+        taker: None,
+        order_type: None,
+    }
+}
+
+fn options() -> CreateOrderOptions {
+    CreateOrderOptions {
+        tick_size: TickSize::ZeroPointZeroOne,
+        neg_risk: Some(false),
+    }
+}
+
+fn address_field(order: &Value, key: &str) -> Address {
+    Address::from_str(order[key].as_str().unwrap()).unwrap()
+}
+
+/// Address recovered from the order's signature over its EIP-712 hash
+fn recovered_signer(order: &Value) -> Address {
+    let hash = order_hash(order, 137, Address::from_str(EXCHANGE).unwrap()).unwrap();
+    let signature = Signature::from_str(order["signature"].as_str().unwrap()).unwrap();
+    signature.recover_address_from_prehash(&hash).unwrap()
+}
+
+#[tokio::test]
+async fn test_orders_are_signed_by_new_wallet() {
+    let mut client = ClobClient::builder()
+        .host(HOST)
+        .gamma_host(HOST)
+        .wallet(PrivateKeySigner::random())
+        .build()
+        .unwrap();
+
+    let wallet = PrivateKeySigner::random();
+    let address = wallet.address();
+    client.set_wallet(wallet);
+
+    let signed = client
+        .create_order_offline(&order(), options(), 0)
+        .await
+        .unwrap();
+    assert_eq!(client.address(), Some(address));
+    assert_eq!(address_field(&signed, "maker"), address);
+    assert_eq!(address_field(&signed, "signer"), address);
+    assert_eq!(recovered_signer(&signed), address);
+}
+
+#[tokio::test]
+async fn test_set_wallet_keeps_signature_type_and_funder() {
+    let mut client = ClobClient::builder()
+        .host(HOST)
+        .gamma_host(HOST)
+        .signature_type(1)
+        .funder_address(FUNDER)
+        .build()
+        .unwrap();
+    assert_eq!(client.auth_level(), AuthLevel::Public);
+
+    let wallet = PrivateKeySigner::random();
+    let address = wallet.address();
+    client.set_wallet(wallet);
+
+    let signed = client
+        .create_order_offline(&order(), options(), 0)
+        .await
+        .unwrap();
+    assert_eq!(
+        address_field(&signed, "maker"),
+        Address::from_str(FUNDER).unwrap()
+    );
+    assert_eq!(address_field(&signed, "signer"), address);
+    assert_eq!(recovered_signer(&signed), address);
+}
+
+#[tokio::test]
+async fn test_set_funder_address_rebuilds_order_builder() {
+    let wallet = PrivateKeySigner::random();
+    let address = wallet.address();
+    let mut client = ClobClient::builder()
+        .host(HOST)
+        .gamma_host(HOST)
+        .wallet(wallet)
+        .signature_type(2)
+        .funder_address(address.to_string())
+        .build()
+        .unwrap();
+
+    client.set_funder_address(Some(FUNDER.to_string())).unwrap();
+    let signed = client
+        .create_order_offline(&order(), options(), 0)
+        .await
+        .unwrap();
+    assert_eq!(
+        address_field(&signed, "maker"),
+        Address::from_str(FUNDER).unwrap()
+    );
+
+    // Safe orders need a funder; a rejected change keeps the current one
+    assert!(matches!(
+        client.set_funder_address(None),
+        Err(ClobError::ConfigError(_))
+    ));
+    assert!(matches!(
+        client.set_funder_address(Some("not-an-address".to_string())),
+        Err(ClobError::ConfigError(_))
+    ));
+    let signed = client
+        .create_order_offline(&order(), options(), 0)
+        .await
+        .unwrap();
+    assert_eq!(
+        address_field(&signed, "maker"),
+        Address::from_str(FUNDER).unwrap()
+    );
+}
+
+#[test]
+fn test_clear_creds_drops_to_l1() {
+    let mut client = ClobClient::builder()
+        .host(HOST)
+        .gamma_host(HOST)
+        .wallet(PrivateKeySigner::random())
+        .creds(ApiKeyCreds {
+            key: "test-api-key".to_string(),
+            secret: "dGVzdF9zZWNyZXRfa2V5".to_string(),
+            passphrase: "test-passphrase".to_string(),
+        })
+        .build()
+        .unwrap();
+    assert_eq!(client.auth_level(), AuthLevel::L2);
+
+    client.clear_creds();
+    assert_eq!(client.auth_level(), AuthLevel::L1);
+    assert!(matches!(
+        client.can_l2_auth(),
+        Err(ClobError::L2AuthNotAvailable)
+    ));
+}
+
+#[tokio::test]
+async fn test_set_geo_block_token_applies_to_requests() {
+    let mut server = mockito::Server::new_async().await;
+    let with_token = server
+        .mock("GET", "/time")
+        .match_query(Matcher::UrlEncoded(
+            "geo_block_token".into(),
+            "token-2".into(),
+        ))
+        .with_status(200)
+        .with_body("1700000000")
+        .expect(1)
+        .create_async()
+        .await;
+    let without_token = server
+        .mock("GET", "/time")
+        .match_query(Matcher::Missing)
+        .with_status(200)
+        .with_body("1700000000")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let mut client = ClobClient::builder()
+        .host(server.url())
+        .gamma_host(server.url())
+        .geo_block_token("token-1")
+        .build()
+        .unwrap();
+
+    client.set_geo_block_token(Some("token-2".to_string()));
+    client.get_server_time().await.unwrap();
+    client.set_geo_block_token(None);
+    client.get_server_time().await.unwrap();
+
+    with_token.assert_async().await;
+    without_token.assert_async().await;
+}
@@ -0,0 +1,99 @@
+use alloy_signer_local::PrivateKeySigner;
+use rs_clob_client::{
+    types::{ApiKeyCreds, Chain, ServerTimeFallback},
+    ClobClient,
+};
+use std::time::Duration;
+
+fn server_time_client(host: String) -> ClobClient {
+    let creds = ApiKeyCreds {
+        key: "test-api-key".to_string(),
+        secret: "dGVzdF9zZWNyZXRfa2V5".to_string(),
+        passphrase: "test-passphrase".to_string(),
+    };
+
+    let mut client = ClobClient::new(
+        host.clone(),
+        host,
+        Chain::Polygon,
+        Some(PrivateKeySigner::random()),
+        Some(creds),
+        Some(0),
+        None,
+        None,
+        true, // use_server_time
+        None,
+        None,
+    )
+    .expect("Failed to create ClobClient");
+    client.set_server_time_fallback(Some(ServerTimeFallback {
+        failure_threshold: 2,
+        retry_after: Duration::from_millis(200),
+    }));
+    client
+}
+
+#[tokio::test]
+async fn test_falls_back_to_local_time_after_repeated_failures() {
+    let mut server = mockito::Server::new_async().await;
+    let time = server
+        .mock("GET", "/time")
+        .with_status(503)
+        .expect(3)
+        .create_async()
+        .await;
+    let notifications = server
+        .mock("GET", "/notifications")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = server_time_client(server.url());
+
+    // Below the threshold the failure still aborts the call
+    assert!(client.get_notifications().await.is_err());
+
+    // Threshold reached: signed with local time instead
+    client
+        .get_notifications()
+        .await
+        .expect("Should fall back to local time");
+
+    // Breaker open: /time is not queried
+    client
+        .get_notifications()
+        .await
+        .expect("Should keep using local time");
+
+    // After retry_after the server is tried again (and still failing, falls back again)
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    client
+        .get_notifications()
+        .await
+        .expect("Should fall back again");
+
+    time.assert_async().await;
+    notifications.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_disabled_fallback_always_propagates_errors() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("GET", "/time")
+        .with_status(503)
+        .expect(4)
+        .create_async()
+        .await;
+
+    let mut client = server_time_client(server.url());
+    client.set_server_time_fallback(None);
+
+    for _ in 0..4 {
+        assert!(client.get_notifications().await.is_err());
+    }
+}
@@ -0,0 +1,43 @@
+use alloy_signer_local::PrivateKeySigner;
+use rs_clob_client::{types::ApiKeyCreds, ClobClient};
+
+#[tokio::test]
+async fn test_server_time_offset_is_reused_across_signed_requests() {
+    let mut server = mockito::Server::new_async().await;
+    let time = server
+        .mock("GET", "/time")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("1700000000")
+        .expect(1)
+        .create_async()
+        .await;
+    let notifications = server
+        .mock("GET", "/notifications")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = ClobClient::builder()
+        .host(server.url())
+        .gamma_host(server.url())
+        .wallet(PrivateKeySigner::random())
+        .creds(ApiKeyCreds {
+            key: "test-api-key".to_string(),
+            secret: "dGVzdF9zZWNyZXRfa2V5".to_string(),
+            passphrase: "test-passphrase".to_string(),
+        })
+        .use_server_time(true)
+        .build()
+        .expect("Failed to create ClobClient");
+
+    client.get_notifications().await.unwrap();
+    client.get_notifications().await.unwrap();
+
+    time.assert_async().await;
+    notifications.assert_async().await;
+}
@@ -0,0 +1,115 @@
+use mockito::Matcher;
+use rs_clob_client::types::Chain;
+use rs_clob_client::ClobClient;
+
+fn public_client(host: String) -> ClobClient {
+    ClobClient::new_public(host, "http://127.0.0.1:1".to_string(), Chain::Polygon)
+        .expect("Failed to create public client")
+}
+
+#[tokio::test]
+async fn test_simplified_markets_follow_cursor() {
+    let mut server = mockito::Server::new_async().await;
+    let first = server
+        .mock("GET", "/simplified-markets")
+        .match_query(Matcher::UrlEncoded("next_cursor".into(), "MA==".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"limit":1,"count":1,"next_cursor":"MQ==","data":[{
+                "condition_id":"0xaaa",
+                "rewards":{"rates":[{"asset_address":"0xusdc","rewards_daily_rate":25}],"min_size":50,"max_spread":3.5},
+                "tokens":[{"token_id":"111","outcome":"Yes","price":0.6,"winner":false},
+                          {"token_id":"222","outcome":"No","price":0.4,"winner":false}],
+                "active":true,"closed":false,"archived":false,"accepting_orders":true}]}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/simplified-markets")
+        .match_query(Matcher::UrlEncoded("next_cursor".into(), "MQ==".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"limit":1,"count":1,"next_cursor":"LTE=","data":[{
+                "condition_id":"0xbbb","rewards":{"rates":null,"min_size":0,"max_spread":0},
+                "tokens":[],"active":false,"closed":true,"archived":false,"accepting_orders":false}]}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let markets = public_client(server.url())
+        .get_simplified_markets()
+        .await
+        .unwrap();
+
+    assert_eq!(markets.len(), 2);
+    assert_eq!(markets[0].condition_id, "0xaaa");
+    assert_eq!(markets[0].tokens[1].token_id, "222");
+    let rewards = markets[0].rewards.as_ref().unwrap();
+    assert_eq!(rewards.max_spread, 3.5);
+    assert_eq!(rewards.rates.as_ref().unwrap()[0].rewards_daily_rate, 25.0);
+    assert!(markets[0].accepting_orders);
+    assert!(markets[0].tick_size.is_none());
+    assert!(markets[1].closed);
+    assert!(markets[1].rewards.as_ref().unwrap().rates.is_none());
+
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_sampling_markets_parse_order_constraints() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/sampling-markets")
+        .match_query(Matcher::UrlEncoded("next_cursor".into(), "MA==".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"limit":1,"count":1,"next_cursor":"LTE=","data":[{
+                "condition_id":"0xccc","question":"Will it rain?","neg_risk":true,
+                "minimum_order_size":5,"minimum_tick_size":0.01,
+                "rewards":{"rates":null,"min_size":20,"max_spread":3},
+                "tokens":[{"token_id":"333","outcome":"Yes","price":0.25,"winner":false}],
+                "active":true,"closed":false,"archived":false,"accepting_orders":true}]}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let markets = public_client(server.url())
+        .get_sampling_markets()
+        .await
+        .unwrap();
+
+    assert_eq!(markets.len(), 1);
+    assert_eq!(markets[0].min_order_size, Some(5.0));
+    assert_eq!(markets[0].tick_size, Some(0.01));
+    assert_eq!(markets[0].neg_risk, Some(true));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_sampling_simplified_markets_endpoint() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/sampling-simplified-markets")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"limit":0,"count":0,"next_cursor":"LTE=","data":[]}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let markets = public_client(server.url())
+        .get_sampling_simplified_markets()
+        .await
+        .unwrap();
+
+    assert!(markets.is_empty());
+    mock.assert_async().await;
+}
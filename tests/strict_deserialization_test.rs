@@ -0,0 +1,38 @@
+use rs_clob_client::types::{Midpoint, OrderBookSummary};
+
+const BOOK_WITH_EXTRA_FIELD: &str = r#"{
+    "market": "0xabc",
+    "asset_id": "1234",
+    "timestamp": "1700000000000",
+    "bids": [{"price": "0.4", "size": "10"}],
+    "asks": [{"price": "0.6", "size": "10"}],
+    "min_order_size": "5",
+    "tick_size": "0.01",
+    "neg_risk": false,
+    "hash": "0xhash",
+    "last_trade_price": "0.5"
+}"#;
+
+#[test]
+fn test_known_fields_parse_in_every_mode() {
+    let midpoint: Midpoint = serde_json::from_str(r#"{"mid":"0.5"}"#).expect("Failed to parse");
+    assert_eq!(midpoint.mid, "0.5");
+}
+
+#[cfg(not(feature = "strict-deserialization"))]
+#[test]
+fn test_unknown_fields_ignored_in_lenient_mode() {
+    let book: OrderBookSummary =
+        serde_json::from_str(BOOK_WITH_EXTRA_FIELD).expect("Lenient mode should ignore extras");
+    assert_eq!(book.asset_id, "1234");
+}
+
+#[cfg(feature = "strict-deserialization")]
+#[test]
+fn test_unknown_fields_rejected_in_strict_mode() {
+    let err = serde_json::from_str::<OrderBookSummary>(BOOK_WITH_EXTRA_FIELD).unwrap_err();
+    assert!(err.to_string().contains("last_trade_price"));
+
+    let err = serde_json::from_str::<Midpoint>(r#"{"mid":"0.5","spread":"0.02"}"#).unwrap_err();
+    assert!(err.to_string().contains("spread"));
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use common::{
+    accepted_order_response_json, create_mock_client_with_api_key, rejected_order_response_json,
+};
+use mockito::Matcher;
+use rs_clob_client::types::OrderType;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_submit_pair_posts_both_legs_in_one_batch() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/orders")
+        .match_body(Matcher::PartialJson(json!([
+            { "order": { "tokenId": "111", "side": "BUY" }, "orderType": "FOK" },
+            { "order": { "tokenId": "222", "side": "BUY" }, "orderType": "FOK" }
+        ])))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                accepted_order_response_json("0xyes", "matched"),
+                rejected_order_response_json("not enough balance / allowance")
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let (yes, no) = client
+        .submit_pair(
+            json!({ "tokenId": "111", "side": "BUY" }),
+            json!({ "tokenId": "222", "side": "BUY" }),
+            OrderType::Fok,
+        )
+        .await
+        .expect("Failed to submit pair");
+
+    mock.assert_async().await;
+    assert!(yes.is_success());
+    assert_eq!(yes.order_id.as_deref(), Some("0xyes"));
+    assert_eq!(yes.status.as_deref(), Some("matched"));
+    assert!(!no.is_success());
+    assert_eq!(
+        no.error_msg.as_deref(),
+        Some("not enough balance / allowance")
+    );
+}
@@ -0,0 +1,56 @@
+use rs_clob_client::types::{Chain, TickSize, TickSizeInfo};
+use rs_clob_client::ClobClient;
+
+const TOKEN_ID: &str = "1234";
+const SAMPLE_RESPONSE: &str = r#"{"minimum_tick_size":0.001,"maximum":0.999,"min_order_size":5}"#;
+
+#[test]
+fn test_tick_size_info_keeps_extra_fields() {
+    let info: TickSizeInfo = serde_json::from_str(SAMPLE_RESPONSE).expect("Failed to parse");
+
+    assert_eq!(info.minimum_tick_size, 0.001);
+    assert_eq!(info.tick_size().unwrap(), TickSize::ZeroPointZeroZeroOne);
+    assert_eq!(info.extra["maximum"], 0.999);
+    assert_eq!(info.extra["min_order_size"], 5);
+}
+
+#[test]
+fn test_tick_size_info_rejects_unknown_tick_size() {
+    let info: TickSizeInfo =
+        serde_json::from_str(r#"{"minimum_tick_size":0.05}"#).expect("Failed to parse");
+
+    assert!(info.tick_size().is_err());
+}
+
+#[tokio::test]
+async fn test_get_tick_size_info() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/tick-size")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "token_id".to_string(),
+            TOKEN_ID.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(SAMPLE_RESPONSE)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+
+    let info = client
+        .get_tick_size_info(TOKEN_ID)
+        .await
+        .expect("Failed to fetch tick size info");
+    assert_eq!(info.extra["maximum"], 0.999);
+
+    // get_tick_size parses the same response, then serves later calls from the cache
+    let tick_size = client.get_tick_size(TOKEN_ID).await.unwrap();
+    assert_eq!(tick_size, TickSize::ZeroPointZeroZeroOne);
+    client.get_tick_size(TOKEN_ID).await.unwrap();
+
+    mock.assert_async().await;
+}
@@ -0,0 +1,161 @@
+use alloy_signer_local::PrivateKeySigner;
+use mockito::Matcher;
+use rs_clob_client::headers::{create_l1_headers, create_l2_headers};
+use rs_clob_client::time::{FixedTime, SystemTimeProvider, TimeProvider};
+use rs_clob_client::types::ApiKeyCreds;
+use rs_clob_client::{ClobClient, ClobError};
+use std::sync::Arc;
+
+const NOW: u64 = 1_700_000_000;
+
+fn creds() -> ApiKeyCreds {
+    ApiKeyCreds {
+        key: "test-api-key".to_string(),
+        secret: "dGVzdF9zZWNyZXRfa2V5".to_string(),
+        passphrase: "test-passphrase".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_fixed_time_makes_signatures_reproducible() {
+    let wallet = PrivateKeySigner::random();
+
+    let first = create_l1_headers(&wallet, 137, None, None, &FixedTime(NOW))
+        .await
+        .unwrap();
+    let second = create_l1_headers(&wallet, 137, None, None, &FixedTime(NOW))
+        .await
+        .unwrap();
+    assert_eq!(first.poly_timestamp, NOW.to_string());
+    assert_eq!(first.poly_signature, second.poly_signature);
+
+    let body = Some(r#"{"orderID":"0x1"}"#);
+    let first = create_l2_headers(
+        wallet.address(),
+        &creds(),
+        "DELETE",
+        "/order",
+        body,
+        None,
+        &FixedTime(NOW),
+    )
+    .await
+    .unwrap();
+    let second = create_l2_headers(
+        wallet.address(),
+        &creds(),
+        "DELETE",
+        "/order",
+        body,
+        None,
+        &FixedTime(NOW),
+    )
+    .await
+    .unwrap();
+    assert_eq!(first.poly_timestamp, NOW.to_string());
+    assert_eq!(first.poly_signature, second.poly_signature);
+}
+
+#[tokio::test]
+async fn test_client_signs_with_its_time_provider() {
+    let mut server = mockito::Server::new_async().await;
+    let wallet = PrivateKeySigner::random();
+    let client = ClobClient::builder()
+        .host(server.url())
+        .gamma_host(server.url())
+        .wallet(wallet.clone())
+        .creds(creds())
+        .time_provider(Arc::new(FixedTime(NOW)))
+        .build()
+        .unwrap();
+
+    // Headers the client should send, signed independently at the same time
+    let expected = create_l2_headers(
+        wallet.address(),
+        &creds(),
+        "GET",
+        "/auth/api-keys",
+        None,
+        None,
+        &FixedTime(NOW),
+    )
+    .await
+    .unwrap();
+
+    let mock = server
+        .mock("GET", "/auth/api-keys")
+        .match_header("POLY_TIMESTAMP", Matcher::Exact(NOW.to_string()))
+        .match_header("POLY_SIGNATURE", Matcher::Exact(expected.poly_signature))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"apiKeys":[]}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    client.get_api_keys().await.unwrap();
+    client.get_api_keys().await.unwrap();
+    mock.assert_async().await;
+}
+
+/// A clock that cannot be read, e.g. a sandboxed host without one
+struct NoClock;
+
+impl TimeProvider for NoClock {
+    fn now_secs(&self) -> u64 {
+        0
+    }
+
+    fn is_available(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn test_system_clock_is_available() {
+    assert!(SystemTimeProvider.is_available());
+    assert!(SystemTimeProvider.now_secs() > NOW);
+}
+
+#[tokio::test]
+async fn test_without_a_clock_every_signature_uses_server_time() {
+    let builder = || {
+        ClobClient::builder()
+            .host("http://127.0.0.1:1")
+            .gamma_host("http://127.0.0.1:1")
+            .time_provider(Arc::new(NoClock))
+    };
+    assert!(matches!(builder().build(), Err(ClobError::ConfigError(_))));
+
+    let mut server = mockito::Server::new_async().await;
+    let time = server
+        .mock("GET", "/time")
+        .with_status(200)
+        .with_body(NOW.to_string())
+        .expect(2)
+        .create_async()
+        .await;
+    let api_keys = server
+        .mock("GET", "/auth/api-keys")
+        .match_header("POLY_TIMESTAMP", Matcher::Exact(NOW.to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"apiKeys":[]}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = builder()
+        .host(server.url())
+        .gamma_host(server.url())
+        .wallet(PrivateKeySigner::random())
+        .creds(creds())
+        .use_server_time(true)
+        .build()
+        .unwrap();
+    client.get_api_keys().await.unwrap();
+    client.get_api_keys().await.unwrap();
+
+    time.assert_async().await;
+    api_keys.assert_async().await;
+}
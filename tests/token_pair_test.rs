@@ -0,0 +1,72 @@
+use rs_clob_client::types::Chain;
+use rs_clob_client::ClobClient;
+
+const CONDITION_ID: &str = "0xabc123";
+
+async fn mock_market(server: &mut mockito::ServerGuard, tokens: &str) -> mockito::Mock {
+    server
+        .mock("GET", format!("/markets/{}", CONDITION_ID).as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"condition_id":"{}","neg_risk":false,"tokens":{}}}"#,
+            CONDITION_ID, tokens
+        ))
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_token_pair_is_cached() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = mock_market(
+        &mut server,
+        r#"[{"token_id":"111","outcome":"Yes","price":0.6},{"token_id":"222","outcome":"No","price":0.4}]"#,
+    )
+    .await;
+
+    // The pair comes from the CLOB host, not Gamma
+    let client = ClobClient::new_public(
+        server.url(),
+        "http://127.0.0.1:1".to_string(),
+        Chain::Polygon,
+    )
+    .expect("Failed to create public client");
+
+    let pair = client.token_pair_for_condition(CONDITION_ID).await.unwrap();
+    assert_eq!(pair, ("111".to_string(), "222".to_string()));
+
+    // Second call is served from the cache
+    let cached = client.token_pair_for_condition(CONDITION_ID).await.unwrap();
+    assert_eq!(cached, pair);
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_token_pair_orders_by_outcome() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = mock_market(
+        &mut server,
+        r#"[{"token_id":"222","outcome":"No"},{"token_id":"111","outcome":"Yes"}]"#,
+    )
+    .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+
+    let pair = client.token_pair_for_condition(CONDITION_ID).await.unwrap();
+    assert_eq!(pair, ("111".to_string(), "222".to_string()));
+}
+
+#[tokio::test]
+async fn test_token_pair_rejects_non_binary_market() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = mock_market(&mut server, r#"[{"token_id":"111","outcome":"Yes"}]"#).await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+
+    assert!(client.token_pair_for_condition(CONDITION_ID).await.is_err());
+}
@@ -0,0 +1,62 @@
+use rs_clob_client::types::{OrderBookSummary, OrderSummary};
+
+fn level(price: &str, size: &str) -> OrderSummary {
+    OrderSummary {
+        price: price.to_string(),
+        size: size.to_string(),
+    }
+}
+
+fn book(bids: Vec<OrderSummary>, asks: Vec<OrderSummary>) -> OrderBookSummary {
+    OrderBookSummary {
+        market: "0xabc".to_string(),
+        asset_id: "1234".to_string(),
+        timestamp: "1700000000000".to_string(),
+        bids,
+        asks,
+        min_order_size: "5".to_string(),
+        tick_size: "0.01".to_string(),
+        neg_risk: false,
+        hash: "0xhash".to_string(),
+    }
+}
+
+#[test]
+fn test_top_of_book_uses_last_level_of_each_side() {
+    // Server order: bids ascending, asks descending
+    let orderbook = book(
+        vec![level("0.40", "10"), level("0.45", "20"), level("0.48", "5")],
+        vec![level("0.60", "15"), level("0.55", "5"), level("0.52", "8")],
+    );
+
+    assert_eq!(orderbook.best_bid(), Some(0.48));
+    assert_eq!(orderbook.best_ask(), Some(0.52));
+    assert!((orderbook.midpoint().unwrap() - 0.5).abs() < 1e-9);
+    assert!((orderbook.spread().unwrap() - 0.04).abs() < 1e-9);
+}
+
+#[test]
+fn test_top_of_book_with_empty_side() {
+    let orderbook = book(vec![level("0.45", "20")], vec![]);
+
+    assert_eq!(orderbook.best_bid(), Some(0.45));
+    assert_eq!(orderbook.best_ask(), None);
+    assert_eq!(orderbook.midpoint(), None);
+    assert_eq!(orderbook.spread(), None);
+
+    let empty = book(vec![], vec![]);
+    assert_eq!(empty.best_bid(), None);
+    assert_eq!(empty.midpoint(), None);
+}
+
+#[test]
+fn test_top_of_book_after_sanitize_of_unordered_book() {
+    let mut orderbook = book(
+        vec![level("0.48", "5"), level("0.40", "10")],
+        vec![level("0.52", "8"), level("0.60", "15")],
+    );
+    orderbook.sanitize();
+
+    assert_eq!(orderbook.best_bid(), Some(0.48));
+    assert_eq!(orderbook.best_ask(), Some(0.52));
+}
@@ -0,0 +1,54 @@
+use mockito::Matcher;
+use rs_clob_client::{types::Chain, ClobClient};
+
+const TAG_BODY: &str = r#"{
+    "id": "21",
+    "label": "Crypto",
+    "slug": "crypto",
+    "forceShow": false
+}"#;
+
+const MARKETS_BODY: &str = r#"[
+    { "id": "1", "acceptingOrders": true, "enableOrderBook": true },
+    { "id": "2", "acceptingOrders": false, "enableOrderBook": true },
+    { "id": "3", "acceptingOrders": true, "enableOrderBook": false },
+    { "id": "4", "acceptingOrders": true },
+    { "id": "5", "acceptingOrders": true, "enableOrderBook": true }
+]"#;
+
+#[tokio::test]
+async fn test_get_tradeable_markets_for_tag() {
+    let mut server = mockito::Server::new_async().await;
+    let tag_mock = server
+        .mock("GET", "/tags/slug/crypto")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(TAG_BODY)
+        .create_async()
+        .await;
+    let markets_mock = server
+        .mock("GET", "/markets")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("tag_id".into(), "21".into()),
+            Matcher::UrlEncoded("closed".into(), "false".into()),
+            Matcher::UrlEncoded("offset".into(), "0".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(MARKETS_BODY)
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+
+    let markets = client
+        .get_tradeable_markets_for_tag("crypto")
+        .await
+        .expect("Failed to fetch tradeable markets");
+
+    tag_mock.assert_async().await;
+    markets_mock.assert_async().await;
+    let ids: Vec<_> = markets.iter().map(|m| m.id.as_str()).collect();
+    assert_eq!(ids, vec!["1", "5"]);
+}
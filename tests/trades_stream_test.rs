@@ -0,0 +1,65 @@
+mod common;
+
+use common::{create_mock_client_with_api_key, trade_json};
+use futures::StreamExt;
+use mockito::Matcher;
+
+async fn mock_page(
+    server: &mut mockito::ServerGuard,
+    cursor: &str,
+    ids: &[&str],
+    next_cursor: &str,
+    hits: usize,
+) -> mockito::Mock {
+    let data: Vec<String> = ids
+        .iter()
+        .map(|id| trade_json(id, "10", "0.5", 1700000000, "TAKER").to_string())
+        .collect();
+    server
+        .mock("GET", "/data/trades")
+        .match_query(Matcher::UrlEncoded("next_cursor".into(), cursor.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"data":[{}],"next_cursor":"{}"}}"#,
+            data.join(","),
+            next_cursor
+        ))
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_trades_stream_fetches_pages_lazily() {
+    let mut server = mockito::Server::new_async().await;
+    let first = mock_page(&mut server, "MA==", &["t1", "t2"], "MTAw", 1).await;
+    let second = mock_page(&mut server, "MTAw", &["t3"], "LTE=", 0).await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let ids: Vec<String> = client
+        .get_trades_stream(None)
+        .take(2)
+        .map(|trade| trade.unwrap().id)
+        .collect()
+        .await;
+
+    assert_eq!(ids, vec!["t1", "t2"]);
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_trades_collects_all_pages() {
+    let mut server = mockito::Server::new_async().await;
+    let first = mock_page(&mut server, "MA==", &["t1", "t2"], "MTAw", 1).await;
+    let second = mock_page(&mut server, "MTAw", &["t3"], "LTE=", 1).await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let trades = client.get_trades(None).await.expect("Failed to get trades");
+
+    let ids: Vec<&str> = trades.iter().map(|trade| trade.id.as_str()).collect();
+    assert_eq!(ids, vec!["t1", "t2", "t3"]);
+    first.assert_async().await;
+    second.assert_async().await;
+}
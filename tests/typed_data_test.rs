@@ -0,0 +1,98 @@
+use alloy_primitives::Address;
+use alloy_signer::Signer;
+use alloy_signer_local::PrivateKeySigner;
+use rs_clob_client::signing::eip712::order_hash;
+use rs_clob_client::signing::{
+    build_order_typed_data, recover_signer, signed_order_from_typed_data,
+};
+use rs_clob_client::types::{Chain, CreateOrderOptions, Side, TickSize, UserLimitOrder};
+use rs_clob_client::ClobError;
+use std::str::FromStr;
+
+fn order() -> UserLimitOrder {
+    UserLimitOrder {
+        token_id: "1234".to_string(),
+        price: 0.37,
+        size: 25.0,
+        side: Side::Buy,
+        fee_rate_bps: None,
+        nonce: None,
+        expiration: None,
+        expiration_buffer: None,
+        taker: None,
+        order_type: None,
+    }
+}
+
+fn options(neg_risk: bool) -> CreateOrderOptions {
+    CreateOrderOptions {
+        tick_size: TickSize::ZeroPointZeroOne,
+        neg_risk: Some(neg_risk),
+    }
+}
+
+#[tokio::test]
+async fn test_externally_signed_typed_data_makes_a_valid_order() {
+    // Stands in for the browser wallet
+    let wallet = PrivateKeySigner::random();
+
+    for neg_risk in [false, true] {
+        let typed_data = build_order_typed_data(
+            &order(),
+            &options(neg_risk),
+            wallet.address(),
+            wallet.address(),
+            Chain::Amoy,
+            0,
+        )
+        .await
+        .unwrap();
+
+        let domain = &typed_data["domain"];
+        assert_eq!(domain["chainId"], 80002);
+        let exchange = Address::from_str(domain["verifyingContract"].as_str().unwrap()).unwrap();
+
+        // The wallet signs the same digest the crate's own signer would
+        let digest = order_hash(&typed_data["message"], 80002, exchange).unwrap();
+        let signature = wallet.sign_hash(&digest).await.unwrap();
+
+        let signed = signed_order_from_typed_data(&typed_data, &signature.as_bytes()).unwrap();
+        assert_eq!(
+            order_hash(&serde_json::to_value(&signed).unwrap(), 80002, exchange).unwrap(),
+            digest
+        );
+        assert_eq!(
+            recover_signer(&signed, Chain::Amoy, neg_risk).unwrap(),
+            wallet.address()
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_signature_from_another_account_is_rejected() {
+    let signer = PrivateKeySigner::random();
+    let typed_data = build_order_typed_data(
+        &order(),
+        &options(false),
+        signer.address(),
+        signer.address(),
+        Chain::Amoy,
+        0,
+    )
+    .await
+    .unwrap();
+
+    let exchange =
+        Address::from_str(typed_data["domain"]["verifyingContract"].as_str().unwrap()).unwrap();
+    let digest = order_hash(&typed_data["message"], 80002, exchange).unwrap();
+    let signature = PrivateKeySigner::random().sign_hash(&digest).await.unwrap();
+
+    assert!(matches!(
+        signed_order_from_typed_data(&typed_data, &signature.as_bytes()),
+        Err(ClobError::SigningError(_))
+    ));
+    assert!(matches!(
+        signed_order_from_typed_data(&typed_data, &[0u8; 12]),
+        Err(ClobError::SigningError(_))
+    ));
+}
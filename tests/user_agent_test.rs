@@ -0,0 +1,52 @@
+use rs_clob_client::{constants::DEFAULT_USER_AGENT, types::Chain, ClobClient};
+
+#[test]
+fn test_default_user_agent_contains_version() {
+    assert_eq!(
+        DEFAULT_USER_AGENT,
+        format!("rs-clob-client/{}", env!("CARGO_PKG_VERSION"))
+    );
+}
+
+#[tokio::test]
+async fn test_requests_send_default_user_agent() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/time")
+        .match_header("User-Agent", DEFAULT_USER_AGENT)
+        .with_status(200)
+        .with_body("1700000000")
+        .create_async()
+        .await;
+
+    let client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+    client
+        .get_server_time()
+        .await
+        .expect("Failed to fetch server time");
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_user_agent_override() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/time")
+        .match_header("User-Agent", "my-bot/1.0")
+        .with_status(200)
+        .with_body("1700000000")
+        .create_async()
+        .await;
+
+    let mut client = ClobClient::new_public(server.url(), server.url(), Chain::Polygon)
+        .expect("Failed to create public client");
+    client.set_user_agent("my-bot/1.0");
+    client
+        .get_server_time()
+        .await
+        .expect("Failed to fetch server time");
+
+    mock.assert_async().await;
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use common::create_mock_client_with_api_key;
+use mockito::Matcher;
+use rs_clob_client::constants::{END_CURSOR, INITIAL_CURSOR};
+use rs_clob_client::types::UserEarningsConfigParams;
+
+#[tokio::test]
+async fn test_user_earnings_config_params_with_defaults() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/rewards/user/markets")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("date".into(), "2024-06-01".into()),
+            Matcher::UrlEncoded("next_cursor".into(), INITIAL_CURSOR.into()),
+            Matcher::UrlEncoded("order_by".into(), "".into()),
+            Matcher::UrlEncoded("position".into(), "".into()),
+            Matcher::UrlEncoded("no_competition".into(), "true".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(r#"{{"data":[],"next_cursor":"{}"}}"#, END_CURSOR))
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let params = UserEarningsConfigParams {
+        date: "2024-06-01".to_string(),
+        no_competition: true,
+        ..Default::default()
+    };
+    let earnings = client
+        .get_user_earnings_and_markets_config(params)
+        .await
+        .expect("Failed to fetch user earnings config");
+
+    mock.assert_async().await;
+    assert!(earnings.is_empty());
+}
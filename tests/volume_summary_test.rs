@@ -0,0 +1,57 @@
+mod common;
+
+use common::{create_mock_client_with_api_key, trade_json};
+use mockito::Matcher;
+
+const START_TS: u64 = 1_700_000_000;
+const END_TS: u64 = 1_700_086_400;
+
+#[tokio::test]
+async fn test_get_volume_summary_aggregates_by_trader_side() {
+    let mut server = mockito::Server::new_async().await;
+    let first_page = server
+        .mock("GET", "/data/trades")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("next_cursor".into(), "MA==".into()),
+            Matcher::UrlEncoded("after".into(), START_TS.to_string()),
+            Matcher::UrlEncoded("before".into(), END_TS.to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"data":[{},{}],"next_cursor":"MTAw"}}"#,
+            trade_json("t1", "100", "0.5", START_TS + 10, "TAKER"),
+            trade_json("t2", "20", "0.25", START_TS + 20, "MAKER"),
+        ))
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("GET", "/data/trades")
+        .match_query(Matcher::UrlEncoded("next_cursor".into(), "MTAw".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"data":[{},{}],"next_cursor":"LTE="}}"#,
+            trade_json("t3", "10", "0.8", START_TS + 30, "TAKER"),
+            // Outside the window: ignored
+            trade_json("t4", "1000", "0.5", END_TS + 1, "TAKER"),
+        ))
+        .create_async()
+        .await;
+
+    let client = create_mock_client_with_api_key(server.url());
+    let summary = client
+        .get_volume_summary(START_TS, END_TS)
+        .await
+        .expect("Failed to get volume summary");
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+
+    assert_eq!(summary.trade_count, 3);
+    assert!((summary.volume - 63.0).abs() < 1e-9);
+    assert_eq!(summary.taker.trade_count, 2);
+    assert!((summary.taker.volume - 58.0).abs() < 1e-9);
+    assert_eq!(summary.maker.trade_count, 1);
+    assert!((summary.maker.volume - 5.0).abs() < 1e-9);
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "ws")]
+
+use futures::{SinkExt, StreamExt};
+use rs_clob_client::ws::{MarketChannel, MarketEvent};
+use rs_clob_client::Side;
+use serde_json::Value;
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+
+const BOOK: &str = r#"[{
+    "event_type": "book",
+    "market": "0xabc",
+    "asset_id": "111",
+    "timestamp": "1700000000000",
+    "hash": "0xbook",
+    "bids": [{"price": "0.48", "size": "30"}, {"price": "0.49", "size": "20"}],
+    "asks": [{"price": "0.52", "size": "25"}]
+}]"#;
+
+const PRICE_CHANGE: &str = r#"{
+    "event_type": "price_change",
+    "market": "0xabc",
+    "timestamp": "1700000000100",
+    "price_changes": [{
+        "asset_id": "111", "price": "0.50", "size": "15", "side": "BUY",
+        "hash": "0xchange", "best_bid": "0.50", "best_ask": "0.52"
+    }]
+}"#;
+
+const LAST_TRADE_PRICE: &str = r#"{
+    "event_type": "last_trade_price",
+    "market": "0xabc",
+    "asset_id": "111",
+    "price": "0.52",
+    "side": "SELL",
+    "size": "5",
+    "fee_rate_bps": "0",
+    "timestamp": "1700000000200"
+}"#;
+
+/// Serves one connection per message batch, closing each after sending it, and returns the
+/// subscription requests received
+async fn serve(listener: TcpListener, batches: Vec<Vec<&'static str>>) -> Vec<Value> {
+    let mut subscriptions = Vec::new();
+    for batch in batches {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+        let subscribe = socket.next().await.unwrap().unwrap();
+        subscriptions.push(serde_json::from_str(subscribe.to_text().unwrap()).unwrap());
+
+        for message in batch {
+            socket
+                .send(Message::Text(message.to_string()))
+                .await
+                .unwrap();
+        }
+        socket.close(None).await.unwrap();
+    }
+    subscriptions
+}
+
+#[tokio::test]
+async fn test_market_channel_streams_typed_events_across_reconnects() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(serve(
+        listener,
+        vec![
+            vec![BOOK, r#"{"event_type":"new_market"}"#],
+            vec![PRICE_CHANGE, LAST_TRADE_PRICE],
+        ],
+    ));
+
+    let channel = MarketChannel::with_url(url, vec!["111".to_string(), "222".to_string()]);
+    let events: Vec<MarketEvent> = channel
+        .subscribe()
+        .take(3)
+        .map(|event| event.expect("Failed to parse event"))
+        .collect()
+        .await;
+
+    match &events[0] {
+        MarketEvent::Book(book) => {
+            assert_eq!(book.asset_id, "111");
+            assert_eq!(book.bids.len(), 2);
+            assert_eq!(book.asks[0].price, "0.52");
+        }
+        other => panic!("expected book, got {other:?}"),
+    }
+    match &events[1] {
+        MarketEvent::PriceChange(change) => {
+            assert_eq!(change.price_changes[0].side, Side::Buy);
+            assert_eq!(change.price_changes[0].size, "15");
+        }
+        other => panic!("expected price change, got {other:?}"),
+    }
+    match &events[2] {
+        MarketEvent::LastTradePrice(trade) => {
+            assert_eq!(trade.price, "0.52");
+            assert_eq!(trade.side, Side::Sell);
+        }
+        other => panic!("expected last trade price, got {other:?}"),
+    }
+
+    let subscriptions = server.await.unwrap();
+    assert_eq!(subscriptions.len(), 2);
+    for subscription in subscriptions {
+        assert_eq!(subscription["type"], "market");
+        assert_eq!(
+            subscription["assets_ids"],
+            serde_json::json!(["111", "222"])
+        );
+    }
+}
@@ -0,0 +1,152 @@
+#![cfg(feature = "ws")]
+
+use futures::{SinkExt, StreamExt};
+use rs_clob_client::types::{ApiKeyCreds, Chain};
+use rs_clob_client::ws::{OrderUpdateType, UserChannel, UserEvent};
+use rs_clob_client::{ClobClient, ClobError, Side};
+use serde_json::Value;
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+
+// Frames as captured from the user channel
+const ORDER_PLACEMENT: &str = r#"{
+    "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+    "associate_trades": null,
+    "event_type": "order",
+    "id": "0xff354cd7ca7539dfa9c28d90943ab5779a4eac34b9b37a757d7b32bdfb11790b",
+    "market": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+    "order_owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+    "original_size": "10",
+    "outcome": "YES",
+    "owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+    "price": "0.57",
+    "side": "SELL",
+    "size_matched": "0",
+    "timestamp": "1672290687",
+    "type": "PLACEMENT"
+}"#;
+
+const TRADE_MATCHED: &str = r#"[{
+    "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+    "event_type": "trade",
+    "id": "28c4d2eb-bbea-40e7-a9f0-b2fdb56b2c2e",
+    "last_update": "1672290701",
+    "maker_orders": [{
+        "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+        "matched_amount": "10",
+        "order_id": "0xff354cd7ca7539dfa9c28d90943ab5779a4eac34b9b37a757d7b32bdfb11790b",
+        "outcome": "YES",
+        "owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+        "price": "0.57"
+    }],
+    "market": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+    "matchtime": "1672290701",
+    "outcome": "YES",
+    "owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+    "price": "0.57",
+    "side": "BUY",
+    "size": "10",
+    "status": "MATCHED",
+    "taker_order_id": "0x06bc63e346ed4ceddce9efd6b3af37c8f8f440c92fe7da6b2d0f9e4ccbc50c42",
+    "timestamp": "1672290701",
+    "trade_owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+    "type": "TRADE"
+}]"#;
+
+const ORDER_UPDATE: &str = r#"{
+    "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+    "associate_trades": ["28c4d2eb-bbea-40e7-a9f0-b2fdb56b2c2e"],
+    "event_type": "order",
+    "id": "0xff354cd7ca7539dfa9c28d90943ab5779a4eac34b9b37a757d7b32bdfb11790b",
+    "market": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+    "order_owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+    "original_size": "10",
+    "outcome": "YES",
+    "owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+    "price": "0.57",
+    "side": "SELL",
+    "size_matched": "10",
+    "timestamp": "1672290701",
+    "type": "UPDATE"
+}"#;
+
+fn creds() -> ApiKeyCreds {
+    ApiKeyCreds {
+        key: "test-api-key".to_string(),
+        secret: "dGVzdC1zZWNyZXQ=".to_string(),
+        passphrase: "test-passphrase".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_user_channel_authenticates_and_parses_captured_frames() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+        let subscribe = socket.next().await.unwrap().unwrap();
+
+        for frame in [ORDER_PLACEMENT, TRADE_MATCHED, ORDER_UPDATE] {
+            socket.send(Message::Text(frame.to_string())).await.unwrap();
+        }
+        // Keep the connection open until the client has read every frame
+        let _ = socket.next().await;
+        serde_json::from_str::<Value>(subscribe.to_text().unwrap()).unwrap()
+    });
+
+    let channel = UserChannel::with_url(url, creds(), vec!["0xbd31".to_string()]);
+    let events: Vec<UserEvent> = channel
+        .subscribe()
+        .take(3)
+        .map(|event| event.expect("Failed to parse event"))
+        .collect()
+        .await;
+
+    match &events[0] {
+        UserEvent::Order(order) => {
+            assert_eq!(order.update_type, OrderUpdateType::Placement);
+            assert_eq!(order.order.side, Some(Side::Sell));
+            assert!(order.order.associate_trades.is_empty());
+        }
+        other => panic!("expected order, got {other:?}"),
+    }
+    match &events[1] {
+        UserEvent::Trade(trade) => {
+            assert_eq!(trade.trade.status, "MATCHED");
+            assert_eq!(trade.trade.match_time, "1672290701");
+            assert_eq!(trade.trade.maker_orders[0].matched_amount, "10");
+        }
+        other => panic!("expected trade, got {other:?}"),
+    }
+    match &events[2] {
+        UserEvent::Order(order) => {
+            assert_eq!(order.update_type, OrderUpdateType::Update);
+            assert_eq!(order.order.size_matched, "10");
+            assert_eq!(order.order.associate_trades.len(), 1);
+        }
+        other => panic!("expected order, got {other:?}"),
+    }
+
+    let subscribe = server.await.unwrap();
+    assert_eq!(subscribe["type"], "user");
+    assert_eq!(subscribe["markets"], serde_json::json!(["0xbd31"]));
+    assert_eq!(subscribe["auth"]["apiKey"], "test-api-key");
+    assert_eq!(subscribe["auth"]["secret"], "dGVzdC1zZWNyZXQ=");
+    assert_eq!(subscribe["auth"]["passphrase"], "test-passphrase");
+}
+
+#[test]
+fn test_user_channel_requires_api_creds() {
+    let client = ClobClient::new_public(
+        "http://127.0.0.1:1".to_string(),
+        "http://127.0.0.1:1".to_string(),
+        Chain::Polygon,
+    )
+    .unwrap();
+
+    assert!(matches!(
+        client.user_channel(Vec::new()),
+        Err(ClobError::L2AuthNotAvailable)
+    ));
+}